@@ -0,0 +1,277 @@
+use ::core::{
+    alloc::Layout,
+    cell::Cell,
+    ptr::{slice_from_raw_parts_mut, NonNull},
+};
+
+use crate::alloc::{AllocError, Allocator, Global, Owns};
+
+/// A bump allocator which allocates from a single owned chunk of memory.
+///
+/// Allocations are made by bumping an offset into the chunk forward, so they
+/// are very fast. Deallocating memory only reclaims it if it was the most
+/// recent allocation, and all of the memory in the chunk is freed at once when
+/// the `Bump` is dropped.
+///
+/// `Allocator` is implemented for `&Bump`, so the borrow checker ensures that
+/// the chunk outlives every allocation made from it.
+pub struct Bump {
+    chunk: NonNull<[u8]>,
+    len: Cell<usize>,
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        // SAFETY: `self.chunk` was allocated by `Global` with the layout
+        // returned from `chunk_layout`.
+        unsafe {
+            Global.deallocate(
+                self.chunk.cast(),
+                Self::chunk_layout(self.capacity()).unwrap(),
+            );
+        }
+    }
+}
+
+impl Bump {
+    /// The alignment of the chunk that allocations are made from.
+    ///
+    /// Allocations with alignments greater than this are still supported, but
+    /// may waste some space in the chunk for padding.
+    pub const CHUNK_ALIGN: usize = 16;
+
+    /// Creates a new bump allocator with the given capacity in bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chunk could not be allocated.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::try_with_capacity(capacity).unwrap()
+    }
+
+    /// Attempts to create a new bump allocator with the given capacity in
+    /// bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the chunk could not be allocated.
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, AllocError> {
+        let layout = Self::chunk_layout(capacity)?;
+        let ptr = Global.allocate(layout)?;
+        let chunk =
+            slice_from_raw_parts_mut(ptr.as_ptr().cast::<u8>(), capacity);
+        Ok(Self {
+            // SAFETY: `chunk` was allocated by `Global` and so is non-null.
+            chunk: unsafe { NonNull::new_unchecked(chunk) },
+            len: Cell::new(0),
+        })
+    }
+
+    /// Returns the total number of bytes in the chunk.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        ::ptr_meta::metadata(self.chunk.as_ptr())
+    }
+
+    /// Returns the number of bytes in the chunk which are currently in use.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Returns whether no bytes in the chunk are currently in use.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Frees all of the allocations made from the chunk so that its memory can
+    /// be reused.
+    ///
+    /// This requires a mutable borrow, so there can't be any live allocations
+    /// when it is called.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.len.set(0);
+    }
+
+    fn chunk_layout(capacity: usize) -> Result<Layout, AllocError> {
+        Layout::from_size_align(capacity, Self::CHUNK_ALIGN)
+            .map_err(|_| AllocError)
+    }
+
+    // TODO strict_provenance: Use `pointer.addr()`.
+    #[allow(clippy::as_conversions)]
+    fn base(&self) -> usize {
+        self.chunk.as_ptr().cast::<u8>() as usize
+    }
+
+    // TODO strict_provenance: Use `pointer.addr()`.
+    #[allow(clippy::as_conversions)]
+    fn offset_of(&self, ptr: NonNull<u8>) -> usize {
+        ptr.as_ptr() as usize - self.base()
+    }
+
+    fn is_aligned(&self, offset: usize, align: usize) -> bool {
+        (self.base() + offset) & (align - 1) == 0
+    }
+
+    /// # Safety
+    ///
+    /// `start + size` must be less than or equal to the capacity of the chunk.
+    unsafe fn block(&self, start: usize, size: usize) -> NonNull<[u8]> {
+        // SAFETY: The caller has guaranteed that `start + size` is in bounds of
+        // the chunk, so `start` must be as well.
+        let ptr = unsafe { self.chunk.as_ptr().cast::<u8>().add(start) };
+        // SAFETY: `ptr` is in bounds of the chunk, which is non-null.
+        unsafe { NonNull::new_unchecked(slice_from_raw_parts_mut(ptr, size)) }
+    }
+}
+
+// SAFETY: Memory blocks returned from `&Bump` point to memory in the chunk,
+// which remains valid until the `Bump` is dropped or reset. Neither can happen
+// while it is borrowed.
+unsafe impl Allocator for &Bump {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let base = self.base();
+        let unaligned = base.checked_add(self.len()).ok_or(AllocError)?;
+        let aligned = unaligned
+            .checked_add(layout.align() - 1)
+            .ok_or(AllocError)?
+            & !(layout.align() - 1);
+        let start = aligned - base;
+        let end = start.checked_add(layout.size()).ok_or(AllocError)?;
+        if end > self.capacity() {
+            Err(AllocError)
+        } else {
+            self.len.set(end);
+            // SAFETY: We checked that `end` is less than or equal to the
+            // capacity of the chunk.
+            Ok(unsafe { self.block(start, layout.size()) })
+        }
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // Only the most recent allocation can be reclaimed. Everything else is
+        // freed when the `Bump` is dropped or reset.
+        let start = self.offset_of(ptr);
+        if start + layout.size() == self.len() {
+            self.len.set(start);
+        }
+    }
+
+    unsafe fn grow_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let start = self.offset_of(ptr);
+        if start + old_layout.size() != self.len()
+            || !self.is_aligned(start, new_layout.align())
+            || self.capacity() - start < new_layout.size()
+        {
+            Err(AllocError)
+        } else {
+            self.len.set(start + new_layout.size());
+            // SAFETY: We checked that `start + new_layout.size()` is less than
+            // or equal to the capacity of the chunk.
+            Ok(unsafe { self.block(start, new_layout.size()) })
+        }
+    }
+
+    unsafe fn shrink_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let start = self.offset_of(ptr);
+        if !self.is_aligned(start, new_layout.align()) {
+            Err(AllocError)
+        } else {
+            if start + old_layout.size() == self.len() {
+                self.len.set(start + new_layout.size());
+            }
+            // SAFETY: The caller has guaranteed that `old_layout` fits the
+            // memory block pointed to by `ptr`, so `start + old_layout.size()`
+            // is in bounds of the chunk. The caller has also guaranteed that
+            // `new_layout.size()` is less than or equal to `old_layout.size()`.
+            Ok(unsafe { self.block(start, new_layout.size()) })
+        }
+    }
+}
+
+// SAFETY: All memory blocks allocated via `&Bump` are located inside of its
+// chunk, and no other allocator can allocate memory inside of the chunk while
+// the `Bump` owns it.
+unsafe impl Owns for &Bump {
+    // TODO strict_provenance: Use `pointer.addr()`.
+    #[allow(clippy::as_conversions)]
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        let address = ptr.as_ptr() as usize;
+        address >= self.base()
+            && address - self.base() <= self.capacity()
+            && self.capacity() - (address - self.base()) >= layout.size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::alloc::Layout;
+
+    use crate::{
+        alloc::{Allocator, Bump},
+        Box,
+    };
+
+    #[test]
+    fn allocate_and_reclaim() {
+        let bump = Bump::with_capacity(64);
+
+        let a = Box::new_in(1u64, &bump);
+        let b = Box::new_in(2u64, &bump);
+        assert_eq!(bump.len(), 16);
+        assert_eq!((*a, *b), (1, 2));
+
+        drop(b);
+        assert_eq!(bump.len(), 8);
+        drop(a);
+        assert!(bump.is_empty());
+    }
+
+    #[test]
+    fn try_new_in_exhausted() {
+        let bump = Bump::with_capacity(8);
+
+        let a = Box::try_new_in(1u64, &bump).unwrap();
+        assert!(Box::try_new_in(2u64, &bump).is_err());
+        assert_eq!(*a, 1);
+    }
+
+    #[test]
+    fn grow_in_place() {
+        let bump = Bump::with_capacity(64);
+        let alloc = &bump;
+
+        let old_layout = Layout::new::<[u8; 8]>();
+        let new_layout = Layout::new::<[u8; 32]>();
+        let ptr = alloc.allocate(old_layout).unwrap();
+        // SAFETY: `ptr` was allocated by `alloc` with `old_layout`, and
+        // `new_layout` is larger than `old_layout`.
+        let grown = unsafe {
+            alloc
+                .grow_in_place(ptr.cast(), old_layout, new_layout)
+                .unwrap()
+        };
+        assert_eq!(grown.cast::<u8>(), ptr.cast::<u8>());
+        assert_eq!(bump.len(), 32);
+
+        assert!(alloc.allocate(Layout::new::<[u8; 64]>()).is_err());
+        // SAFETY: `grown` was allocated by `alloc` with `new_layout`.
+        unsafe {
+            alloc.deallocate(grown.cast(), new_layout);
+        }
+        assert!(bump.is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use ::core::{alloc::Layout, ptr::NonNull};
+
+use crate::alloc::{AllocError, Allocator, Owns};
+
+/// An allocator which allocates from a primary allocator, and falls back to a
+/// secondary allocator when the primary allocator fails.
+///
+/// The primary allocator must implement [`Owns`] so that memory blocks can be
+/// routed back to the allocator that allocated them. A common use is to
+/// allocate from a fixed buffer first, and fall back to the heap when the
+/// buffer is exhausted.
+pub struct Fallback<A, B> {
+    primary: A,
+    fallback: B,
+}
+
+impl<A, B> Fallback<A, B> {
+    /// Returns a new `Fallback` which allocates from `primary` first, and from
+    /// `fallback` if that fails.
+    pub fn new(primary: A, fallback: B) -> Self {
+        Self { primary, fallback }
+    }
+
+    /// Returns a reference to the primary allocator.
+    pub fn primary(&self) -> &A {
+        &self.primary
+    }
+
+    /// Returns a reference to the fallback allocator.
+    pub fn fallback(&self) -> &B {
+        &self.fallback
+    }
+}
+
+impl<A: Owns, B: Allocator> Fallback<A, B> {
+    /// Moves a memory block allocated via the primary allocator into a new
+    /// memory block allocated via the fallback allocator.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must denote a block of memory _currently allocated_ via the
+    ///   primary allocator.
+    /// - `old_layout` must _fit_ that block of memory.
+    /// - `new_layout.size()` must be greater than or equal to
+    ///   `old_layout.size()`.
+    unsafe fn move_to_fallback(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+        zeroed: bool,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let new_ptr = if zeroed {
+            self.fallback.allocate_zeroed(new_layout)?
+        } else {
+            self.fallback.allocate(new_layout)?
+        };
+        // SAFETY:
+        // - The caller has guaranteed that `old_layout` fits the memory pointed
+        //   to by `ptr`, and so must be valid for reads of `old_layout.size()`.
+        // - The caller has guaranteed that `new_layout.size()` is greater than
+        //   or equal to `old_layout.size()`, so `new_ptr` must be valid for
+        //   writes of `old_layout.size()`.
+        // - `u8` has an alignment of 1, so both pointers must be properly
+        //   aligned.
+        // - The memory pointed to by `new_ptr` is freshly-allocated by a
+        //   different allocator and must not overlap with the memory pointed to
+        //   by `ptr`.
+        unsafe {
+            ::core::ptr::copy_nonoverlapping(
+                ptr.as_ptr(),
+                new_ptr.as_ptr().cast::<u8>(),
+                old_layout.size(),
+            );
+        }
+        // SAFETY: The caller has guaranteed that `ptr` denotes a block of
+        // memory currently allocated via the primary allocator, and that
+        // `old_layout` fits that block of memory.
+        unsafe {
+            self.primary.deallocate(ptr, old_layout);
+        }
+        Ok(new_ptr)
+    }
+}
+
+// SAFETY: Every memory block returned from `Fallback` was allocated by either
+// the primary or fallback allocator, and `owns` routes it back to the allocator
+// that allocated it. So the blocks retain their validity until they are
+// deallocated or the `Fallback` is dropped.
+unsafe impl<A: Owns, B: Allocator> Allocator for Fallback<A, B> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.primary
+            .allocate(layout)
+            .or_else(|_| self.fallback.allocate(layout))
+    }
+
+    fn allocate_zeroed(
+        &self,
+        layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        self.primary
+            .allocate_zeroed(layout)
+            .or_else(|_| self.fallback.allocate_zeroed(layout))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if self.primary.owns(ptr, layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            unsafe { self.primary.deallocate(ptr, layout) }
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe { self.fallback.deallocate(ptr, layout) }
+        }
+    }
+
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if self.primary.owns(ptr, old_layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            let result =
+                unsafe { self.primary.grow(ptr, old_layout, new_layout) };
+            result.or_else(|_| {
+                // SAFETY: The primary allocator owns the memory block, and
+                // the caller has guaranteed that `old_layout` fits it and that
+                // `new_layout.size()` is at least `old_layout.size()`.
+                unsafe {
+                    self.move_to_fallback(ptr, old_layout, new_layout, false)
+                }
+            })
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe { self.fallback.grow(ptr, old_layout, new_layout) }
+        }
+    }
+
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if self.primary.owns(ptr, old_layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            let result = unsafe {
+                self.primary.grow_zeroed(ptr, old_layout, new_layout)
+            };
+            result.or_else(|_| {
+                // SAFETY: The primary allocator owns the memory block, and
+                // the caller has guaranteed that `old_layout` fits it and that
+                // `new_layout.size()` is at least `old_layout.size()`.
+                unsafe {
+                    self.move_to_fallback(ptr, old_layout, new_layout, true)
+                }
+            })
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe { self.fallback.grow_zeroed(ptr, old_layout, new_layout) }
+        }
+    }
+
+    unsafe fn grow_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if self.primary.owns(ptr, old_layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            unsafe { self.primary.grow_in_place(ptr, old_layout, new_layout) }
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe { self.fallback.grow_in_place(ptr, old_layout, new_layout) }
+        }
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if self.primary.owns(ptr, old_layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            unsafe { self.primary.shrink(ptr, old_layout, new_layout) }
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe { self.fallback.shrink(ptr, old_layout, new_layout) }
+        }
+    }
+
+    unsafe fn shrink_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if self.primary.owns(ptr, old_layout) {
+            // SAFETY: The primary allocator owns the memory block, so the
+            // caller's guarantees apply to it.
+            unsafe { self.primary.shrink_in_place(ptr, old_layout, new_layout) }
+        } else {
+            // SAFETY: The primary allocator does not own the memory block, so
+            // it must have been allocated by the fallback allocator. The
+            // caller's guarantees apply to it.
+            unsafe {
+                self.fallback.shrink_in_place(ptr, old_layout, new_layout)
+            }
+        }
+    }
+}
+
+// SAFETY: A memory block is currently allocated via `Fallback` if and only if
+// it is currently allocated via the primary or fallback allocator, both of
+// which can determine whether they own it.
+unsafe impl<A: Owns, B: Owns> Owns for Fallback<A, B> {
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool {
+        self.primary.owns(ptr, layout) || self.fallback.owns(ptr, layout)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use ::core::{alloc::Layout, ptr::NonNull};
+
+    use crate::{
+        alloc::{Bump, Fallback, Global, Owns},
+        Box,
+    };
+
+    #[test]
+    fn falls_back_when_exhausted() {
+        let bump = Bump::with_capacity(16);
+        let layout = Layout::new::<[u8; 16]>();
+        let in_bump =
+            |ptr: &[u8; 16]| (&bump).owns(NonNull::from(ptr).cast(), layout);
+
+        let a = Box::new_in([1u8; 16], Fallback::new(&bump, Global));
+        assert_eq!(bump.len(), 16);
+        let b = Box::new_in([2u8; 16], Fallback::new(&bump, Global));
+        assert_eq!(bump.len(), 16);
+
+        assert!(in_bump(&a));
+        assert!(!in_bump(&b));
+        assert_eq!(*a, [1; 16]);
+        assert_eq!(*b, [2; 16]);
+
+        drop(b);
+        assert_eq!(bump.len(), 16);
+        drop(a);
+        assert!(bump.is_empty());
+    }
+}
@@ -1,6 +1,14 @@
 //! Memory alocation APIs.
 
-use ::core::{alloc::Layout, ptr::NonNull};
+#[cfg(feature = "alloc")]
+mod bump;
+mod fallback;
+
+use ::core::{alloc::Layout, fmt, ptr::NonNull};
+
+#[cfg(feature = "alloc")]
+pub use self::bump::*;
+pub use self::fallback::*;
 
 /// The `AllocError` error indicates an allocation failure that may be due to
 /// resource exhaustion or to something wrong when combining the given input
@@ -8,6 +16,15 @@ use ::core::{alloc::Layout, ptr::NonNull};
 #[derive(Debug)]
 pub struct AllocError;
 
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for AllocError {}
+
 /// An implementation of `Allocator` can allocate, grow, shrink, and deallocate
 /// arbitrary blocks of data described via `Layout`.
 ///
@@ -471,6 +488,25 @@ pub unsafe trait Allocator {
     }
 }
 
+/// An allocator which can determine whether it allocated a block of memory.
+///
+/// # Safety
+///
+/// For any memory block denoted by `ptr` that `layout` fits:
+///
+/// - If the block is currently allocated via this allocator, `owns` must return
+///   `true`.
+/// - If the block is currently allocated via any other allocator, `owns` must
+///   return `false`.
+///
+/// In practice, this means that the memory which this allocator allocates from
+/// must be disjoint from the memory that any other allocator allocates from.
+pub unsafe trait Owns: Allocator {
+    /// Returns whether the memory block denoted by `ptr` is owned by this
+    /// allocator.
+    fn owns(&self, ptr: NonNull<u8>, layout: Layout) -> bool;
+}
+
 /// The global memory allocator.
 #[cfg(feature = "alloc")]
 #[derive(Clone)]
@@ -8,7 +8,7 @@ use ::core::{
     ptr::{read, NonNull},
 };
 
-use crate::alloc::Allocator;
+use crate::alloc::{AllocError, Allocator};
 #[cfg(feature = "alloc")]
 use crate::alloc::Global;
 
@@ -86,12 +86,23 @@ impl<T: ?Sized, A: Allocator> Box<T, A> {
 
 impl<T, A: Allocator> Box<T, A> {
     /// Allocates memory in the given allocator then places `x` into it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails. For a fallible alternative, see
+    /// [`try_new_in`](Box::try_new_in).
     pub fn new_in(x: T, alloc: A) -> Box<T, A> {
-        let ptr = alloc
-            .allocate(Layout::new::<T>())
-            .unwrap()
-            .as_ptr()
-            .cast::<T>();
+        Self::try_new_in(x, alloc).unwrap()
+    }
+
+    /// Allocates memory in the given allocator then places `x` into it,
+    /// returning an error if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocation fails.
+    pub fn try_new_in(x: T, alloc: A) -> Result<Box<T, A>, AllocError> {
+        let ptr = alloc.allocate(Layout::new::<T>())?.as_ptr().cast::<T>();
         // SAFETY: `ptr` is guaranteed to be properly aligned for a `T` and
         // valid for writes because we just allocated it.
         unsafe {
@@ -99,16 +110,31 @@ impl<T, A: Allocator> Box<T, A> {
         }
         // SAFETY: We allocated `ptr` in `alloc` with the layout of `T` and
         // initialized it by writing `x`.
-        unsafe { Self::from_raw_in(ptr, alloc) }
+        Ok(unsafe { Self::from_raw_in(ptr, alloc) })
     }
 }
 
 #[cfg(feature = "alloc")]
 impl<T> Box<T, Global> {
     /// Allocates memory in the `Global` allocator and then places `x` into it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails. For a fallible alternative, see
+    /// [`try_new`](Box::try_new).
     pub fn new(x: T) -> Self {
         Self::new_in(x, Global)
     }
+
+    /// Allocates memory in the `Global` allocator and then places `x` into it,
+    /// returning an error if the allocation fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocation fails.
+    pub fn try_new(x: T) -> Result<Self, AllocError> {
+        Self::try_new_in(x, Global)
+    }
 }
 
 impl<T: Debug + ?Sized, A: Allocator> Debug for Box<T, A> {
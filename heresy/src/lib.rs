@@ -13,6 +13,8 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc as builtin_alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod alloc;
 pub mod boxed;
@@ -1,11 +1,15 @@
 use ::core::{
+    alloc::Layout,
     mem::{ManuallyDrop, MaybeUninit},
     ops::{Deref, DerefMut},
     ptr::{self, read, NonNull},
 };
 #[cfg(feature = "alloc")]
 use ::heresy::alloc::Global;
-use ::heresy::{alloc::Allocator, Box};
+use ::heresy::{
+    alloc::{AllocError, Allocator},
+    Box,
+};
 use ::ptr_meta::Pointee;
 
 use crate::{
@@ -92,6 +96,22 @@ where
         unsafe { Box::from_raw_in(raw, alloc) }
     }
 
+    /// Converts a `Box<T, A>` into a `Frame<T, A>` without dropping its
+    /// contents.
+    ///
+    /// This does not move the value pointed to by the box. Instead, the value
+    /// is leaked and the returned frame treats its contents as uninitialized.
+    /// If the value needs to be dropped, the caller must drop it in place
+    /// before reusing the frame. The caller is also responsible for
+    /// reinitializing the contents before calling
+    /// [`assume_init`](Frame::assume_init) again.
+    pub fn from_box(b: Box<T, A>) -> Self {
+        let (raw, alloc) = Box::into_raw_with_allocator(b);
+        // SAFETY: `raw` is non-null and was allocated by `alloc`. `Box`es
+        // allocate memory with the same layouts as `Frame`s.
+        unsafe { Self::from_raw_in(raw, alloc) }
+    }
+
     /// Constructs a frame from a raw pointer in the given allocator.
     ///
     /// After calling this function, the raw pointer is owned by the resulting
@@ -144,6 +164,28 @@ where
     ///
     /// `metadata` must be valid for a pointer to `T`.
     pub unsafe fn new_unsized_in(metadata: T::Metadata, alloc: A) -> Self {
+        // SAFETY: The caller has ensured that `metadata` is valid for a pointer
+        // to `T`.
+        unsafe { Self::try_new_unsized_in(metadata, alloc).unwrap() }
+    }
+
+    /// Attempts to allocate memory for an unsized type with the given metadata
+    /// in the given allocator.
+    ///
+    /// This doesn't actually allocate if the metadata provides a layout with
+    /// zero size.
+    ///
+    /// # Safety
+    ///
+    /// `metadata` must be valid for a pointer to `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocator fails to allocate the memory.
+    pub unsafe fn try_new_unsized_in(
+        metadata: T::Metadata,
+        alloc: A,
+    ) -> Result<Self, AllocError> {
         // SAFETY: The caller has ensured that `metadata` is valid for a pointer
         // to `T`.
         let layout = unsafe { metadata.pointee_layout() };
@@ -153,15 +195,69 @@ where
             ptr_meta::from_raw_parts_mut(layout.align() as *mut (), metadata)
         } else {
             ptr_meta::from_raw_parts_mut(
-                alloc.allocate(layout).unwrap().as_ptr().cast(),
+                alloc.allocate(layout)?.as_ptr().cast(),
                 metadata,
             )
         };
-        Self {
+        Ok(Self {
             // SAFETY: `ptr` is non-null.
             ptr: unsafe { NonNull::new_unchecked(ptr) },
             alloc,
-        }
+        })
+    }
+
+    /// Grows the memory of the frame to fit a `T` with the given metadata.
+    ///
+    /// The contents of the old memory are preserved, and any newly-added
+    /// memory is uninitialized. The frame may be moved to a new location in
+    /// memory, so any pointers to its old contents are invalidated.
+    ///
+    /// This doesn't actually allocate if the new metadata provides a layout
+    /// with zero size.
+    ///
+    /// # Safety
+    ///
+    /// - `metadata` must be valid for a pointer to `T`.
+    /// - The layout provided by `metadata` must have a size greater than or
+    ///   equal to the size of the current layout of the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocator fails to grow the memory. When this
+    /// happens, the frame is left unchanged.
+    pub unsafe fn grow_unsized(
+        &mut self,
+        metadata: T::Metadata,
+    ) -> Result<(), AllocError> {
+        let old_layout = layout_of_val_raw(self.as_ptr());
+        // SAFETY: The caller has guaranteed that `metadata` is valid for a
+        // pointer to `T`.
+        let new_layout = unsafe { metadata.pointee_layout() };
+        let data = if new_layout.size() == 0 {
+            // TODO strict_provenance: Use `::core::ptr::invalid_mut`.
+            #[allow(clippy::as_conversions)]
+            let data = new_layout.align() as *mut ();
+            data
+        } else if old_layout.size() == 0 {
+            // Zero-sized frames don't allocate, so there is no memory to grow.
+            self.alloc.allocate(new_layout)?.as_ptr().cast()
+        } else {
+            // SAFETY:
+            // - `self.ptr` is currently allocated by `self.alloc` because its
+            //   layout has a nonzero size.
+            // - `old_layout` is the layout used to allocate `self.ptr`.
+            // - The caller has guaranteed that `new_layout.size()` is greater
+            //   than or equal to `old_layout.size()`.
+            let ptr = unsafe {
+                self.alloc.grow(self.ptr.cast(), old_layout, new_layout)?
+            };
+            ptr.as_ptr().cast()
+        };
+        let ptr = ptr_meta::from_raw_parts_mut(data, metadata);
+        // SAFETY: `ptr` is non-null because `data` is either a nonzero
+        // alignment or a pointer returned from the allocator.
+        self.ptr = unsafe { NonNull::new_unchecked(ptr) };
+        Ok(())
     }
 
     /// Returns a [`Slot`] of the internal contents.
@@ -195,6 +291,18 @@ impl<T, A: Allocator> Frame<T, A> {
         unsafe { Self::new_unsized_in((), alloc) }
     }
 
+    /// Attempts to allocate memory in the given allocator.
+    ///
+    /// This doesn't actually allocate if `T` is zero-sized.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the allocator fails to allocate the memory.
+    pub fn try_new_in(alloc: A) -> Result<Self, AllocError> {
+        // SAFETY: `()` is valid metadata for a pointer to `T`.
+        unsafe { Self::try_new_unsized_in((), alloc) }
+    }
+
     /// Sets the value of the underlying memory and converts the frame to a box.
     ///
     /// This overwrites any previous value without dropping it, so be careful
@@ -207,6 +315,37 @@ impl<T, A: Allocator> Frame<T, A> {
     }
 }
 
+impl<T, A: Allocator> Frame<[T], A> {
+    /// Grows the frame to hold `new_len` elements.
+    ///
+    /// The existing elements are preserved, and the newly-added elements are
+    /// uninitialized. The frame may be moved to a new location in memory, so
+    /// any pointers to its old contents are invalidated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is less than the current length of the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the new length overflows the maximum size of a slice,
+    /// or if the allocator fails to grow the memory. When this happens, the
+    /// frame is left unchanged.
+    pub fn grow(&mut self, new_len: usize) -> Result<(), AllocError> {
+        let len = ::ptr_meta::metadata(self.as_ptr());
+        assert!(
+            new_len >= len,
+            "`new_len` must be greater than or equal to the current length",
+        );
+        Layout::array::<T>(new_len).map_err(|_| AllocError)?;
+        // SAFETY:
+        // - We checked that `new_len` is a valid length for a slice of `T`.
+        // - The size of a slice only increases with its length, and `new_len`
+        //   is at least as long as the current length.
+        unsafe { self.grow_unsized(new_len) }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<T: Pointee + ?Sized> Frame<T, Global>
 where
@@ -405,4 +544,39 @@ mod tests {
         let s = unsafe { x.assume_init() };
         assert_eq!(&*s, [0, 0, 0, 0]);
     }
+
+    #[test]
+    fn grow_slice() {
+        // SAFETY: `0` is valid metadata for a `[u8]`.
+        let mut x = unsafe { Frame::<[u8]>::new_unsized(0) };
+        x.grow(0).unwrap();
+        x.grow(2).unwrap();
+        assert_eq!(::ptr_meta::metadata(x.as_ptr()), 2);
+        // SAFETY: `x` is a valid `[u8; 2]`.
+        unsafe {
+            x.as_mut_ptr().cast::<[u8; 2]>().write([1, 2]);
+        }
+        x.grow(1024).unwrap();
+        assert_eq!(::ptr_meta::metadata(x.as_ptr()), 1024);
+        // SAFETY: The first two elements of `x` were initialized before
+        // growing.
+        let prefix = unsafe { x.as_ptr().cast::<[u8; 2]>().read() };
+        assert_eq!(prefix, [1, 2]);
+    }
+
+    #[test]
+    fn from_box() {
+        use ::heresy::Box;
+
+        let boxed = Box::new(42u32);
+        let ptr: *const u32 = &*boxed;
+
+        let mut frame = Frame::from_box(boxed);
+        assert_eq!(frame.as_ptr(), ptr);
+        frame.slot().write(7);
+        // SAFETY: The contents of `frame` were just initialized.
+        let boxed = unsafe { frame.assume_init() };
+        assert_eq!(*boxed, 7);
+        assert!(::core::ptr::eq(&*boxed, ptr));
+    }
 }
@@ -5,7 +5,15 @@ use ::core::{
 use ::munge::{Destructure, Restructure};
 use ::ptr_meta::Pointee;
 
-use crate::{Frame, Metadata, Pointer, Region, RegionalAllocator, Slot};
+use crate::{
+    Frame,
+    Metadata,
+    Pointer,
+    Region,
+    RegionalAllocator,
+    Slot,
+    Subregion,
+};
 
 /// A pointer which has its pointee in a specific memory region.
 #[derive(Clone, Copy)]
@@ -19,6 +27,7 @@ where
     P::Target: Pointee,
 {
     /// Creates a new `In` from a pointer.
+    #[inline]
     pub fn new(ptr: P) -> Self
     where
         P: Within<R>,
@@ -33,6 +42,7 @@ where
     /// # Safety
     ///
     /// The pointee of `ptr` must be contained in `R`.
+    #[inline]
     pub unsafe fn new_unchecked(ptr: P) -> Self {
         Self {
             ptr,
@@ -41,6 +51,7 @@ where
     }
 
     /// Maps this `In` to another pointer in its region.
+    #[inline]
     pub fn map<F, Q>(self, f: F) -> In<Q, R>
     where
         F: FnOnce(P) -> Q,
@@ -55,6 +66,7 @@ where
     /// # Safety
     ///
     /// The pointer returned by `f` must be completely contained in `R`.
+    #[inline]
     pub unsafe fn map_unchecked<F, Q>(self, f: F) -> In<Q, R>
     where
         F: FnOnce(P) -> Q,
@@ -68,6 +80,7 @@ where
     }
 
     /// Gets a raw `In` from this pointer.
+    #[inline]
     pub fn as_raw(&self) -> In<*mut P::Target, R> {
         // SAFETY: `self.ptr.deref_raw()` returns a pointer located in `R`.
         // Calling `deref_raw` on that returned `*mut P::Target` returns the
@@ -80,11 +93,13 @@ where
 
 impl<P, R: Region> In<P, R> {
     /// Unwraps an `In`, returning the underlying pointer.
+    #[inline]
     pub fn into_inner(this: Self) -> P {
         this.ptr
     }
 
     /// Returns a reference to the pointer of this `In`.
+    #[inline]
     pub fn ptr(&self) -> &P {
         &self.ptr
     }
@@ -94,9 +109,22 @@ impl<P, R: Region> In<P, R> {
     /// # Safety
     ///
     /// The pointer must not be mutated to point outside of `R`.
+    #[inline]
     pub unsafe fn ptr_mut(&mut self) -> &mut P {
         &mut self.ptr
     }
+
+    /// Converts this `In` into an `In` of a region which contains `R`.
+    #[inline]
+    pub fn widen<Q: Region>(this: Self) -> In<P, Q>
+    where
+        R: Subregion<Q>,
+    {
+        In {
+            ptr: this.ptr,
+            region: PhantomData,
+        }
+    }
 }
 
 impl<T: Pointee + ?Sized, A: RegionalAllocator> In<Frame<T, A>, A::Region>
@@ -104,6 +132,7 @@ where
     <T as Pointee>::Metadata: Metadata<T>,
 {
     /// Returns a [`Slot`] of the internal contents.
+    #[inline]
     pub fn slot(&'_ mut self) -> In<Slot<'_, T>, A::Region> {
         // SAFETY: `slot` is wrapped in an `In` before being returned to prevent
         // it from being pointed outside of `R`, and we don't mutate it to point
@@ -118,6 +147,7 @@ where
 
 impl<'a, T: Pointee + ?Sized, R: Region> In<Slot<'a, T>, R> {
     /// Gets a mutable borrow from this slot.
+    #[inline]
     pub fn as_mut<'b>(&'b mut self) -> In<Slot<'b, T>, R>
     where
         'a: 'b,
@@ -132,17 +162,36 @@ impl<'a, T: Pointee + ?Sized, R: Region> In<Slot<'a, T>, R> {
 impl<P: Deref, R: Region> Deref for In<P, R> {
     type Target = P::Target;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         self.ptr().deref()
     }
 }
 
 impl<P: DerefMut, R: Region> DerefMut for In<P, R> {
+    #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.ptr.deref_mut()
     }
 }
 
+// SAFETY: `In<P, R>` only implements `Deref` and `DerefMut` when `P` does, and
+// it forwards them to `P`. Its `target` is the same as the `target` of `P`, so
+// it is equal to the references returned from `deref` and `deref_mut`.
+unsafe impl<P: Pointer, R: Region> Pointer for In<P, R> {
+    type Target = P::Target;
+
+    #[inline]
+    fn target(&self) -> *mut Self::Target {
+        self.ptr.target()
+    }
+}
+
+// SAFETY: The target of `In<P, C>` is located in `C`. Because `C` is a
+// subregion of `R`, every memory segment contained in `C` is also contained in
+// `R`. So the target of `In<P, C>` is also located in `R`.
+unsafe impl<P: Pointer, C: Subregion<R>, R: Region> Within<R> for In<P, C> {}
+
 /// A `Pointer` that may be restructured with `munge`.
 ///
 /// # Safety
@@ -160,6 +209,7 @@ unsafe impl<P: Destructure, R: Region> Destructure for In<P, R> {
     type Underlying = P::Underlying;
     type Destructuring = P::Destructuring;
 
+    #[inline]
     fn underlying(&mut self) -> *mut Self::Underlying {
         self.ptr.underlying()
     }
@@ -178,6 +228,7 @@ where
 {
     type Restructured = In<<P as Restructure<U>>::Restructured, R>;
 
+    #[inline]
     unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
         // SAFETY: The caller has guaranteed that `ptr` is a properly aligned
         // pointer to a subfield of the pointer underlying `self`, which is the
@@ -200,3 +251,35 @@ where
 ///
 /// The `target` of the type must be located in `R`.
 pub unsafe trait Within<R: Region>: Pointer {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{In, Pointer, Region, Subregion};
+
+    #[test]
+    fn widen() {
+        struct Parent;
+        // SAFETY: `Parent` only corresponds to `values`.
+        unsafe impl Region for Parent {}
+
+        struct Child;
+        // SAFETY: `Child` only corresponds to `values`.
+        unsafe impl Region for Child {}
+        // SAFETY: `Child` only contains the second half of `values`, which is
+        // contained in `Parent`.
+        unsafe impl Subregion<Parent> for Child {}
+
+        let mut values = [0u32; 4];
+        let ptr = values[2..].as_mut_ptr();
+        // SAFETY: `ptr` points into the second half of `values`, which is
+        // contained in `Child`.
+        let child = unsafe { In::<_, Child>::new_unchecked(ptr) };
+
+        let nested = In::<_, Parent>::new(child);
+        assert_eq!(Pointer::target(&nested), ptr);
+
+        let child = In::into_inner(nested);
+        let parent: In<_, Parent> = In::widen(child);
+        assert_eq!(*parent.ptr(), ptr);
+    }
+}
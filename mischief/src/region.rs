@@ -22,6 +22,31 @@ use ::heresy::alloc::Allocator;
 /// This type must correspond to a single unique allocated object.
 pub unsafe trait Region {}
 
+/// A region which is completely contained in another region `P`.
+///
+/// This is useful for allocators which carve their memory out of a parent
+/// allocator's region. Values located in the subregion are also located in the
+/// parent region, so pointers into the subregion may be used wherever pointers
+/// into the parent region are expected.
+///
+/// Because a `Region` must correspond to a single allocated object, the
+/// subregion and the parent region correspond to the same allocated object.
+/// Computing the offset between any two memory segments contained in either
+/// region is therefore safe, since both segments are contained in the parent
+/// region.
+///
+/// Every region is a subregion of itself.
+///
+/// # Safety
+///
+/// Every memory segment contained in this region must also be contained in `P`.
+/// That is, the bounds of this region must lie entirely within the bounds of
+/// `P` for as long as any memory segment is located in this region.
+pub unsafe trait Subregion<P: Region>: Region {}
+
+// SAFETY: Every memory segment contained in `R` is contained in `R`.
+unsafe impl<R: Region> Subregion<R> for R {}
+
 /// An `Allocator` that allocates inside a single contiguous memory region.
 ///
 /// # Safety
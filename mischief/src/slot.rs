@@ -1,5 +1,6 @@
 use ::core::{
     alloc::Layout,
+    iter::FusedIterator,
     marker::PhantomData,
     mem::MaybeUninit,
     ptr::{slice_from_raw_parts_mut, NonNull},
@@ -331,8 +332,165 @@ impl<'a, T> Slot<'a, [T]> {
         //   because the slice never does either.
         unsafe { Slot::new_unchecked(ptr) }
     }
+
+    /// Returns an iterator over slots of the elements of the slice.
+    pub fn iter_mut(&mut self) -> SlotIter<'_, T> {
+        self.as_mut().into_iter()
+    }
+
+    /// Fills the slot slice by writing `value` to each of its elements.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        for mut element in self.iter_mut() {
+            element.write(value);
+        }
+    }
+
+    /// Divides one slot slice into two at an index.
+    ///
+    /// The first will contain all indices from `[0, mid)` (excluding the index
+    /// `mid` itself) and the second will contain all indices from `[mid, len)`
+    /// (excluding the index `len` itself).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`.
+    pub fn split_at_mut(self, mid: usize) -> (Slot<'a, [T]>, Slot<'a, [T]>) {
+        let len = self.len();
+        assert!(mid <= len, "mid > len");
+        let ptr = self.as_ptr().cast::<T>();
+        // SAFETY: `ptr.add(mid)` will always end up inside the bounds of the
+        // slice or one past its end because `mid` is at most `len`.
+        let right = unsafe { ptr.add(mid) };
+        // SAFETY:
+        // - Both slice pointers are non-null, properly aligned, and valid for
+        //   reads and writes because they point to subslices of the original
+        //   slice.
+        // - The two slice pointers cover the disjoint ranges `[0, mid)` and
+        //   `[mid, len)` of the original slice, so they do not alias each
+        //   other. They also do not alias any other accessible references for
+        //   `'a` because the original slice never does either.
+        unsafe {
+            (
+                Slot::new_unchecked(slice_from_raw_parts_mut(ptr, mid)),
+                Slot::new_unchecked(slice_from_raw_parts_mut(right, len - mid)),
+            )
+        }
+    }
+}
+
+impl<'a> Slot<'a, [u8]> {
+    /// Attempts to cast the slot of bytes to a slot of a slice of `U`.
+    ///
+    /// The returned slot covers all of the bytes. Returns `None` if the bytes
+    /// are not aligned for `U`, if their length is not a multiple of the size
+    /// of `U`, or if `U` is zero-sized.
+    pub fn cast_slice<U>(self) -> Option<Slot<'a, [U]>> {
+        use core::mem::{align_of, size_of};
+
+        let size = size_of::<U>();
+        // TODO strict_provenance: Use `pointer.addr()`.
+        #[allow(clippy::as_conversions)]
+        let is_aligned =
+            self.as_ptr().cast::<u8>() as usize & (align_of::<U>() - 1) == 0;
+        if size != 0 && self.len() % size == 0 && is_aligned {
+            let slice_ptr = slice_from_raw_parts_mut(
+                self.as_ptr().cast::<U>(),
+                self.len() / size,
+            );
+            // SAFETY:
+            // - `slice_ptr` is non-null because `self.as_ptr()` is always
+            //   non-null.
+            // - `slice_ptr` is properly aligned for `U`, and it covers exactly
+            //   the bytes of `self` because their length is a multiple of the
+            //   size of `U`. Those bytes are valid for reads and writes.
+            // - `slice_ptr` does not alias any other accessible references for
+            //   `'a` because `self` does not alias any accessible references
+            //   for `'a`.
+            Some(unsafe { Slot::new_unchecked(slice_ptr) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for Slot<'a, [T]> {
+    type Item = Slot<'a, T>;
+    type IntoIter = SlotIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SlotIter {
+            ptr: self.as_ptr().cast(),
+            len: self.len(),
+            _phantom: PhantomData,
+        }
+    }
 }
 
+/// An iterator over slots of the elements of a slot slice.
+///
+/// This `struct` is created by the [`iter_mut`](Slot::iter_mut) method on slot
+/// slices, or by calling `into_iter` on one.
+pub struct SlotIter<'a, T> {
+    ptr: *mut T,
+    len: usize,
+    _phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> Iterator for SlotIter<'a, T> {
+    type Item = Slot<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            let ptr = self.ptr;
+            // SAFETY: There is at least one element remaining, so `ptr.add(1)`
+            // will end up inside the bounds of the slice or one past its end.
+            self.ptr = unsafe { ptr.add(1) };
+            self.len -= 1;
+            // SAFETY:
+            // - `ptr` is non-null, properly aligned, and valid for reads and
+            //   writes because it points to an element of the slice.
+            // - `ptr` does not alias any other accessible references for `'a`
+            //   because each element is only returned once and the slice never
+            //   aliases any other accessible references either.
+            Some(unsafe { Slot::new_unchecked(ptr) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for SlotIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            // SAFETY: `self.len` is the index of the last remaining element, so
+            // `self.ptr.add(self.len)` will end up inside the bounds of the
+            // slice.
+            let ptr = unsafe { self.ptr.add(self.len) };
+            // SAFETY:
+            // - `ptr` is non-null, properly aligned, and valid for reads and
+            //   writes because it points to an element of the slice.
+            // - `ptr` does not alias any other accessible references for `'a`
+            //   because each element is only returned once and the slice never
+            //   aliases any other accessible references either.
+            Some(unsafe { Slot::new_unchecked(ptr) })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for SlotIter<'_, T> {}
+
+impl<T> FusedIterator for SlotIter<'_, T> {}
+
 // SAFETY: `Slot` returns the same value from `target`, `deref`, and
 // `deref_mut`.
 unsafe impl<T: ?Sized> Pointer for Slot<'_, T> {
@@ -373,3 +531,86 @@ unsafe impl<'a, T, U: 'a> Restructure<U> for Slot<'a, T> {
         unsafe { Slot::new_unchecked(ptr) }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::MaybeUninit;
+
+    use crate::Slot;
+
+    #[test]
+    fn split_at_mut() {
+        let mut bytes = MaybeUninit::<[u8; 4]>::uninit();
+        let slot = Slot::new(&mut bytes).unsize();
+
+        let (mut left, right) = slot.split_at_mut(1);
+        assert_eq!(left.len(), 1);
+        assert_eq!(right.len(), 3);
+        left.zero();
+        // SAFETY: `right` points to three `u8`s.
+        unsafe {
+            right.as_ptr().cast::<[u8; 3]>().write([1, 2, 3]);
+        }
+
+        // SAFETY: Both halves of `bytes` have been initialized.
+        let bytes = unsafe { bytes.assume_init() };
+        assert_eq!(bytes, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut values = MaybeUninit::<[u32; 4]>::uninit();
+        let mut slot = Slot::new(&mut values).unsize();
+
+        let iter = slot.iter_mut();
+        assert_eq!(iter.len(), 4);
+        for (i, mut element) in (0..).zip(iter) {
+            element.write(i * 10);
+        }
+        let last = slot.into_iter().next_back().unwrap();
+        // SAFETY: Every element of `values` has been initialized.
+        assert_eq!(unsafe { *last.assume_init_ref() }, 30);
+
+        // SAFETY: Every element of `values` has been initialized.
+        let values = unsafe { values.assume_init() };
+        assert_eq!(values, [0, 10, 20, 30]);
+    }
+
+    #[test]
+    fn cast_slice() {
+        let mut values = MaybeUninit::<[u32; 4]>::uninit();
+        let bytes = Slot::new(&mut values).as_bytes();
+
+        let (_, misaligned) = bytes.split_at_mut(1);
+        assert!(misaligned.cast_slice::<u32>().is_none());
+
+        let bytes = Slot::new(&mut values).as_bytes();
+        let (uneven, _) = bytes.split_at_mut(6);
+        assert!(uneven.cast_slice::<u32>().is_none());
+
+        let bytes = Slot::new(&mut values).as_bytes();
+        assert!(bytes.cast_slice::<()>().is_none());
+
+        let bytes = Slot::new(&mut values).as_bytes();
+        let mut slot = bytes.cast_slice::<u32>().unwrap();
+        assert_eq!(slot.len(), 4);
+        slot.fill(7);
+
+        // SAFETY: Every element of `values` has been initialized.
+        let values = unsafe { values.assume_init() };
+        assert_eq!(values, [7; 4]);
+    }
+
+    #[test]
+    fn fill() {
+        let mut values = MaybeUninit::<[u32; 4]>::uninit();
+        let mut slot = Slot::new(&mut values).unsize();
+
+        slot.fill(u32::MAX);
+        slot.as_mut().get(1).write(7);
+
+        // SAFETY: Every element of `values` has been initialized.
+        let values = unsafe { values.assume_init() };
+        assert_eq!(values, [u32::MAX, 7, u32::MAX, u32::MAX]);
+    }
+}
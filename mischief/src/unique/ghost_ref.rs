@@ -26,6 +26,23 @@ impl<T> GhostRef<T> {
     }
 }
 
+impl<T> GhostRef<&mut T> {
+    /// Calls the given function with a `GhostRef` to a mutable borrow of the
+    /// given value.
+    ///
+    /// Unlike [`leak`](GhostRef::leak), the borrow only lasts for the duration
+    /// of the function and cannot escape it. After the function returns, the
+    /// value may be borrowed again. This allows a single unique value to be
+    /// reused for several `GhostRef`s in sequence.
+    #[inline]
+    pub fn scope<R, F>(value: &mut T, f: F) -> R
+    where
+        F: for<'scope> FnOnce(GhostRef<&'scope mut T>) -> R,
+    {
+        f(GhostRef::leak(value))
+    }
+}
+
 // SAFETY: Because `GhostRef` can only be constructed by leaking a `T`, and `T`
 // is guaranteed to be `Unique`, the `GhostRef` is also `Unique`.
 unsafe impl<T: Unique> Unique for GhostRef<T> {}
@@ -33,3 +50,28 @@ unsafe impl<T: Unique> Unique for GhostRef<T> {}
 // SAFETY: Because `GhostRef` can only be constructed by leaking a `T`, and `T`
 // is guaranteed to be a `Singleton`, the `GhostRef` is also a `Singleton`.
 unsafe impl<T: Singleton> Singleton for GhostRef<T> {}
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::size_of_val;
+
+    use crate::{GhostRef, StaticToken, Unique};
+
+    #[test]
+    fn scope() {
+        fn assert_unique<T: Unique>(_: &T) {}
+
+        StaticToken::acquire(|mut token| {
+            // The token can be reborrowed for a new `GhostRef` once each scope
+            // ends.
+            for i in 0..2 {
+                let result = GhostRef::scope(&mut token, |ghost| {
+                    assert_unique(&ghost);
+                    assert_eq!(size_of_val(&ghost), 0);
+                    i * 2
+                });
+                assert_eq!(result, i * 2);
+            }
+        });
+    }
+}
@@ -104,6 +104,104 @@ macro_rules! runtime_token {
     };
 }
 
+/// Creates a pool of `N` tokens with fresh types that are each checked for
+/// uniqueness at runtime.
+///
+/// The generated type takes a const generic index `I`, and the token for each
+/// index less than `N` is a distinct type. Each of them can be acquired once at
+/// a time, independently of the others. Attempting to acquire a token with an
+/// index of `N` or greater fails to compile.
+#[macro_export]
+macro_rules! runtime_token_pool {
+    ($name:ident, $n:expr) => {
+        $crate::runtime_token_pool!(@impl $name, $n;);
+    };
+    (pub $name:ident, $n:expr) => {
+        $crate::runtime_token_pool!(@impl $name, $n; pub);
+    };
+    (pub ($($vis:tt)*) $name:ident, $n:expr) => {
+        $crate::runtime_token_pool!(@impl $name, $n; pub($($vis)*));
+    };
+    (@impl $name:ident, $n:expr; $($vis:tt)*) => {
+        #[repr(transparent)]
+        $($vis)* struct $name<const I: usize>(::core::marker::PhantomData<()>);
+
+        const _: () = {
+            const N: usize = $n;
+
+            #[allow(clippy::declare_interior_mutable_const)]
+            const FREE: ::core::sync::atomic::AtomicBool =
+                ::core::sync::atomic::AtomicBool::new(false);
+            static ALIVE: [::core::sync::atomic::AtomicBool; N] = [FREE; N];
+
+            impl<const I: usize> Drop for $name<I> {
+                #[inline]
+                fn drop(&mut self) {
+                    ALIVE[I].compare_exchange(
+                        true,
+                        false,
+                        ::core::sync::atomic::Ordering::AcqRel,
+                        ::core::sync::atomic::Ordering::Acquire,
+                    ).unwrap();
+                }
+            }
+
+            impl<const I: usize> $name<I> {
+                const IN_BOUNDS: () =
+                    assert!(I < N, "token index is out of bounds for the pool");
+
+                /// The number of distinct tokens in the pool.
+                pub const POOL_SIZE: usize = N;
+
+                /// Acquires the token.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the token is still acquired elsewhere.
+                #[inline]
+                pub fn acquire() -> Self {
+                    Self::try_acquire().unwrap()
+                }
+
+                /// Attempts to acquire the token.
+                ///
+                /// Returns an error if the token is still acquired elsewhere.
+                #[inline]
+                pub fn try_acquire() ->
+                    ::core::result::Result<Self, $crate::RuntimeTokenError>
+                {
+                    #[allow(clippy::let_unit_value)]
+                    let () = Self::IN_BOUNDS;
+
+                    let result = ALIVE[I].compare_exchange(
+                        false,
+                        true,
+                        ::core::sync::atomic::Ordering::AcqRel,
+                        ::core::sync::atomic::Ordering::Acquire,
+                    );
+
+                    match result {
+                        Ok(_) => ::core::result::Result::Ok(
+                            $name(::core::marker::PhantomData),
+                        ),
+                        Err(_) => ::core::result::Result::Err(
+                            $crate::RuntimeTokenError,
+                        ),
+                    }
+                }
+            }
+
+            // SAFETY: `$name<I>` can only be constructed by flipping
+            // `ALIVE[I]` from `false` to `true`, which can only happen one at a
+            // time. Therefore, only one `$name<I>` can exist at a time. The
+            // token will flip it back to `false` when it is dropped, which
+            // destroys the unique value. Each index has its own flag, so tokens
+            // with different indices are independent of each other.
+            unsafe impl<const I: usize> $crate::Unique for $name<I> {}
+        };
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Unique;
@@ -142,4 +240,21 @@ mod tests {
         drop(foo);
         drop(bar);
     }
+
+    #[test]
+    fn runtime_token_pool() {
+        runtime_token_pool!(Pool, 2);
+        assert_unique::<Pool<0>>();
+        assert_unique::<Pool<1>>();
+        assert_eq!(Pool::<0>::POOL_SIZE, 2);
+
+        let first: Pool<0> = Pool::acquire();
+        let second: Pool<1> = Pool::acquire();
+        assert!(matches!(Pool::<0>::try_acquire(), Err(_)));
+        assert!(matches!(Pool::<1>::try_acquire(), Err(_)));
+        drop(first);
+        assert!(matches!(Pool::<0>::try_acquire(), Ok(_)));
+        assert!(matches!(Pool::<1>::try_acquire(), Err(_)));
+        drop(second);
+    }
 }
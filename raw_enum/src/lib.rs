@@ -25,7 +25,9 @@ use ::syn::{
     Fields,
     Generics,
     Ident,
+    Index,
     Variant,
+    Visibility,
 };
 
 /// The identifiers of the generated items.
@@ -84,6 +86,28 @@ impl RawIdents {
     }
 }
 
+/// Options for generating a raw enum.
+pub struct RawEnumOptions {
+    /// The visibility of the generated items. Defaults to private.
+    pub vis: Visibility,
+    /// Whether to generate `extern "C"` accessors for the discriminant and
+    /// fields of the enum.
+    pub ffi: bool,
+    /// The prefix to use for the names of the `extern "C"` accessors. Defaults
+    /// to the snake-case name of the enum.
+    pub ffi_prefix: Option<String>,
+}
+
+impl Default for RawEnumOptions {
+    fn default() -> Self {
+        Self {
+            vis: Visibility::Inherited,
+            ffi: false,
+            ffi_prefix: None,
+        }
+    }
+}
+
 /// A generated raw enum.
 pub struct RawEnum {
     /// The identifiers for the generated items.
@@ -95,6 +119,14 @@ pub struct RawEnum {
 impl RawEnum {
     /// Generates a raw enum for the given derive input.
     pub fn for_derive(input: &DeriveInput) -> Result<RawEnum, Error> {
+        Self::for_derive_with(input, &RawEnumOptions::default())
+    }
+
+    /// Generates a raw enum for the given derive input with some options.
+    pub fn for_derive_with(
+        input: &DeriveInput,
+        options: &RawEnumOptions,
+    ) -> Result<RawEnum, Error> {
         let idents = RawIdents::for_enum(&input.ident);
 
         let mut repr = None;
@@ -137,12 +169,25 @@ impl RawEnum {
             )
         })?;
 
+        // `repr(C)` enums without a primitive type have the same discriminant
+        // size as a C enum, which is usually an `int`.
+        let discriminant_ty = match (&base.kind, &repr.primitive_type) {
+            (BaseKind::Primitive(int), _)
+            | (_, Some(PrimitiveType { int, .. })) => quote! { #int },
+            _ => quote! { ::core::ffi::c_int },
+        };
+
+        let vis = &options.vis;
         let tokens = match base.kind {
-            BaseKind::C => {
-                derive_c(data, &input.generics, repr.primitive_type, &idents)?
-            }
+            BaseKind::C => derive_c(
+                data,
+                &input.generics,
+                repr.primitive_type,
+                vis,
+                &idents,
+            )?,
             BaseKind::Primitive(int) => {
-                derive_primitive(data, &input.generics, int, &idents)?
+                derive_primitive(data, &input.generics, int, vis, &idents)?
             }
             BaseKind::Transparent => {
                 return Err(Error::new_spanned(
@@ -165,10 +210,27 @@ impl RawEnum {
         let (impl_generics, ty_generics, where_clause) =
             input.generics.split_for_impl();
 
+        let ffi = if options.ffi {
+            let prefix = options
+                .ffi_prefix
+                .clone()
+                .unwrap_or_else(|| pascal_to_snake(&input_ident.to_string()));
+            Some(generate_ffi(
+                input,
+                data,
+                &discriminant_ty,
+                &prefix,
+                &idents,
+            )?)
+        } else {
+            None
+        };
+
         let tokens = quote! {
             #tokens
+            #ffi
 
-            fn #raw_enum_fn_ident #impl_generics (
+            #vis fn #raw_enum_fn_ident #impl_generics (
                 this: *mut #input_ident #ty_generics,
             ) -> *mut #raw_enum_ident #ty_generics
             #where_clause
@@ -187,7 +249,7 @@ impl RawEnum {
                 ::core::mem::ManuallyDrop::into_inner(unsafe { reinterpret.to })
             }
 
-            fn #discriminant_fn_ident #impl_generics (
+            #vis fn #discriminant_fn_ident #impl_generics (
                 this: *mut #raw_enum_ident #ty_generics,
             ) -> *mut #discriminant_ident
             #where_clause
@@ -200,9 +262,119 @@ impl RawEnum {
     }
 }
 
+fn generate_ffi(
+    input: &DeriveInput,
+    data: &DataEnum,
+    discriminant_ty: &TokenStream,
+    prefix: &str,
+    idents: &RawIdents,
+) -> Result<TokenStream, Error> {
+    let input_ident = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            format!(
+                "`extern \"C\"` accessors can't be generated for `{}` \
+                 because it is generic",
+                input_ident,
+            ),
+        ));
+    }
+
+    let raw_enum_fn_ident = &idents.raw_enum_fn;
+    let discriminant_fn_ident = &idents.discriminant_fn;
+    let variant_fn_ident = &idents.variant_fn;
+    let variants_ident = &idents.variants;
+
+    let safety_doc = format!(
+        "`this` must be non-null, properly aligned, and point to a valid \
+         `{input_ident}`.",
+    );
+
+    let discriminant_accessor =
+        Ident::new(&format!("{prefix}_discriminant"), input_ident.span());
+    let discriminant_doc =
+        format!("Returns the discriminant of a `{input_ident}`.");
+
+    let safety_doc = &safety_doc;
+    let field_accessors = data.variants.iter().flat_map(|v| {
+        let variant_ident = &v.ident;
+        let variant_prefix =
+            format!("{prefix}_{}", pascal_to_snake(&variant_ident.to_string()));
+        v.fields.iter().enumerate().map(move |(i, f)| {
+            let ty = &f.ty;
+            let (name, member) = match &f.ident {
+                Some(ident) => (ident.to_string(), quote! { #ident }),
+                None => {
+                    // The tag is the first element of tuple variant structs, so
+                    // we have to skip over it.
+                    let index = Index::from(i + 1);
+                    (i.to_string(), quote! { #index })
+                }
+            };
+            let accessor = Ident::new(
+                &format!("{variant_prefix}_{name}"),
+                variant_ident.span(),
+            );
+            let doc = format!(
+                "Returns a pointer to the `{name}` field of a \
+                 `{input_ident}::{variant_ident}`, or null if it is a \
+                 different variant.",
+            );
+
+            quote! {
+                #[doc = #doc]
+                #[doc = ""]
+                #[doc = "# Safety"]
+                #[doc = ""]
+                #[doc = #safety_doc]
+                #[no_mangle]
+                pub unsafe extern "C" fn #accessor(
+                    this: *const #input_ident,
+                ) -> *const #ty {
+                    let raw = #raw_enum_fn_ident(this.cast_mut());
+                    match #variant_fn_ident(raw) {
+                        #variants_ident::#variant_ident(variant) => {
+                            // SAFETY: The caller has guaranteed that `this`
+                            // points to a valid enum, and it has this variant.
+                            unsafe {
+                                ::core::ptr::addr_of!((*variant).#member)
+                            }
+                        }
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::ptr::null(),
+                    }
+                }
+            }
+        })
+    });
+
+    Ok(quote! {
+        #[doc = #discriminant_doc]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        #[doc = ""]
+        #[doc = #safety_doc]
+        #[no_mangle]
+        #[allow(clippy::as_conversions)]
+        pub unsafe extern "C" fn #discriminant_accessor(
+            this: *const #input_ident,
+        ) -> #discriminant_ty {
+            let raw = #raw_enum_fn_ident(this.cast_mut());
+            // SAFETY: The caller has guaranteed that `this` points to a valid
+            // enum, so its discriminant is initialized.
+            unsafe { *#discriminant_fn_ident(raw) as #discriminant_ty }
+        }
+
+        #(#field_accessors)*
+    })
+}
+
 fn generate_discriminant(
     data: &DataEnum,
     base: BaseKind,
+    vis: &Visibility,
     idents: &RawIdents,
 ) -> TokenStream {
     let discriminant = &idents.discriminant;
@@ -220,7 +392,7 @@ fn generate_discriminant(
         #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
         #[repr(#base)]
         #[allow(dead_code)]
-        enum #discriminant {
+        #vis enum #discriminant {
             #(#variants),*
         }
     }
@@ -229,15 +401,19 @@ fn generate_discriminant(
 fn generate_variant_struct(
     variant: &Variant,
     generics: &Generics,
+    vis: &Visibility,
     idents: &RawIdents,
     with_tag: bool,
 ) -> TokenStream {
     let discriminant_ident = &idents.discriminant;
     let variant_struct_ident = idents.variant(&variant.ident);
 
+    // Every lifetime and type parameter has to be used by each variant struct,
+    // even if the variant doesn't have any fields which use them.
+    let lifetimes = generics.lifetimes().map(|l| &l.lifetime);
     let type_params = generics.type_params().map(|p| &p.ident);
     let phantom_ty = quote! {
-        ::core::marker::PhantomData<(#(#type_params,)*)>
+        ::core::marker::PhantomData<(#(&#lifetimes (),)* #(#type_params,)*)>
     };
     let (impl_generics, _, where_clause) = generics.split_for_impl();
 
@@ -260,7 +436,7 @@ fn generate_variant_struct(
             quote! {
                 #[repr(C)]
                 #[allow(non_snake_case)]
-                struct #variant_struct_ident #impl_generics #where_clause {
+                #vis struct #variant_struct_ident #impl_generics #where_clause {
                     __tag: #tag_ty,
                     #(#fields,)*
                     _phantom: #phantom_ty,
@@ -278,7 +454,7 @@ fn generate_variant_struct(
             quote! {
                 #[repr(C)]
                 #[allow(non_snake_case)]
-                struct #variant_struct_ident #impl_generics (
+                #vis struct #variant_struct_ident #impl_generics (
                     #tag_ty,
                     #(#fields,)*
                     #phantom_ty,
@@ -289,7 +465,7 @@ fn generate_variant_struct(
             quote! {
                 #[repr(C)]
                 #[allow(non_snake_case)]
-                struct #variant_struct_ident #impl_generics (
+                #vis struct #variant_struct_ident #impl_generics (
                     #tag_ty,
                     #phantom_ty,
                 ) #where_clause;
@@ -301,6 +477,7 @@ fn generate_variant_struct(
 fn generate_variants(
     data: &DataEnum,
     generics: &Generics,
+    vis: &Visibility,
     idents: &RawIdents,
 ) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -315,7 +492,7 @@ fn generate_variants(
 
     quote! {
         #[allow(dead_code)]
-        enum #variants_ident #impl_generics #where_clause {
+        #vis enum #variants_ident #impl_generics #where_clause {
             #(#variants,)*
         }
     }
@@ -325,6 +502,7 @@ fn derive_c(
     data: &DataEnum,
     generics: &Generics,
     primitive: Option<PrimitiveType>,
+    vis: &Visibility,
     idents: &RawIdents,
 ) -> Result<TokenStream, Error> {
     let discriminant = generate_discriminant(
@@ -332,12 +510,13 @@ fn derive_c(
         primitive
             .map(|p| BaseKind::Primitive(p.int))
             .unwrap_or(BaseKind::C),
+        vis,
         idents,
     );
     let variant_structs = data.variants.iter().map(|variant| {
-        generate_variant_struct(variant, generics, idents, false)
+        generate_variant_struct(variant, generics, vis, idents, false)
     });
-    let variants = generate_variants(data, generics, idents);
+    let variants = generate_variants(data, generics, vis, idents);
 
     let raw_ident = &idents.r#enum;
     let discriminant_ident = &idents.discriminant;
@@ -372,7 +551,7 @@ fn derive_c(
 
         #[repr(C)]
         #[allow(non_snake_case)]
-        union #fields_ident #impl_generics #where_clause {
+        #vis union #fields_ident #impl_generics #where_clause {
             #(
                 pub #variant_idents: ::core::mem::ManuallyDrop<
                     #variant_struct_idents #ty_generics,
@@ -381,14 +560,14 @@ fn derive_c(
         }
 
         #[repr(C)]
-        struct #raw_ident #impl_generics #where_clause {
+        #vis struct #raw_ident #impl_generics #where_clause {
             pub __tag: #discriminant_ident,
             pub fields: #fields_ident #ty_generics,
         }
 
         #variants
 
-        fn #variant_fn_ident #impl_generics (
+        #vis fn #variant_fn_ident #impl_generics (
             this: *mut #raw_ident #ty_generics,
         ) -> #variants_ident #ty_generics
         #where_clause
@@ -404,14 +583,15 @@ fn derive_primitive(
     data: &DataEnum,
     generics: &Generics,
     int: Int,
+    vis: &Visibility,
     idents: &RawIdents,
 ) -> Result<TokenStream, Error> {
     let discriminant =
-        generate_discriminant(data, BaseKind::Primitive(int), idents);
+        generate_discriminant(data, BaseKind::Primitive(int), vis, idents);
     let variant_structs = data.variants.iter().map(|variant| {
-        generate_variant_struct(variant, generics, idents, true)
+        generate_variant_struct(variant, generics, vis, idents, true)
     });
-    let variants = generate_variants(data, generics, idents);
+    let variants = generate_variants(data, generics, vis, idents);
 
     let raw_ident = &idents.r#enum;
     let discriminant_ident = &idents.discriminant;
@@ -445,7 +625,7 @@ fn derive_primitive(
 
         #[repr(C)]
         #[allow(non_snake_case)]
-        union #raw_ident #impl_generics #where_clause {
+        #vis union #raw_ident #impl_generics #where_clause {
             #(
                 pub #variant_idents: ::core::mem::ManuallyDrop<
                     #variant_struct_idents #ty_generics,
@@ -455,7 +635,7 @@ fn derive_primitive(
 
         #variants
 
-        fn #variant_fn_ident #impl_generics (
+        #vis fn #variant_fn_ident #impl_generics (
             this: *mut #raw_ident #ty_generics,
         ) -> #variants_ident #ty_generics
         #where_clause
@@ -13,23 +13,93 @@
 
 use ::proc_macro2::TokenStream;
 use ::quote::ToTokens;
-use ::raw_enum::RawEnum;
-use ::syn::{parse_macro_input, DeriveInput, Error};
+use ::raw_enum::{RawEnum, RawEnumOptions};
+use ::syn::{
+    parse_macro_input,
+    AttributeArgs,
+    DeriveInput,
+    Error,
+    Lit,
+    Meta,
+    NestedMeta,
+};
 
 /// Generates a raw version of an enum.
+///
+/// # Arguments
+///
+/// - `vis = "pub(crate)"`: Sets the visibility of the generated items so that
+///   they can be used outside of the enum's module. By default, they're
+///   private.
+/// - `ffi`: Also generates `#[no_mangle] extern "C"` accessors for the
+///   discriminant and fields of the enum, so that C code can read them out of a
+///   buffer. The discriminant accessor is named `<prefix>_discriminant`, and
+///   the field accessors are named `<prefix>_<variant>_<field>`. Tuple fields
+///   are named by their index. Field accessors return null if the enum is a
+///   different variant. Only non-generic enums can have accessors generated.
+/// - `ffi = "prefix"`: Like `ffi`, but uses the given prefix for the names of
+///   the accessors instead of the snake-case name of the enum.
 #[proc_macro_attribute]
 pub fn raw_enum(
-    _attr: proc_macro::TokenStream,
+    attr: proc_macro::TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(attr as AttributeArgs);
     let input = parse_macro_input!(input as DeriveInput);
-    generate_raw_enum(&input)
+    generate_raw_enum(&args, &input)
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
 
-fn generate_raw_enum(input: &DeriveInput) -> Result<TokenStream, Error> {
-    let raw_enum = RawEnum::for_derive(input)?;
+fn parse_options(args: &[NestedMeta]) -> Result<RawEnumOptions, Error> {
+    let mut options = RawEnumOptions::default();
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("ffi") => {
+                options.ffi = true;
+            }
+            NestedMeta::Meta(Meta::NameValue(name_value))
+                if name_value.path.is_ident("vis") =>
+            {
+                if let Lit::Str(vis) = &name_value.lit {
+                    options.vis = vis.parse()?;
+                } else {
+                    return Err(Error::new_spanned(
+                        &name_value.lit,
+                        "expected a string literal",
+                    ));
+                }
+            }
+            NestedMeta::Meta(Meta::NameValue(name_value))
+                if name_value.path.is_ident("ffi") =>
+            {
+                if let Lit::Str(prefix) = &name_value.lit {
+                    options.ffi = true;
+                    options.ffi_prefix = Some(prefix.value());
+                } else {
+                    return Err(Error::new_spanned(
+                        &name_value.lit,
+                        "expected a string literal",
+                    ));
+                }
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    arg,
+                    "unrecognized `raw_enum` argument",
+                ))
+            }
+        }
+    }
+    Ok(options)
+}
+
+fn generate_raw_enum(
+    args: &[NestedMeta],
+    input: &DeriveInput,
+) -> Result<TokenStream, Error> {
+    let options = parse_options(args)?;
+    let raw_enum = RawEnum::for_derive_with(input, &options)?;
 
     let mut result = input.to_token_stream();
     result.extend(raw_enum.tokens);
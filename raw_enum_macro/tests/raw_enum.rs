@@ -0,0 +1,108 @@
+use ::raw_enum_macro::raw_enum;
+
+#[test]
+fn ffi() {
+    #[raw_enum(ffi = "test_raw_enum_ffi_shape")]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Shape {
+        Circle { radius: u32 },
+        Rect(u32, u32),
+        Empty,
+    }
+
+    let circle = Shape::Circle { radius: 3 };
+    let rect = Shape::Rect(4, 5);
+    let empty = Shape::Empty;
+
+    // SAFETY: All of the pointers passed to the accessors point to valid
+    // `Shape`s.
+    unsafe {
+        assert_eq!(test_raw_enum_ffi_shape_discriminant(&circle), 0);
+        assert_eq!(test_raw_enum_ffi_shape_discriminant(&rect), 1);
+        assert_eq!(test_raw_enum_ffi_shape_discriminant(&empty), 2);
+
+        assert_eq!(*test_raw_enum_ffi_shape_circle_radius(&circle), 3);
+        assert!(test_raw_enum_ffi_shape_circle_radius(&rect).is_null());
+        assert_eq!(*test_raw_enum_ffi_shape_rect_0(&rect), 4);
+        assert_eq!(*test_raw_enum_ffi_shape_rect_1(&rect), 5);
+        assert!(test_raw_enum_ffi_shape_rect_1(&empty).is_null());
+    }
+}
+
+#[test]
+fn vis() {
+    mod shapes {
+        use ::raw_enum_macro::raw_enum;
+
+        #[raw_enum(vis = "pub(super)")]
+        #[repr(u8)]
+        #[allow(dead_code)]
+        pub enum Shape {
+            Circle { radius: u32 },
+            Rect(u32, u32),
+        }
+    }
+
+    let mut rect = shapes::Shape::Rect(4, 5);
+    let raw = shapes::raw_shape(&mut rect);
+    match shapes::raw_shape_variant(raw) {
+        shapes::RawShapeVariants::Rect(variant) => {
+            // SAFETY: `variant` points to the fields of `rect`, which is a
+            // valid `Shape::Rect`.
+            let (width, height) = unsafe { ((*variant).1, (*variant).2) };
+            assert_eq!((width, height), (4, 5));
+        }
+        shapes::RawShapeVariants::Circle(_) => panic!("expected a rect"),
+    }
+}
+
+#[test]
+fn where_clause() {
+    use ::core::fmt::Debug;
+
+    trait Named {
+        fn name(&self) -> &'static str;
+    }
+
+    impl Named for u32 {
+        fn name(&self) -> &'static str {
+            "u32"
+        }
+    }
+
+    #[raw_enum]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Either<'a, T>
+    where
+        T: Debug + Named + 'a,
+    {
+        Borrowed(&'a T),
+        Owned { value: T },
+        Neither,
+    }
+
+    let value = 42u32;
+    let mut borrowed = Either::Borrowed(&value);
+    match raw_either_variant(raw_either(&mut borrowed)) {
+        RawEitherVariants::Borrowed(variant) => {
+            // SAFETY: `variant` points to the fields of `borrowed`, which is a
+            // valid `Either::Borrowed`.
+            let inner = unsafe { (*variant).1 };
+            assert_eq!(inner.name(), "u32");
+            assert_eq!(*inner, 42);
+        }
+        _ => panic!("expected a borrowed value"),
+    }
+
+    let mut owned: Either<'_, u32> = Either::Owned { value: 7 };
+    match raw_either_variant(raw_either(&mut owned)) {
+        RawEitherVariants::Owned(variant) => {
+            // SAFETY: `variant` points to the fields of `owned`, which is a
+            // valid `Either::Owned`.
+            assert_eq!(unsafe { (*variant).value }, 7);
+        }
+        _ => panic!("expected an owned value"),
+    }
+}
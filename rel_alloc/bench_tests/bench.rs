@@ -1,5 +1,7 @@
+mod extend;
 pub mod from_data;
 pub mod gen;
+mod grow;
 mod log;
 mod mc_savedata;
 mod mesh;
@@ -24,6 +26,24 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("populate_mc_savedata", |b| {
         b.iter(&mut bench);
     });
+
+    let mut bench = extend::make_bench(1_000_000);
+    println!("extend_filtered         size:   {} bytes", bench());
+    c.bench_function("extend_filtered", |b| {
+        b.iter(&mut bench);
+    });
+
+    let mut bench = grow::make_bench_bytewise(250_000);
+    println!("grow_bytewise           size:   {} bytes", bench());
+    c.bench_function("grow_bytewise", |b| {
+        b.iter(&mut bench);
+    });
+
+    let mut bench = grow::make_bench_elementwise(250_000);
+    println!("grow_elementwise        size:   {} bytes", bench());
+    c.bench_function("grow_elementwise", |b| {
+        b.iter(&mut bench);
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
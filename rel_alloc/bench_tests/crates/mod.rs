@@ -0,0 +1 @@
+mod rel_alloc;
@@ -0,0 +1,883 @@
+use crate::fixture::with_slab;
+
+#[test]
+fn test_emplace_byte_array() {
+    use ::rel_alloc::EmplaceIn;
+
+    let uuid = [
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68,
+        0x0e, 0x5f, 0xe0, 0xc8,
+    ];
+
+    with_slab(256, |alloc| {
+        let emplaced = uuid.emplace_in::<[u8; 16]>(alloc);
+        assert_eq!(*emplaced, uuid);
+    });
+}
+
+#[test]
+fn test_extend_without_size_hint() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(1024, |alloc| {
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        RelVec::extend(values.as_mut(), (0..30).filter(|i| i % 3 != 0));
+
+        assert_eq!(values.len(), 20);
+        assert!(values.capacity() >= 20);
+        for (i, value) in RelVec::iter_enumerated(values.as_ref()) {
+            assert_eq!(
+                value.to_ne(),
+                u32::try_from(i / 2 * 3 + i % 2 + 1).unwrap()
+            );
+        }
+    });
+}
+
+#[test]
+fn test_extend_emplace_panic() {
+    use ::mischief::{In, Region, Slot};
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::{Emplace, EmplaceExt, U32};
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct PanicOn(u32, u32);
+
+    unsafe impl<R: Region> Emplace<U32, R> for PanicOn {
+        fn emplaced_meta(&self) -> <U32 as ptr_meta::Pointee>::Metadata {}
+
+        unsafe fn emplace_unsized_unchecked(self, out: In<Slot<'_, U32>, R>) {
+            if self.0 == self.1 {
+                panic!("failed to emplace {}", self.0);
+            }
+            self.0.emplace(out);
+        }
+    }
+
+    with_slab(1024, |alloc| {
+        let mut values = vec::WithCapacity(alloc, 8)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            RelVec::extend(values.as_mut(), (0..8).map(|i| PanicOn(i, 3)));
+        }));
+        assert!(result.is_err());
+
+        // Only the values emplaced before the panic are counted.
+        assert_eq!(values.len(), 3);
+        for (i, value) in RelVec::iter_enumerated(values.as_ref()) {
+            assert_eq!(value.to_ne(), u32::try_from(i).unwrap());
+        }
+    });
+}
+
+#[test]
+fn test_with_capacity_zeroed() {
+    use ::mischief::StaticToken;
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_slab_allocator::{RelSlabAllocator, SlabAllocator};
+    use ::rel_util::Align16;
+
+    // Fill the buffer with garbage so zeroed storage can't come from the
+    // buffer itself.
+    let mut bytes = Align16::frame(256);
+    bytes.slot().as_bytes().fill(0xff);
+
+    StaticToken::acquire(|token| {
+        let alloc =
+            SlabAllocator::try_new_in(bytes.slot().as_bytes(), token).unwrap();
+        let mut values = vec::WithCapacityZeroed(alloc, 16)
+            .emplace_in::<RelVec<u8, RelSlabAllocator<_>>>(alloc);
+        assert_eq!(values.len(), 0);
+        assert_eq!(values.capacity(), 16);
+
+        // SAFETY: 16 is the capacity of `values`, and every element was
+        // zeroed. Zero is a valid `u8`.
+        unsafe {
+            RelVec::set_len(values.as_mut(), 16);
+        }
+        assert_eq!(*RelVec::as_slice(values.as_ref()), [0; 16]);
+
+        // Growing past the initial capacity keeps the zeroed elements.
+        RelVec::push(values.as_mut(), 1u8);
+        assert_eq!(values.len(), 17);
+        assert_eq!(RelVec::as_slice(values.as_ref())[..16], [0; 16]);
+    });
+}
+
+#[test]
+fn test_rel_vec_macro() {
+    use ::rel_alloc::{rel_string, rel_vec, EmplaceIn, RelString, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    type Alloc<'a, U> = RelSlabAllocator<'a, U>;
+
+    with_slab(1024, |alloc| {
+        let empty =
+            rel_vec![in alloc].emplace_in::<RelVec<U32, Alloc<_>>>(alloc);
+        assert!(empty.is_empty());
+
+        let numbers = rel_vec![in alloc; 1u32, 2, 3]
+            .emplace_in::<RelVec<U32, Alloc<_>>>(alloc);
+        assert_eq!(format!("{:?}", numbers), "[1, 2, 3]");
+
+        let strings = rel_vec![in alloc;
+            rel_string!(in alloc; "a"),
+            rel_string!(in alloc; "b"),
+            rel_string!(in alloc; "c"),
+        ]
+        .emplace_in::<RelVec<RelString<Alloc<_>>, Alloc<_>>>(alloc);
+        assert_eq!(format!("{:?}", strings), r#"["a", "b", "c"]"#);
+    });
+}
+
+#[test]
+fn test_rel_small_vec() {
+    use ::rel_alloc::{small_vec, EmplaceIn, RelSmallVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    type SmallVec<'a, U> = RelSmallVec<U32, 4, RelSlabAllocator<'a, U>>;
+
+    with_slab(1024, |alloc| {
+        let mut values =
+            small_vec::New(alloc).emplace_in::<SmallVec<'_, _>>(alloc);
+        assert!(values.is_empty());
+        assert!(!values.spilled());
+        assert_eq!(values.capacity(), 4);
+
+        RelSmallVec::extend(values.as_mut(), 0u32..4);
+        assert!(!values.spilled());
+        assert_eq!(format!("{:?}", values), "[0, 1, 2, 3]");
+
+        RelSmallVec::push(values.as_mut(), 4u32);
+        assert!(values.spilled());
+        assert_eq!(values.len(), 5);
+        assert_eq!(values.capacity(), 8);
+        assert_eq!(format!("{:?}", values), "[0, 1, 2, 3, 4]");
+
+        RelSmallVec::clear(values.as_mut());
+        assert!(values.is_empty());
+        assert!(values.spilled());
+
+        let spilled = small_vec::WithCapacity(alloc, 6)
+            .emplace_in::<SmallVec<'_, _>>(alloc);
+        assert!(spilled.spilled());
+        assert_eq!(spilled.capacity(), 6);
+    });
+}
+
+#[test]
+fn test_rel_small_vec_try_reserve() {
+    use ::core::alloc::Layout;
+    use ::rel_alloc::{
+        alloc::TryReserveError,
+        small_vec,
+        EmplaceIn,
+        RelSmallVec,
+    };
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    type SmallVec<'a, U> = RelSmallVec<U32, 4, RelSlabAllocator<'a, U>>;
+
+    with_slab(256, |alloc| {
+        let mut values =
+            small_vec::New(alloc).emplace_in::<SmallVec<'_, _>>(alloc);
+        RelSmallVec::extend(values.as_mut(), 0u32..3);
+
+        assert_eq!(
+            RelSmallVec::try_reserve(values.as_mut(), usize::MAX),
+            Err(TryReserveError::CapacityOverflow),
+        );
+        assert_eq!(
+            RelSmallVec::try_reserve(values.as_mut(), 1 << 20),
+            Err(TryReserveError::AllocError {
+                layout: Layout::array::<U32>(1 << 21).unwrap(),
+            }),
+        );
+        assert!(!values.spilled());
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+
+        assert_eq!(RelSmallVec::try_reserve(values.as_mut(), 1), Ok(()));
+        assert!(!values.spilled());
+        assert_eq!(RelSmallVec::try_reserve(values.as_mut(), 5), Ok(()));
+        assert!(values.spilled());
+        assert!(values.capacity() >= 8);
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+
+        assert_eq!(
+            RelSmallVec::try_reserve(values.as_mut(), 1 << 20),
+            Err(TryReserveError::AllocError {
+                layout: Layout::array::<U32>(1 << 21).unwrap(),
+            }),
+        );
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+    });
+}
+
+#[test]
+fn test_rel_vec_iter_mut() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        RelVec::extend(values.as_mut(), 0u32..5);
+
+        let iter = RelVec::iter_mut(values.as_mut());
+        assert_eq!(iter.len(), 5);
+        for mut value in iter {
+            *value = U32::from_ne(value.to_ne() * 10);
+        }
+        assert_eq!(format!("{:?}", values), "[0, 10, 20, 30, 40]");
+
+        let mut iter = RelVec::iter_mut(values.as_mut());
+        let mut last = iter.next_back().unwrap();
+        *last = U32::from_ne(1);
+        let mut first = iter.next().unwrap();
+        *first = U32::from_ne(2);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(format!("{:?}", values), "[2, 10, 20, 30, 1]");
+    });
+}
+
+#[test]
+fn test_rel_vec_iter() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        RelVec::extend(values.as_mut(), 0u32..5);
+
+        let iter = RelVec::iter(values.as_ref());
+        assert_eq!(iter.len(), 5);
+        let forward = iter.map(|v| v.to_ne()).collect::<Vec<_>>();
+        assert_eq!(forward, [0, 1, 2, 3, 4]);
+
+        let backward = RelVec::iter(values.as_ref())
+            .rev()
+            .map(|v| v.to_ne())
+            .collect::<Vec<_>>();
+        assert_eq!(backward, [4, 3, 2, 1, 0]);
+
+        let mut iter = RelVec::iter(values.as_ref());
+        assert_eq!(iter.next_back().unwrap().to_ne(), 4);
+        assert_eq!(iter.next().unwrap().to_ne(), 0);
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back().unwrap().to_ne(), 3);
+        assert_eq!(iter.next().unwrap().to_ne(), 1);
+        assert_eq!(iter.next().unwrap().to_ne(), 2);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    });
+}
+
+#[test]
+fn test_move_bytewise() {
+    use ::mischief::Region;
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::{DefaultBasis, Move, Portable, RelPtr, U32};
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::situ::DropRaw;
+
+    struct Any;
+    // SAFETY: `Any` is never used to locate any values.
+    unsafe impl Region for Any {}
+
+    #[derive(DropRaw, Move, Portable)]
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Point {
+        x: U32,
+        y: U32,
+    }
+
+    #[derive(DropRaw, Move, Portable)]
+    #[allow(dead_code)]
+    #[repr(C)]
+    struct Link {
+        value: U32,
+        next: RelPtr<U32, Any, DefaultBasis>,
+    }
+
+    const _: () = {
+        assert!(<U32 as Move<Any>>::BYTEWISE);
+        assert!(<[U32; 4] as Move<Any>>::BYTEWISE);
+        assert!(<[U32] as Move<Any>>::BYTEWISE);
+        assert!(<Point as Move<Any>>::BYTEWISE);
+        assert!(!<RelPtr<U32, Any, DefaultBasis> as Move<Any>>::BYTEWISE);
+        assert!(!<Link as Move<Any>>::BYTEWISE);
+        assert!(!<[Link; 4] as Move<Any>>::BYTEWISE);
+    };
+
+    with_slab(1024, |alloc| {
+        // Growing both vectors in lockstep forces them to move their elements
+        // to a new allocation every time they grow.
+        let mut evens = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        let mut odds = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        for i in 0..20u32 {
+            RelVec::push(evens.as_mut(), 2 * i);
+            RelVec::push(odds.as_mut(), 2 * i + 1);
+        }
+
+        for (i, value) in RelVec::iter_enumerated(evens.as_ref()) {
+            assert_eq!(value.to_ne(), 2 * i as u32);
+        }
+        for (i, value) in RelVec::iter_enumerated(odds.as_ref()) {
+            assert_eq!(value.to_ne(), 2 * i as u32 + 1);
+        }
+    });
+}
+
+#[test]
+fn test_rel_vec_try_reserve() {
+    use ::core::alloc::Layout;
+    use ::rel_alloc::{alloc::TryReserveError, vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        RelVec::extend(values.as_mut(), 0u32..3);
+
+        assert_eq!(
+            RelVec::try_reserve(values.as_mut(), usize::MAX),
+            Err(TryReserveError::CapacityOverflow),
+        );
+        assert_eq!(
+            RelVec::try_reserve(values.as_mut(), 1 << 20),
+            Err(TryReserveError::AllocError {
+                layout: Layout::array::<U32>(1 << 21).unwrap(),
+            }),
+        );
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+
+        assert_eq!(RelVec::try_reserve(values.as_mut(), 5), Ok(()));
+        assert!(values.capacity() >= 8);
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+    });
+}
+
+#[test]
+fn test_rel_vec_reserve_in_place() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        // The elements are the last allocation in the slab, so they grow in
+        // place.
+        let mut values = vec::WithCapacity(alloc, 2)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        let ptr = RelVec::as_ptr(values.as_ref());
+        RelVec::extend(values.as_mut(), 0u32..3);
+        assert_eq!(RelVec::as_ptr(values.as_ref()), ptr);
+        assert_eq!(values.capacity(), 4);
+        assert_eq!(format!("{:?}", values), "[0, 1, 2]");
+    });
+}
+
+#[test]
+fn test_rel_vec_contains() {
+    use ::core::cell::Cell;
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::situ::Ref;
+
+    with_slab(256, |alloc| {
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        RelVec::extend(values.as_mut(), [3u32, 1, 4, 1, 5].into_iter());
+
+        let four = U32::from_ne(4);
+        // SAFETY: `four` is a valid `U32` that is never mutated.
+        let four = unsafe { Ref::new_unchecked(&four) };
+        assert!(RelVec::contains(values.as_ref(), four));
+        let nine = U32::from_ne(9);
+        // SAFETY: `nine` is a valid `U32` that is never mutated.
+        let nine = unsafe { Ref::new_unchecked(&nine) };
+        assert!(!RelVec::contains(values.as_ref(), nine));
+
+        let visited = Cell::new(0);
+        let position = RelVec::position_by(values.as_ref(), |value| {
+            visited.set(visited.get() + 1);
+            value.to_ne() == 1
+        });
+        assert_eq!(position, Some(1));
+        assert_eq!(visited.get(), 2);
+
+        assert_eq!(
+            RelVec::position_by(values.as_ref(), |value| value.to_ne() > 9),
+            None,
+        );
+    });
+}
+
+#[test]
+fn test_rel_vec_append() {
+    use ::rel_alloc::{rel_string, rel_vec, EmplaceIn, RelString, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    type Alloc<'a, U> = RelSlabAllocator<'a, U>;
+
+    with_slab(1024, |alloc| {
+        let mut numbers = rel_vec![in alloc; 1u32, 2]
+            .emplace_in::<RelVec<U32, Alloc<_>>>(alloc);
+        let mut more = rel_vec![in alloc; 3u32, 4, 5]
+            .emplace_in::<RelVec<U32, Alloc<_>>>(alloc);
+        RelVec::append(numbers.as_mut(), more.as_mut());
+        assert_eq!(format!("{:?}", numbers), "[1, 2, 3, 4, 5]");
+        assert!(more.is_empty());
+
+        let mut strings = rel_vec![in alloc; rel_string!(in alloc; "a")]
+            .emplace_in::<RelVec<RelString<Alloc<_>>, Alloc<_>>>(alloc);
+        let mut others = rel_vec![in alloc;
+            rel_string!(in alloc; "b"),
+            rel_string!(in alloc; "c"),
+        ]
+        .emplace_in::<RelVec<RelString<Alloc<_>>, Alloc<_>>>(alloc);
+        RelVec::append(strings.as_mut(), others.as_mut());
+        assert_eq!(format!("{:?}", strings), r#"["a", "b", "c"]"#);
+        assert!(others.is_empty());
+
+        RelVec::append(strings.as_mut(), others.as_mut());
+        assert_eq!(strings.len(), 3);
+    });
+}
+
+#[test]
+fn test_rel_vec_dedup_by() {
+    use ::core::cell::Cell;
+    use ::mischief::{In, Region, Slot};
+    use ::ptr_meta::Pointee;
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::{Emplace, Move, Portable, U32};
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::situ::{DropRaw, Mut};
+
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Move, Portable)]
+    #[repr(transparent)]
+    struct Tracked(U32);
+
+    impl DropRaw for Tracked {
+        unsafe fn drop_raw(_: Mut<'_, Self>) {
+            DROPS.with(|drops| drops.set(drops.get() + 1));
+        }
+    }
+
+    // SAFETY:
+    // - `emplaced_meta` returns `()`, the only valid metadata for `Sized`
+    //   types.
+    // - `emplace_unsized_unchecked` initializes its `out` parameter by writing
+    //   to it.
+    unsafe impl<R: Region> Emplace<Tracked, R> for u32 {
+        fn emplaced_meta(&self) -> <Tracked as Pointee>::Metadata {}
+
+        unsafe fn emplace_unsized_unchecked(
+            self,
+            out: In<Slot<'_, Tracked>, R>,
+        ) {
+            In::into_inner(out).write(Tracked(U32::from_ne(self)));
+        }
+    }
+
+    fn dedup(input: &[u32]) -> (Vec<u32>, usize) {
+        with_slab(1024, |alloc| {
+            let mut values = vec::New(alloc)
+                .emplace_in::<RelVec<Tracked, RelSlabAllocator<_>>>(alloc);
+            RelVec::extend(values.as_mut(), input.iter().copied());
+
+            DROPS.with(|drops| drops.set(0));
+            RelVec::dedup_by(values.as_mut(), |a, b| a.0 == b.0);
+            let dropped = DROPS.with(|drops| drops.get());
+
+            let output = RelVec::iter(values.as_ref())
+                .map(|value| value.0.to_ne())
+                .collect();
+            (output, dropped)
+        })
+    }
+
+    assert_eq!(dedup(&[]), (vec![], 0));
+    assert_eq!(dedup(&[7, 7, 7, 7, 7]), (vec![7], 4));
+    assert_eq!(dedup(&[1, 2, 3, 4, 5]), (vec![1, 2, 3, 4, 5], 0));
+    assert_eq!(dedup(&[1, 1, 2, 3, 3, 3, 1]), (vec![1, 2, 3, 1], 3));
+}
+
+#[test]
+fn test_rel_string_reserve() {
+    use ::rel_alloc::{alloc::TryReserveError, string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut s = string::Clone(alloc, "héllo")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        // `é` is two bytes long, so the length is in bytes and not `char`s.
+        assert_eq!(s.len(), 6);
+
+        RelString::reserve(s.as_mut(), 20);
+        assert!(s.capacity() >= 26);
+        assert_eq!(&*RelString::as_str(s.as_ref()), "héllo");
+
+        assert_eq!(
+            RelString::try_reserve(s.as_mut(), usize::MAX),
+            Err(TryReserveError::CapacityOverflow),
+        );
+        assert_eq!(&*RelString::as_str(s.as_ref()), "héllo");
+    });
+}
+
+#[test]
+fn test_rel_string_insert_str() {
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut s = string::Clone(alloc, "añb")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        RelString::insert_str(s.as_mut(), 0, "¡");
+        assert_eq!(&*RelString::as_str(s.as_ref()), "¡añb");
+        RelString::insert_str(s.as_mut(), 5, "日本");
+        assert_eq!(&*RelString::as_str(s.as_ref()), "¡añ日本b");
+        let len = s.len();
+        RelString::insert_str(s.as_mut(), len, "!");
+        assert_eq!(&*RelString::as_str(s.as_ref()), "¡añ日本b!");
+        RelString::insert_str(s.as_mut(), 3, "");
+        assert_eq!(&*RelString::as_str(s.as_ref()), "¡añ日本b!");
+    });
+}
+
+#[test]
+#[should_panic]
+fn test_rel_string_insert_str_not_char_boundary() {
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut s = string::Clone(alloc, "añb")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        // `ñ` is two bytes long, so index 2 is in the middle of it.
+        RelString::insert_str(s.as_mut(), 2, "x");
+    });
+}
+
+#[test]
+fn test_rel_string_to_string_native() {
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let s = string::Clone(alloc, "añ日本")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        let native: String = RelString::to_string_native(s.as_ref());
+        assert_eq!(native, "añ日本");
+
+        let empty = string::Clone(alloc, "")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        assert!(RelString::to_string_native(empty.as_ref()).is_empty());
+    });
+}
+
+#[test]
+fn test_rel_string_ascii_case() {
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let mut s = string::Clone(alloc, "Grüße, Jürgen ❤")
+            .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        RelString::make_ascii_uppercase(s.as_mut());
+        assert_eq!(&*RelString::as_str(s.as_ref()), "GRüßE, JüRGEN ❤");
+        RelString::make_ascii_lowercase(s.as_mut());
+        assert_eq!(&*RelString::as_str(s.as_ref()), "grüße, jürgen ❤");
+    });
+}
+
+#[test]
+fn test_rel_bit_set() {
+    use ::rel_alloc::{bit_set, EmplaceIn, RelBitSet};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let flags = [
+            true, false, false, true, true, false, true, false, false, true,
+        ];
+        let mut bits = bit_set::FromIter(alloc, flags.into_iter())
+            .emplace_in::<RelBitSet<RelSlabAllocator<_>>>(alloc);
+        assert_eq!(bits.len(), 10);
+        assert!(!bits.is_empty());
+        for (i, flag) in flags.iter().enumerate() {
+            assert_eq!(RelBitSet::get(bits.as_ref(), i), Some(*flag));
+        }
+        assert_eq!(RelBitSet::get(bits.as_ref(), 10), None);
+        assert_eq!(RelBitSet::count_ones(bits.as_ref()), 5);
+        assert!(RelBitSet::iter(bits.as_ref()).eq(flags.iter().copied()));
+
+        RelBitSet::set(bits.as_mut(), 1, true);
+        RelBitSet::set(bits.as_mut(), 9, false);
+        assert_eq!(RelBitSet::get(bits.as_ref(), 1), Some(true));
+        assert_eq!(RelBitSet::get(bits.as_ref(), 9), Some(false));
+        assert_eq!(RelBitSet::count_ones(bits.as_ref()), 5);
+        assert_eq!(
+            format!("{:?}", bits),
+            "[true, true, false, true, true, false, true, false, false, false]",
+        );
+
+        let empty = bit_set::FromIter(alloc, ::core::iter::empty())
+            .emplace_in::<RelBitSet<RelSlabAllocator<_>>>(alloc);
+        assert!(empty.is_empty());
+        assert_eq!(RelBitSet::get(empty.as_ref(), 0), None);
+    });
+}
+
+#[test]
+fn test_rel_bit_set_validate() {
+    use ::core::mem::{forget, size_of};
+    use ::mischief::StaticToken;
+    use ::rel_alloc::{bit_set, EmplaceIn, RelBitSet, RelVec};
+    use ::rel_core::{Basis, DefaultBasis, ValidateError};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    type Root<'a, 'id> = RelBitSet<RelSlabAllocator<'a, StaticToken<'id>>>;
+    type Bytes<'a, 'id> = RelVec<u8, RelSlabAllocator<'a, StaticToken<'id>>>;
+    type Usize = <DefaultBasis as Basis>::Usize;
+
+    with_slab(256, |alloc| {
+        let flags = [
+            true, false, false, true, true, false, true, false, false, true,
+        ];
+        let bits = bit_set::FromIter(alloc, flags.into_iter())
+            .emplace_in::<Root<'_, '_>>(alloc);
+        assert!(alloc.deposit(bits).is_none());
+
+        let mut bits = alloc.withdraw::<Root<'_, '_>>().unwrap().unwrap();
+        let ptr = bits.as_mut().as_ptr();
+        forget(bits);
+
+        // SAFETY: `RelBitSet` is `repr(C)`, so its length immediately follows
+        // its bytes.
+        let len_ptr = unsafe {
+            ptr.cast::<u8>()
+                .add(size_of::<Bytes<'_, '_>>())
+                .cast::<Usize>()
+        };
+        let set_len = |len| {
+            let len = DefaultBasis::from_native_usize(len).unwrap();
+            // SAFETY: `len_ptr` points to the length of the deposited bit set.
+            unsafe { len_ptr.write(len) };
+        };
+
+        // Seventeen bits need three bytes, but only two are stored.
+        set_len(17);
+        assert_eq!(
+            alloc.withdraw::<Root<'_, '_>>().err(),
+            Some(ValidateError::InvalidBitSetBytes { len: 17, bytes: 2 }),
+        );
+
+        // Nine bits fit in two bytes, but the tenth bit is set.
+        set_len(9);
+        assert_eq!(
+            alloc.withdraw::<Root<'_, '_>>().err(),
+            Some(ValidateError::InvalidBitSetPadding { len: 9 }),
+        );
+
+        set_len(10);
+        assert!(alloc.withdraw::<Root<'_, '_>>().is_ok());
+    });
+}
+
+#[test]
+fn test_try_emplace_in() {
+    use ::rel_alloc::{
+        alloc::TryReserveError,
+        boxed,
+        string,
+        vec,
+        RelBox,
+        RelString,
+        RelVec,
+        TryEmplaceIn,
+    };
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::situ::ops::DerefRaw;
+
+    with_slab(256, |alloc| {
+        // Allocations which don't fit in the slab fail instead of panicking.
+        let too_big = vec::WithCapacity(alloc, 1 << 20)
+            .try_emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        assert!(matches!(too_big, Err(TryReserveError::AllocError { .. })));
+
+        let long = ::core::str::from_utf8(&[b'a'; 512]).unwrap();
+        let too_long = string::Clone(alloc, long)
+            .try_emplace_in::<RelString<RelSlabAllocator<_>>>(alloc);
+        assert!(matches!(too_long, Err(TryReserveError::AllocError { .. })));
+
+        // Allocations which fit succeed.
+        let values = vec::FromArray(alloc, [1u32, 2, 3])
+            .try_emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc)
+            .unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(RelVec::as_slice(values.as_ref())[2].to_ne(), 3);
+
+        let s = string::Clone(alloc, "hello")
+            .try_emplace_in::<RelString<RelSlabAllocator<_>>>(alloc)
+            .unwrap();
+        assert_eq!(&*RelString::as_str(s.as_ref()), "hello");
+
+        let b = boxed::New(alloc, 42u32)
+            .try_emplace_in::<RelBox<U32, RelSlabAllocator<_>>>(alloc)
+            .unwrap();
+        assert_eq!(DerefRaw::deref_raw(b.as_ref()).to_ne(), 42);
+    });
+}
+
+#[test]
+fn test_vec_from_iter() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(1024, |alloc| {
+        // Exact-size iterators are emplaced without reallocating.
+        let exact = vec::FromIter(alloc, 0..10u32)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        assert_eq!(exact.len(), 10);
+        assert_eq!(exact.capacity(), 10);
+        for (i, value) in RelVec::iter_enumerated(exact.as_ref()) {
+            assert_eq!(value.to_ne(), u32::try_from(i).unwrap());
+        }
+
+        // Iterators without an exact size still emplace all of their elements.
+        let filtered = vec::FromIter(alloc, (0..20u32).filter(|i| i % 3 == 0))
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        assert_eq!(filtered.len(), 7);
+        for (i, value) in RelVec::iter_enumerated(filtered.as_ref()) {
+            assert_eq!(value.to_ne(), u32::try_from(i * 3).unwrap());
+        }
+
+        // Iterators over references to emplacers can be emplaced directly.
+        let values = [1u32, 2, 3];
+        let borrowed = vec::FromIter(alloc, values.iter())
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        assert!(RelVec::iter(borrowed.as_ref())
+            .map(|value| value.to_ne())
+            .eq(values));
+    });
+}
+
+#[test]
+fn test_rel_vec_diff() {
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::rel_util::diff;
+
+    with_slab(1024, |alloc| {
+        let values = |values: &[u32]| {
+            vec::FromIter(alloc, values.iter())
+                .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc)
+        };
+        let a = values(&[1, 2, 3]);
+        // The elements are in different places, but compare the same.
+        assert!(diff(a.as_ref(), values(&[1, 2, 3]).as_ref()).is_none());
+
+        let path = diff(a.as_ref(), values(&[1, 4, 3]).as_ref()).unwrap();
+        assert_eq!(path.to_string(), "[1]");
+        // The first element past the end of the shorter vec differs.
+        let path = diff(a.as_ref(), values(&[1, 2]).as_ref()).unwrap();
+        assert_eq!(path.to_string(), "[2]");
+    });
+}
+
+#[test]
+fn test_rel_string_content_hash() {
+    use ::rel_alloc::{string, vec, EmplaceIn, RelString, RelVec};
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::rel_util::content_hash;
+
+    with_slab(1024, |alloc| {
+        let strings = |x, y| {
+            vec::FromArray(
+                alloc,
+                [string::Clone(alloc, x), string::Clone(alloc, y)],
+            )
+            .emplace_in::<RelVec<
+                RelString<RelSlabAllocator<_>>,
+                RelSlabAllocator<_>,
+            >>(alloc)
+        };
+        let a = strings("hello", "world");
+        let b = strings("hello", "world");
+        let c = strings("hello", "there");
+
+        // Equal values hash the same even though they're in different places.
+        assert_eq!(content_hash(a.as_ref()), content_hash(b.as_ref()));
+        assert_ne!(content_hash(a.as_ref()), content_hash(c.as_ref()));
+    });
+}
+
+#[test]
+fn test_rel_string_cmp() {
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    with_slab(256, |alloc| {
+        let emplace = |s| {
+            string::Clone(alloc, s)
+                .emplace_in::<RelString<RelSlabAllocator<_>>>(alloc)
+        };
+        let a = emplace("apple");
+        let b = emplace("banana");
+        let c = emplace("apple");
+
+        assert_eq!(a.as_ref(), c.as_ref());
+        assert_ne!(a.as_ref(), b.as_ref());
+        assert!(a.as_ref() < b.as_ref());
+    });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serialize_raw() {
+    use ::rel_alloc::{string, vec, EmplaceIn, RelString, RelVec};
+    use ::rel_core::{option::RelOption, rel_tuple::RelTuple2, Char};
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::serde_json::json;
+
+    type Alloc<'a, U> = RelSlabAllocator<'a, U>;
+    type Root<'a, U> = RelTuple2<
+        Char,
+        RelVec<RelOption<RelString<Alloc<'a, U>>>, Alloc<'a, U>>,
+    >;
+
+    with_slab(256, |alloc| {
+        let items = [Some(string::Clone(alloc, "hello")), None];
+        let root = ('x', vec::FromArray(alloc, items))
+            .emplace_in::<Root<'_, _>>(alloc);
+
+        assert_eq!(
+            ::serde_json::to_value(root.as_ref()).unwrap(),
+            json!(["x", ["hello", null]]),
+        );
+    });
+}
@@ -0,0 +1,34 @@
+use ::criterion::black_box;
+use ::mischief::{GhostRef, Slot, StaticToken};
+use ::rel_alloc::{vec, EmplaceIn, RelVec};
+use ::rel_core::U32;
+use ::rel_slab_allocator::{RelSlabAllocator, SlabAllocator};
+use ::rel_util::Align16;
+
+fn populate_buffer(input_size: u32, buffer: Slot<'_, [u8]>) -> usize {
+    StaticToken::acquire(|mut token| {
+        let alloc =
+            SlabAllocator::<_>::try_new_in(buffer, GhostRef::leak(&mut token))
+                .unwrap();
+
+        let mut values = vec::New(alloc)
+            .emplace_in::<RelVec<U32, RelSlabAllocator<_>>>(alloc);
+        // `Filter` always reports a lower bound of zero for its size hint, so
+        // `extend` can't reserve any space up front.
+        RelVec::extend(values.as_mut(), (0..input_size).filter(|i| i % 3 != 0));
+
+        alloc.deposit(values);
+        alloc.shrink_to_fit()
+    })
+}
+
+pub fn make_bench(input_size: u32) -> impl FnMut() -> usize {
+    let mut bytes = Align16::frame(10_000_000);
+
+    move || {
+        black_box(populate_buffer(
+            black_box(input_size),
+            black_box(bytes.slot().as_bytes()),
+        ))
+    }
+}
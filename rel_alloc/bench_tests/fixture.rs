@@ -0,0 +1,16 @@
+use ::mischief::StaticToken;
+use ::rel_slab_allocator::SlabAllocator;
+use ::rel_util::Align16;
+
+/// Calls `f` with a slab allocator backed by a fresh zeroed buffer of `size`
+/// bytes.
+pub fn with_slab<R>(
+    size: usize,
+    f: impl for<'a, 'id> FnOnce(SlabAllocator<'a, StaticToken<'id>>) -> R,
+) -> R {
+    let mut bytes = Align16::frame(size);
+    bytes.slot().zero();
+    StaticToken::acquire(|token| {
+        f(SlabAllocator::try_new_in(bytes.slot().as_bytes(), token).unwrap())
+    })
+}
@@ -0,0 +1,98 @@
+use ::criterion::black_box;
+use ::mischief::{GhostRef, In, Region, Slot, StaticToken};
+use ::ptr_meta::Pointee;
+use ::rel_alloc::{vec, EmplaceIn, RelVec};
+use ::rel_core::{Emplace, Move, Portable, U32};
+use ::rel_slab_allocator::{RelSlabAllocator, SlabAllocator};
+use ::rel_util::Align16;
+use ::situ::{DropRaw, Val};
+
+/// A `U32` which opts out of bytewise moves.
+///
+/// Growing a `RelVec` of these moves each element individually, which provides
+/// a baseline to compare the bulk copy used for `U32` against.
+#[derive(DropRaw, Portable)]
+#[repr(transparent)]
+pub struct Elementwise(U32);
+
+// SAFETY: `move_unsized_unchecked` initializes its `out` parameter by writing
+// the value to it.
+unsafe impl<R: Region> Move<R> for Elementwise {
+    unsafe fn move_unsized_unchecked(
+        this: In<Val<'_, Self>, R>,
+        out: In<Slot<'_, Self>, R>,
+    ) {
+        In::into_inner(out).write(Val::read(In::into_inner(this)));
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by writing to
+//   it.
+unsafe impl<R: Region> Emplace<Elementwise, R> for u32 {
+    fn emplaced_meta(&self) -> <Elementwise as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, Elementwise>, R>,
+    ) {
+        In::into_inner(out).write(Elementwise(U32::from_ne(self)));
+    }
+}
+
+// `populate_buffer` is generated for each element type because the region of
+// the allocator depends on the token it is created with.
+macro_rules! populate_buffer {
+    ($name:ident, $ty:ty) => {
+        fn $name(input_size: u32, buffer: Slot<'_, [u8]>) -> usize {
+            StaticToken::acquire(|mut token| {
+                let alloc = SlabAllocator::<_>::try_new_in(
+                    buffer,
+                    GhostRef::leak(&mut token),
+                )
+                .unwrap();
+
+                // Growing two vectors in lockstep means that neither one can
+                // grow in place, so every time either one grows its elements
+                // have to be moved to a new allocation.
+                let mut evens = vec::New(alloc)
+                    .emplace_in::<RelVec<$ty, RelSlabAllocator<_>>>(alloc);
+                let mut odds = vec::New(alloc)
+                    .emplace_in::<RelVec<$ty, RelSlabAllocator<_>>>(alloc);
+                for i in 0..input_size {
+                    RelVec::push(evens.as_mut(), 2 * i);
+                    RelVec::push(odds.as_mut(), 2 * i + 1);
+                }
+
+                alloc.deposit(evens);
+                alloc.shrink_to_fit()
+            })
+        }
+    };
+}
+
+populate_buffer!(populate_buffer_bytewise, U32);
+populate_buffer!(populate_buffer_elementwise, Elementwise);
+
+pub fn make_bench_bytewise(input_size: u32) -> impl FnMut() -> usize {
+    let mut bytes = Align16::frame(10_000_000);
+
+    move || {
+        black_box(populate_buffer_bytewise(
+            black_box(input_size),
+            black_box(bytes.slot().as_bytes()),
+        ))
+    }
+}
+
+pub fn make_bench_elementwise(input_size: u32) -> impl FnMut() -> usize {
+    let mut bytes = Align16::frame(10_000_000);
+
+    move || {
+        black_box(populate_buffer_elementwise(
+            black_box(input_size),
+            black_box(bytes.slot().as_bytes()),
+        ))
+    }
+}
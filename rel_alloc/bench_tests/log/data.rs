@@ -2,6 +2,7 @@ use ::rand::Rng;
 
 use crate::gen::Generate;
 
+#[derive(Debug, PartialEq)]
 pub struct Address {
     pub x0: u8,
     pub x1: u8,
@@ -20,6 +21,7 @@ impl Generate for Address {
     }
 }
 
+#[derive(Debug, PartialEq)]
 pub struct Entry {
     pub address: Address,
     pub identity: String,
@@ -93,6 +95,7 @@ impl Generate for Entry {
     }
 }
 
+#[derive(Debug, PartialEq)]
 pub struct Log {
     pub entries: Vec<Entry>,
 }
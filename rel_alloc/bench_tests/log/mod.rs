@@ -1,18 +1,25 @@
-mod data;
+pub mod data;
 
 use ::criterion::black_box;
 use ::mischief::{GhostRef, In, Region, Slot, StaticToken};
 use ::munge::munge;
 use ::rand::Rng;
 use ::rel_alloc::{alloc::RelAllocator, EmplaceIn, RelString, RelVec};
-use ::rel_core::{Emplace, EmplaceExt, Move, Portable, U16, U64};
+use ::rel_core::{Emplace, EmplaceExt, Move, Portable, ToNative, U16, U64};
 use ::rel_slab_allocator::{RelSlabAllocator, SlabAllocator};
 use ::rel_util::Align16;
-use ::situ::{alloc::RawRegionalAllocator, DropRaw};
+use ::situ::{
+    alloc::RawRegionalAllocator,
+    fmt::DebugRaw,
+    hash::HashRaw,
+    CloneRaw,
+    DropRaw,
+};
 
 use crate::{from_data::FromData, gen::generate_vec};
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(CloneRaw, DebugRaw, DropRaw, HashRaw, Move, Portable, ToNative)]
+#[native = "data::Address"]
 #[repr(C)]
 pub struct RelAddress {
     pub x0: u8,
@@ -44,7 +51,8 @@ unsafe impl<R: Region> Emplace<RelAddress, R> for &'_ data::Address {
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(CloneRaw, DebugRaw, DropRaw, HashRaw, Move, Portable, ToNative)]
+#[native = "data::Entry"]
 #[repr(C)]
 pub struct RelEntry<A: RawRegionalAllocator> {
     pub address: RelAddress,
@@ -94,7 +102,8 @@ where
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(CloneRaw, DebugRaw, DropRaw, HashRaw, Move, Portable, ToNative)]
+#[native = "data::Log"]
 #[repr(C)]
 pub struct RelLog<A: RawRegionalAllocator> {
     pub entries: RelVec<RelEntry<A>, A>,
@@ -115,17 +124,14 @@ where
 
         munge!(let RelLog { entries } = out);
 
-        let entries =
-            vec::WithCapacity(self.alloc.clone(), self.data.entries.len())
-                .emplace_mut(entries);
-
-        RelVec::extend(
-            In::into_inner(entries),
+        vec::FromIter(
+            self.alloc.clone(),
             self.data.entries.iter().map(|data| FromData {
                 alloc: self.alloc.clone(),
                 data,
             }),
-        );
+        )
+        .emplace(entries);
     }
 }
 
@@ -1,4 +1,4 @@
-mod data;
+pub mod data;
 
 use ::criterion::black_box;
 use ::mischief::{GhostRef, In, Region, Slot, StaticToken};
@@ -13,6 +13,7 @@ use ::rel_core::{
     EmplaceExt,
     Move,
     Portable,
+    ToNative,
     F32,
     F64,
     I32,
@@ -26,7 +27,8 @@ use ::situ::{alloc::RawRegionalAllocator, DropRaw};
 
 use crate::{from_data::FromData, gen::generate_vec};
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::GameType"]
 #[repr(u8)]
 pub enum RelGameType {
     Survival,
@@ -52,7 +54,8 @@ unsafe impl<R: Region> Emplace<RelGameType, R> for &'_ data::GameType {
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::Item"]
 #[repr(C)]
 pub struct RelItem<A: RawRegionalAllocator> {
     pub count: i8,
@@ -87,7 +90,8 @@ where
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::Abilities"]
 #[repr(C)]
 pub struct RelAbilities {
     pub walk_speed: F32,
@@ -128,7 +132,8 @@ unsafe impl<R: Region> Emplace<RelAbilities, R> for &'_ data::Abilities {
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::Entity"]
 #[repr(C)]
 pub struct RelEntity<A: RawRegionalAllocator> {
     pub id: RelString<A>,
@@ -207,7 +212,8 @@ where
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::RecipeBook"]
 #[repr(C)]
 pub struct RelRecipeBook<A: RawRegionalAllocator> {
     pub recipes: RelVec<RelString<A>, A>,
@@ -251,29 +257,23 @@ where
             } = out;
         );
 
-        let recipes =
-            vec::WithCapacity(self.alloc.clone(), self.data.recipes.len())
-                .emplace_mut(recipes);
-        RelVec::extend(
-            In::into_inner(recipes),
+        vec::FromIter(
+            self.alloc.clone(),
             self.data
                 .recipes
                 .iter()
                 .map(|data| string::Clone(self.alloc.clone(), data)),
-        );
+        )
+        .emplace(recipes);
 
-        let to_be_displayed = vec::WithCapacity(
+        vec::FromIter(
             self.alloc.clone(),
-            self.data.to_be_displayed.len(),
-        )
-        .emplace_mut(to_be_displayed);
-        RelVec::extend(
-            In::into_inner(to_be_displayed),
             self.data
                 .to_be_displayed
                 .iter()
                 .map(|data| string::Clone(self.alloc.clone(), data)),
-        );
+        )
+        .emplace(to_be_displayed);
 
         self.data
             .is_filtering_craftable
@@ -296,7 +296,8 @@ where
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::Player"]
 #[repr(C)]
 pub struct RelPlayer<A: RawRegionalAllocator> {
     pub game_type: RelGameType,
@@ -409,27 +410,23 @@ where
         self.data.xp_total.emplace(xp_total);
         self.data.xp_seed.emplace(xp_seed);
 
-        let inventory =
-            vec::WithCapacity(self.alloc.clone(), self.data.inventory.len())
-                .emplace_mut(inventory);
-        RelVec::extend(
-            In::into_inner(inventory),
+        vec::FromIter(
+            self.alloc.clone(),
             self.data.inventory.iter().map(|data| FromData {
                 alloc: self.alloc.clone(),
                 data,
             }),
-        );
+        )
+        .emplace(inventory);
 
-        let ender_items =
-            vec::WithCapacity(self.alloc.clone(), self.data.ender_items.len())
-                .emplace_mut(ender_items);
-        RelVec::extend(
-            In::into_inner(ender_items),
+        vec::FromIter(
+            self.alloc.clone(),
             self.data.ender_items.iter().map(|data| FromData {
                 alloc: self.alloc.clone(),
                 data,
             }),
-        );
+        )
+        .emplace(ender_items);
 
         self.data.abilities.emplace(abilities);
         self.data
@@ -473,7 +470,8 @@ where
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DropRaw, Move, Portable, ToNative)]
+#[native = "data::SaveData"]
 #[repr(C)]
 pub struct RelSaveData<A: RawRegionalAllocator> {
     pub players: RelVec<RelPlayer<A>, A>,
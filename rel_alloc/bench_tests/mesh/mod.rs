@@ -1,18 +1,19 @@
-mod data;
+pub mod data;
 
 use ::criterion::black_box;
 use ::mischief::{GhostRef, In, Region, Slot, StaticToken};
 use ::munge::munge;
 use ::rand::Rng;
 use ::rel_alloc::{alloc::RelAllocator, EmplaceIn, RelVec};
-use ::rel_core::{Emplace, EmplaceExt, Move, Portable, F32};
+use ::rel_core::{Emplace, EmplaceExt, Move, Portable, ToNative, F32};
 use ::rel_slab_allocator::{RelSlabAllocator, SlabAllocator};
 use ::rel_util::Align16;
-use ::situ::{alloc::RawRegionalAllocator, DropRaw};
+use ::situ::{alloc::RawRegionalAllocator, fmt::DebugRaw, DropRaw};
 
 use crate::{from_data::FromData, gen::generate_vec};
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DebugRaw, DropRaw, Move, Portable, ToNative)]
+#[native = "data::Vector3"]
 #[repr(C)]
 pub struct RelVector3 {
     pub x: F32,
@@ -41,7 +42,8 @@ unsafe impl<R: Region> Emplace<RelVector3, R> for &'_ data::Vector3 {
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DebugRaw, DropRaw, Move, Portable, ToNative)]
+#[native = "data::Triangle"]
 #[repr(C)]
 pub struct RelTriangle {
     pub v0: RelVector3,
@@ -73,7 +75,8 @@ unsafe impl<R: Region> Emplace<RelTriangle, R> for &'_ data::Triangle {
     }
 }
 
-#[derive(DropRaw, Move, Portable)]
+#[derive(DebugRaw, DropRaw, Move, Portable, ToNative)]
+#[native = "data::Mesh"]
 #[repr(C)]
 pub struct RelMesh<A: RawRegionalAllocator> {
     pub triangles: RelVec<RelTriangle, A>,
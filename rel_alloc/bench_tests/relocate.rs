@@ -0,0 +1,35 @@
+use ::core::{alloc::Layout, ptr::NonNull};
+use ::mischief::{Frame, In, RegionalAllocator};
+use ::rel_core::{Move, MoveExt};
+use ::situ::{OwnedVal, Val};
+
+/// Moves `value` to a fresh allocation in the same region and then drops it.
+///
+/// This exercises the `Move` and `DropRaw` implementations of `T` end to end,
+/// and is intended to be run under miri to catch errors in relative pointer
+/// fixups.
+pub fn move_and_drop<T, A>(value: OwnedVal<T, A>)
+where
+    T: Move<A::Region>,
+    A: Clone + RegionalAllocator,
+{
+    let (ptr, alloc) = OwnedVal::into_raw_parts(value);
+    let mut frame = In::new(Frame::<T, A>::new_in(alloc.clone()));
+
+    // SAFETY: `ptr` was owned by an `OwnedVal`, so it points to an initialized
+    // and immovable `T` which we now own. It was allocated in `alloc`, so it
+    // is located in `A::Region`.
+    let this = unsafe { In::new_unchecked(Val::new_unchecked(ptr)) };
+    T::r#move(this, frame.slot());
+
+    // SAFETY: `ptr` was allocated in `alloc` with the layout of `T`, and its
+    // value was moved out of it.
+    unsafe {
+        alloc
+            .deallocate(NonNull::new_unchecked(ptr).cast(), Layout::new::<T>());
+    }
+
+    // SAFETY: `frame` was initialized by moving into it.
+    let moved = unsafe { OwnedVal::assume_init(In::into_inner(frame)) };
+    drop(moved);
+}
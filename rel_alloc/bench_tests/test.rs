@@ -1,8 +1,13 @@
+mod crates;
+mod fixture;
 pub mod from_data;
 pub mod gen;
 mod log;
 mod mc_savedata;
 mod mesh;
+mod relocate;
+
+use self::fixture::with_slab;
 
 #[test]
 fn test_log_bench() {
@@ -18,3 +23,162 @@ fn test_mesh_bench() {
 fn test_mc_savedata_bench() {
     mc_savedata::make_bench(&mut gen::default_rng(), 10)();
 }
+
+#[test]
+fn test_relocate_log() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    use self::{from_data::FromData, gen::generate_vec, log::RelLog};
+
+    let input = log::data::Log {
+        entries: generate_vec(&mut gen::default_rng(), 2),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let log = FromData {
+            alloc,
+            data: &input,
+        }
+        .emplace_in::<RelLog<RelSlabAllocator<_>>>(alloc);
+
+        relocate::move_and_drop(log);
+    });
+}
+
+#[test]
+fn test_relocate_mesh() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    use self::{from_data::FromData, gen::generate_vec, mesh::RelMesh};
+
+    let input = mesh::data::Mesh {
+        triangles: generate_vec(&mut gen::default_rng(), 2),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let mesh = FromData {
+            alloc,
+            data: &input,
+        }
+        .emplace_in::<RelMesh<RelSlabAllocator<_>>>(alloc);
+
+        relocate::move_and_drop(mesh);
+    });
+}
+
+#[test]
+fn test_relocate_mc_savedata() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    use self::{
+        from_data::FromData,
+        gen::generate_vec,
+        mc_savedata::RelSaveData,
+    };
+
+    let input = mc_savedata::data::SaveData {
+        players: generate_vec(&mut gen::default_rng(), 2),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let save_data = FromData {
+            alloc,
+            data: &input,
+        }
+        .emplace_in::<RelSaveData<RelSlabAllocator<_>>>(alloc);
+
+        relocate::move_and_drop(save_data);
+    });
+}
+
+#[test]
+fn test_clone_log() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    use self::{from_data::FromData, gen::generate_vec, log::RelLog};
+
+    let input = log::data::Log {
+        entries: generate_vec(&mut gen::default_rng(), 10),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let log = FromData {
+            alloc,
+            data: &input,
+        }
+        .emplace_in::<RelLog<RelSlabAllocator<_>>>(alloc);
+
+        let cloned = log.clone();
+        let expected = format!("{log:?}");
+        assert_eq!(format!("{cloned:?}"), expected);
+
+        // The clone must not share any storage with the original.
+        drop(log);
+        assert_eq!(format!("{cloned:?}"), expected);
+    });
+}
+
+#[test]
+fn test_hash_log() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_slab_allocator::RelSlabAllocator;
+    use ::situ::OwnedVal;
+    use ::std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+    };
+
+    use self::{from_data::FromData, gen::generate_vec, log::RelLog};
+
+    let input = log::data::Log {
+        entries: generate_vec(&mut gen::default_rng(), 10),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let emplace = || {
+            FromData {
+                alloc,
+                data: &input,
+            }
+            .emplace_in::<RelLog<RelSlabAllocator<_>>>(alloc)
+        };
+        let hash_of = |log: &OwnedVal<RelLog<_>, _>| {
+            let mut hasher = DefaultHasher::new();
+            log.as_ref().hash(&mut hasher);
+            hasher.finish()
+        };
+
+        // The two logs are located at different offsets in the slab, so all of
+        // their relative pointers have different offsets.
+        let a = emplace();
+        let b = emplace();
+        assert_eq!(hash_of(&a), hash_of(&b));
+    });
+}
+
+#[test]
+fn test_to_native_log() {
+    use ::rel_alloc::EmplaceIn;
+    use ::rel_core::ToNative;
+    use ::rel_slab_allocator::RelSlabAllocator;
+
+    use self::{from_data::FromData, gen::generate_vec, log::RelLog};
+
+    let input = log::data::Log {
+        entries: generate_vec(&mut gen::default_rng(), 10),
+    };
+
+    with_slab(1_000_000, |alloc| {
+        let log = FromData {
+            alloc,
+            data: &input,
+        }
+        .emplace_in::<RelLog<RelSlabAllocator<_>>>(alloc);
+
+        assert_eq!(ToNative::to_native(log.as_ref()), input);
+    });
+}
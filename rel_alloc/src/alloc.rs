@@ -1,5 +1,6 @@
 //! Memory allocation APIs.
 
+use ::core::{alloc::Layout, fmt};
 use ::mischief::RegionalAllocator;
 use ::rel_core::Emplace;
 use ::situ::{alloc::RawRegionalAllocator, DropRaw};
@@ -19,3 +20,32 @@ where
     E: DropRaw + RawRegionalAllocator<Region = Self::Region>,
 {
 }
+
+/// The error type for `try_reserve` methods and fallible emplacement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The computed capacity exceeded the collection's maximum. This happens
+    /// when the allocation would be larger than `isize::MAX` bytes, or when
+    /// the capacity can't be represented in the collection's basis.
+    CapacityOverflow,
+    /// The allocator returned an error.
+    AllocError {
+        /// The layout of the allocation request that failed.
+        layout: Layout,
+    },
+}
+
+impl fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")?;
+        match self {
+            Self::CapacityOverflow => f.write_str(
+                " because the computed capacity exceeded the collection's \
+                maximum",
+            ),
+            Self::AllocError { .. } => {
+                f.write_str(" because the memory allocator returned an error")
+            }
+        }
+    }
+}
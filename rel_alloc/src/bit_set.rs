@@ -0,0 +1,241 @@
+//! A compact set of bits.
+
+use ::core::{fmt, iter::FusedIterator, ptr::addr_of};
+use ::mischief::{In, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+use ::rel_core::{
+    Basis,
+    DefaultBasis,
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    Validate,
+    ValidateError,
+    Validator,
+};
+use ::situ::{alloc::RawRegionalAllocator, fmt::DebugRaw, DropRaw, Mut, Ref};
+
+use crate::{alloc::RelAllocator, vec, RelVec};
+
+/// A fixed-length sequence of bits, packed eight to a byte.
+///
+/// Storing flags in a `RelBitSet` takes an eighth of the space of storing them
+/// as `bool`s. Its bytes may hold any bit pattern, except that the unused bits
+/// of the last byte must be zero.
+#[derive(DropRaw, Move, Portable)]
+#[repr(C)]
+pub struct RelBitSet<A: RawRegionalAllocator, B: Basis = DefaultBasis> {
+    bytes: RelVec<u8, A, B>,
+    len: B::Usize,
+}
+
+impl<A: RawRegionalAllocator, B: Basis> RelBitSet<A, B> {
+    /// Returns the number of bits in the `RelBitSet`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        B::to_native_usize(self.len).unwrap()
+    }
+
+    /// Returns `true` if the `RelBitSet` contains no bits.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the bit at the given index, or `None` if the index is out of
+    /// bounds.
+    #[inline]
+    pub fn get(this: Ref<'_, Self>, index: usize) -> Option<bool> {
+        if index < this.len() {
+            munge!(let RelBitSet { bytes, .. } = this);
+            let byte = RelVec::as_slice(bytes)[index / 8];
+            Some(byte & (1 << (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the bit at the given index to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub fn set(this: Mut<'_, Self>, index: usize, value: bool) {
+        let len = this.len();
+        assert!(
+            index < len,
+            "index out of bounds: the len is {len} but the index is {index}",
+        );
+
+        munge!(let RelBitSet { bytes, .. } = this);
+        let mut bytes = RelVec::as_slice_mut(bytes);
+        let mask = 1 << (index % 8);
+        if value {
+            bytes[index / 8] |= mask;
+        } else {
+            bytes[index / 8] &= !mask;
+        }
+    }
+
+    /// Returns the number of bits which are set.
+    #[inline]
+    pub fn count_ones(this: Ref<'_, Self>) -> usize {
+        munge!(let RelBitSet { bytes, .. } = this);
+        // Bits past the end of the set are always zero (and validation checks
+        // this), so they don't need to be masked off.
+        RelVec::as_slice(bytes)
+            .iter()
+            .map(|byte| usize::try_from(byte.count_ones()).unwrap())
+            .sum()
+    }
+
+    /// Returns an iterator over the bits of the `RelBitSet`.
+    #[inline]
+    pub fn iter(this: Ref<'_, Self>) -> Iter<'_> {
+        let len = this.len();
+        munge!(let RelBitSet { bytes, .. } = this);
+        Iter {
+            bytes: RelVec::as_slice(bytes),
+            index: 0,
+            len,
+        }
+    }
+}
+
+// SAFETY: `validate` validates the underlying `RelVec` and then checks that it
+// has exactly enough bytes to hold all of the bits, and that the unused bits of
+// the last byte are zero.
+unsafe impl<A, B> Validate for RelBitSet<A, B>
+where
+    A: RawRegionalAllocator + Validate,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `bytes` field is as well.
+        unsafe {
+            RelVec::validate(addr_of!((*ptr).bytes), validator)?;
+        }
+
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads. We just validated `bytes`, and every
+        // bit pattern is a valid `B::Usize`, so `this` is initialized.
+        let this = unsafe { Ref::new_unchecked(ptr) };
+        let len = B::to_native_usize(this.len)
+            .map_err(|_| ValidateError::LayoutOverflow)?;
+        let bytes = this.bytes.len();
+        if bytes != len.div_ceil(8) {
+            return Err(ValidateError::InvalidBitSetBytes { len, bytes });
+        }
+
+        if len % 8 != 0 {
+            munge!(let RelBitSet { bytes, .. } = this);
+            let last = RelVec::as_slice(bytes)[len / 8];
+            if last >> (len % 8) != 0 {
+                return Err(ValidateError::InvalidBitSetPadding { len });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<A: RawRegionalAllocator, B: Basis> DebugRaw for RelBitSet<A, B> {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        f.debug_list().entries(Self::iter(this)).finish()
+    }
+}
+
+/// An iterator over the bits of a `RelBitSet`.
+///
+/// This is created by [`RelBitSet::iter`].
+#[derive(Clone)]
+pub struct Iter<'a> {
+    bytes: Ref<'a, [u8]>,
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.len {
+            let byte = self.bytes[self.index / 8];
+            let bit = byte & (1 << (self.index % 8)) != 0;
+            self.index += 1;
+            Some(bit)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// An emplacer for a `RelBitSet` that packs the `bool`s from an iterator.
+pub struct FromIter<R, I>(pub R, pub I);
+
+// SAFETY:
+// - `RelBitSet` is `Sized` and always has metadata `()`, so `emplaced_meta`
+//   always returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   each field.
+unsafe impl<A, B, R, I> Emplace<RelBitSet<A, B>, R::Region> for FromIter<R, I>
+where
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: RelAllocator<A>,
+    I: Iterator<Item = bool>,
+{
+    fn emplaced_meta(&self) -> <RelBitSet<A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelBitSet<A, B>>, R::Region>,
+    ) {
+        let Self(alloc, bits) = self;
+
+        munge!(let RelBitSet { bytes: out_bytes, len: out_len } = out);
+        let cap = bits.size_hint().0.div_ceil(8);
+        let mut bytes = In::into_inner(
+            vec::WithCapacity(alloc, cap).emplace_mut(out_bytes),
+        );
+
+        let mut len = 0;
+        let mut byte = 0u8;
+        for bit in bits {
+            if bit {
+                byte |= 1 << (len % 8);
+            }
+            len += 1;
+            if len % 8 == 0 {
+                RelVec::push(bytes.as_mut(), byte);
+                byte = 0;
+            }
+        }
+        if len % 8 != 0 {
+            RelVec::push(bytes.as_mut(), byte);
+        }
+
+        In::into_inner(out_len).write(B::from_native_usize(len).unwrap());
+    }
+}
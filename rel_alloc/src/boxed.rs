@@ -1,10 +1,15 @@
 //! A pointer type for heap allocation.
 
-use ::core::{alloc::Layout, fmt, mem::MaybeUninit};
-use ::mischief::{In, Slot};
+#[cfg(feature = "alloc")]
+use ::builtin_alloc::boxed::Box;
+use ::core::{alloc::Layout, fmt, mem::MaybeUninit, ptr::addr_of};
+use ::mischief::{Frame, In, Metadata, Slot};
 use ::munge::munge;
 use ::ptr_meta::Pointee;
+#[cfg(feature = "alloc")]
+use ::rel_core::ToNative;
 use ::rel_core::{
+    validate_ptr,
     Basis,
     BasisPointee,
     DefaultBasis,
@@ -13,11 +18,20 @@ use ::rel_core::{
     Move,
     Portable,
     RelPtr,
+    Validate,
+    ValidateError,
+    Validator,
 };
+#[cfg(feature = "serde")]
+use ::serde::Serializer;
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
 use ::situ::{
     alloc::RawRegionalAllocator,
+    diff::{DiffPath, DiffRaw},
     fmt::{DebugRaw, DisplayRaw},
     ops::{DerefMutRaw, DerefRaw, IndexMutRaw, IndexRaw},
+    CloneRaw,
     DropRaw,
     Mut,
     OwnedVal,
@@ -25,7 +39,11 @@ use ::situ::{
     Val,
 };
 
-use crate::alloc::RelAllocator;
+use crate::{
+    alloc::{RelAllocator, TryReserveError},
+    EmplaceIn,
+    TryEmplace,
+};
 
 /// A relative counterpart to `Box`.
 #[derive(Move, Portable)]
@@ -127,6 +145,105 @@ where
     }
 }
 
+// SAFETY: `clone_raw` initializes its `out` parameter by emplacing a clone of
+// the boxed value to it.
+unsafe impl<T, A, B, R> CloneRaw<R> for RelBox<T, A, B>
+where
+    T: BasisPointee<B> + CloneRaw<R> + DropRaw,
+    <T as Pointee>::Metadata: Metadata<T>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: Clone + RelAllocator<A>,
+{
+    fn clone_raw(
+        this: Ref<'_, Self>,
+        alloc: R,
+        out: In<Slot<'_, Self>, R::Region>,
+    ) {
+        let mut frame = In::new(Frame::new_in(alloc.clone()));
+        T::clone_raw(Self::deref_raw(this), alloc, frame.slot());
+        // SAFETY: `clone_raw` initialized `frame`.
+        let value = unsafe { OwnedVal::assume_init(In::into_inner(frame)) };
+        value.emplace(out);
+    }
+}
+
+// SAFETY: `validate` checks that the relative pointer is not null, then claims
+// and validates the boxed value and validates the allocator.
+unsafe impl<T, A, B> Validate for RelBox<T, A, B>
+where
+    T: BasisPointee<B> + Validate,
+    A: RawRegionalAllocator + Validate,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads. Every bit pattern is a valid
+        // `RelPtr`, so its `ptr` field is initialized.
+        let rel_ptr = unsafe { Ref::new_unchecked(addr_of!((*ptr).ptr)) };
+        let target = RelPtr::as_ptr_wrapping(rel_ptr)
+            .ok_or(ValidateError::NullPointer)?;
+        validate_ptr(target, validator)?;
+
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `alloc` field is as well.
+        unsafe { A::validate(addr_of!((*ptr).alloc), validator) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A, B> ToNative for RelBox<T, A, B>
+where
+    T: BasisPointee<B> + ToNative,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Native = Box<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        Box::new(T::to_native(Self::deref_raw(this)))
+    }
+}
+
+// `RelBox`s serialize like the values they point to.
+#[cfg(feature = "serde")]
+impl<T, A, B> SerializeRaw for RelBox<T, A, B>
+where
+    T: BasisPointee<B> + SerializeRaw + ?Sized,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SerializeRaw::serialize_raw(DerefRaw::deref_raw(this), serializer)
+    }
+}
+
+// Boxes are transparent to diffs, so they don't add a segment to the path.
+impl<T, A, B> DiffRaw for RelBox<T, A, B>
+where
+    T: BasisPointee<B> + DiffRaw + ?Sized,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        path: &mut P,
+    ) -> bool {
+        DiffRaw::diff_raw(
+            DerefRaw::deref_raw(this),
+            DerefRaw::deref_raw(other),
+            path,
+        )
+    }
+}
+
 impl<T, A, B> DebugRaw for RelBox<T, A, B>
 where
     T: BasisPointee<B> + DebugRaw + ?Sized,
@@ -251,3 +368,85 @@ where
         alloc.emplace(out_alloc);
     }
 }
+
+/// An emplacer for a `RelBox` that allocates its value in an allocator.
+///
+/// The value itself is emplaced infallibly. To propagate allocation failures
+/// from emplacing the value as well, emplace it with
+/// [`try_emplace_in`](crate::TryEmplaceIn::try_emplace_in) and then emplace
+/// the returned `OwnedVal` as the `RelBox`.
+pub struct New<R, E>(pub R, pub E);
+
+// SAFETY:
+// - `RelBox` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing a
+//   newly-allocated `OwnedVal` to it.
+unsafe impl<T, A, B, R, E> Emplace<RelBox<T, A, B>, R::Region> for New<R, E>
+where
+    T: BasisPointee<B> + DropRaw + ?Sized,
+    <T as Pointee>::Metadata: Metadata<T>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: RelAllocator<A>,
+    E: Emplace<T, R::Region>,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelBox<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelBox<T, A, B>>, R::Region>,
+    ) {
+        let Self(alloc, value) = self;
+        value.emplace_in::<T>(alloc).emplace(out);
+    }
+}
+
+// SAFETY:
+// - `RelBox` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter by
+//   emplacing a newly-allocated `OwnedVal` to it whenever it returns `Ok`.
+unsafe impl<T, A, B, R, E> TryEmplace<RelBox<T, A, B>, R::Region> for New<R, E>
+where
+    T: BasisPointee<B> + DropRaw + ?Sized,
+    <T as Pointee>::Metadata: Metadata<T>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: RelAllocator<A>,
+    E: Emplace<T, R::Region>,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelBox<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelBox<T, A, B>>, R::Region>,
+    ) -> Result<(), TryReserveError> {
+        let Self(alloc, value) = self;
+
+        let metadata = value.emplaced_meta();
+        // SAFETY: The pointer metadata is from `emplaced_meta`, which is
+        // guaranteed to be valid for a pointer to `T`.
+        let frame = unsafe { Frame::try_new_unsized_in(metadata, alloc) }
+            .map_err(|_| TryReserveError::AllocError {
+                // SAFETY: The pointer metadata is from `emplaced_meta`, which
+                // is guaranteed to be valid for a pointer to `T`.
+                layout: unsafe { metadata.pointee_layout() },
+            })?;
+
+        let mut frame = In::new(frame);
+        // SAFETY: We just allocated the slot in a frame with the metadata from
+        // `emplaced_meta`.
+        unsafe {
+            value.emplace_unsized_unchecked(frame.slot());
+        }
+        // SAFETY: `emplace_unsized_unchecked` is guaranteed to initialize the
+        // slot. That slot is from the frame, so the frame is initialized.
+        let value = unsafe { OwnedVal::assume_init(In::into_inner(frame)) };
+        value.emplace(out);
+
+        Ok(())
+    }
+}
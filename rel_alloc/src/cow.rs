@@ -0,0 +1,294 @@
+//! A pointer which either borrows or owns its value.
+
+use ::core::{
+    fmt,
+    hint::unreachable_unchecked,
+    ptr::{addr_of, addr_of_mut},
+};
+use ::mischief::{In, Region, Slot};
+use ::ptr_meta::Pointee;
+use ::raw_enum_macro::raw_enum;
+use ::rel_core::{
+    Basis,
+    BasisPointee,
+    DefaultBasis,
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    RelPtr,
+};
+use ::situ::{
+    alloc::RawRegionalAllocator,
+    fmt::{DebugRaw, DisplayRaw},
+    ops::DerefRaw,
+    DropRaw,
+    Ref,
+};
+
+use crate::RelBox;
+
+/// A relative pointer which either borrows a value from elsewhere in its
+/// region or owns it.
+///
+/// Either way, a `RelCow` dereferences to its value. Dropping a `RelCow` only
+/// drops its value if it is owned; borrowed values are left untouched.
+#[derive(DropRaw, Move, Portable)]
+#[repr(u8)]
+#[raw_enum]
+pub enum RelCow<
+    T: BasisPointee<B>,
+    A: RawRegionalAllocator,
+    B: Basis = DefaultBasis,
+> {
+    /// A value borrowed from elsewhere in the same region.
+    Borrowed(RelPtr<T, A::Region, B>),
+    /// A value owned by the `RelCow`.
+    Owned(RelBox<T, A, B>),
+}
+
+impl<T, A, B> RelCow<T, A, B>
+where
+    T: BasisPointee<B>,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    /// Returns whether the `RelCow` borrows its value.
+    #[inline]
+    pub fn is_borrowed(this: Ref<'_, Self>) -> bool {
+        matches!(
+            raw_rel_cow_variant(raw_rel_cow(this.as_ptr())),
+            RawRelCowVariants::Borrowed(_),
+        )
+    }
+
+    /// Returns whether the `RelCow` owns its value.
+    #[inline]
+    pub fn is_owned(this: Ref<'_, Self>) -> bool {
+        !Self::is_borrowed(this)
+    }
+}
+
+impl<T, A, B> DerefRaw for RelCow<T, A, B>
+where
+    T: BasisPointee<B>,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Target = T;
+
+    fn deref_raw(this: Ref<'_, Self>) -> Ref<'_, T> {
+        match raw_rel_cow_variant(raw_rel_cow(this.as_ptr())) {
+            RawRelCowVariants::Borrowed(this_ptr) => {
+                // SAFETY: `this_ptr` points to the `Borrowed` variant of a
+                // valid `RelCow`, so its pointer is initialized and valid for
+                // reads.
+                let ptr =
+                    unsafe { Ref::new_unchecked(addr_of!((*this_ptr).1)) };
+                // SAFETY: The pointer of a borrowed `RelCow` always points to
+                // an initialized value which outlives the `RelCow` and is not
+                // mutably aliased for as long as it exists.
+                unsafe { RelPtr::as_ref(ptr) }
+            }
+            RawRelCowVariants::Owned(this_ptr) => {
+                // SAFETY: `this_ptr` points to the `Owned` variant of a valid
+                // `RelCow`, so its box is initialized and valid for reads.
+                let value =
+                    unsafe { Ref::new_unchecked(addr_of!((*this_ptr).1)) };
+                DerefRaw::deref_raw(value)
+            }
+        }
+    }
+}
+
+impl<T, A, B> DebugRaw for RelCow<T, A, B>
+where
+    T: BasisPointee<B> + DebugRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        DebugRaw::fmt_raw(DerefRaw::deref_raw(this), f)
+    }
+}
+
+impl<T, A, B> DisplayRaw for RelCow<T, A, B>
+where
+    T: BasisPointee<B> + DisplayRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        DisplayRaw::fmt_raw(DerefRaw::deref_raw(this), f)
+    }
+}
+
+/// An emplacer for a `RelCow` which borrows a value.
+pub struct Borrowed<'a, T, R: Region>(In<Ref<'a, T>, R>);
+
+impl<'a, T, R: Region> Borrowed<'a, T, R> {
+    /// Returns a new emplacer which borrows the given value.
+    ///
+    /// # Safety
+    ///
+    /// The value pointed to by `value` must outlive the emplaced `RelCow`, and
+    /// must not be mutably accessed for as long as the `RelCow` exists.
+    #[inline]
+    pub unsafe fn new(value: In<Ref<'a, T>, R>) -> Self {
+        Self(value)
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by setting the
+//   `Borrowed` discriminant and emplacing a pointer to the borrowed value.
+unsafe impl<T, A, B> Emplace<RelCow<T, A, B>, A::Region>
+    for Borrowed<'_, T, A::Region>
+where
+    T: BasisPointee<B> + DropRaw,
+    A: DropRaw + RawRegionalAllocator,
+    B: Basis,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelCow<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelCow<T, A, B>>, A::Region>,
+    ) {
+        let raw_out = raw_rel_cow(out.ptr().as_ptr());
+
+        // SAFETY: `raw_rel_cow_discriminant` guarantees that the pointer it
+        // returns is properly aligned and valid for writes.
+        unsafe {
+            raw_rel_cow_discriminant(raw_out)
+                .write(RawRelCowDiscriminant::Borrowed);
+        }
+        match raw_rel_cow_variant(raw_out) {
+            RawRelCowVariants::Borrowed(out_ptr) => {
+                // SAFETY: `raw_rel_cow_variant` guarantees that `out_ptr` is
+                // properly aligned and valid for reads and writes.
+                let ptr_ptr = unsafe { addr_of_mut!((*out_ptr).1) };
+                // SAFETY:
+                // - `ptr_ptr` is a pointer into `out`, so it is non-null,
+                //   properly aligned, and valid for reads and writes.
+                // - `ptr_ptr` is a disjoint borrow of `out`, which is
+                //   guaranteed not to alias any other accessible references,
+                //   so the returned `Slot` will not either.
+                let slot = unsafe { Slot::new_unchecked(ptr_ptr) };
+                // SAFETY: `ptr_ptr` is a pointer into `out`, which is contained
+                // in `A::Region`, so `ptr_ptr` must be contained in
+                // `A::Region` as well.
+                let slot = unsafe { In::new_unchecked(slot) };
+                self.0.as_raw().emplace(slot);
+            }
+            // SAFETY: We wrote the `Borrowed` discriminant to `out` so it must
+            // be the `Borrowed` variant.
+            _ => unsafe { unreachable_unchecked() },
+        }
+    }
+}
+
+/// An emplacer for a `RelCow` which owns a value.
+///
+/// The wrapped emplacer must emplace a `RelBox`, like an `OwnedVal` does.
+pub struct Owned<E>(pub E);
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by setting the
+//   `Owned` discriminant and emplacing a box of the owned value.
+unsafe impl<T, A, B, E> Emplace<RelCow<T, A, B>, A::Region> for Owned<E>
+where
+    T: BasisPointee<B> + DropRaw,
+    A: DropRaw + RawRegionalAllocator,
+    B: Basis,
+    E: Emplace<RelBox<T, A, B>, A::Region>,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelCow<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelCow<T, A, B>>, A::Region>,
+    ) {
+        let raw_out = raw_rel_cow(out.ptr().as_ptr());
+
+        // SAFETY: `raw_rel_cow_discriminant` guarantees that the pointer it
+        // returns is properly aligned and valid for writes.
+        unsafe {
+            raw_rel_cow_discriminant(raw_out)
+                .write(RawRelCowDiscriminant::Owned);
+        }
+        match raw_rel_cow_variant(raw_out) {
+            RawRelCowVariants::Owned(out_ptr) => {
+                // SAFETY: `raw_rel_cow_variant` guarantees that `out_ptr` is
+                // properly aligned and valid for reads and writes.
+                let box_ptr = unsafe { addr_of_mut!((*out_ptr).1) };
+                // SAFETY:
+                // - `box_ptr` is a pointer into `out`, so it is non-null,
+                //   properly aligned, and valid for reads and writes.
+                // - `box_ptr` is a disjoint borrow of `out`, which is
+                //   guaranteed not to alias any other accessible references,
+                //   so the returned `Slot` will not either.
+                let slot = unsafe { Slot::new_unchecked(box_ptr) };
+                // SAFETY: `box_ptr` is a pointer into `out`, which is contained
+                // in `A::Region`, so `box_ptr` must be contained in
+                // `A::Region` as well.
+                let slot = unsafe { In::new_unchecked(slot) };
+                self.0.emplace(slot);
+            }
+            // SAFETY: We wrote the `Owned` discriminant to `out` so it must be
+            // the `Owned` variant.
+            _ => unsafe { unreachable_unchecked() },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::mischief::In;
+    use ::rel_core::U32;
+    use ::situ::ops::DerefRaw;
+
+    use crate::{
+        cow,
+        tests::{Arena, RelArena},
+        EmplaceIn,
+        RelCow,
+    };
+
+    #[test]
+    fn borrowed_and_owned() {
+        let shared = 1u32.emplace_in::<U32>(Arena);
+        // SAFETY:
+        // - `shared` was allocated by `Arena`, so it is located in its region.
+        // - `shared` outlives `borrowed`, and isn't mutably accessed while
+        //   `borrowed` exists.
+        let borrowed = unsafe {
+            cow::Borrowed::new(In::new_unchecked(shared.as_ref()))
+        }
+        .emplace_in::<RelCow<U32, RelArena>>(Arena);
+        assert!(RelCow::is_borrowed(borrowed.as_ref()));
+        assert!(!RelCow::is_owned(borrowed.as_ref()));
+        assert_eq!(DerefRaw::deref_raw(borrowed.as_ref()).to_ne(), 1);
+
+        let owned = cow::Owned(2u32.emplace_in::<U32>(Arena))
+            .emplace_in::<RelCow<U32, RelArena>>(Arena);
+        assert!(RelCow::is_owned(owned.as_ref()));
+        assert!(!RelCow::is_borrowed(owned.as_ref()));
+        assert_eq!(DerefRaw::deref_raw(owned.as_ref()).to_ne(), 2);
+
+        // Dropping the borrowed `RelCow` leaves the shared value intact.
+        drop(borrowed);
+        assert_eq!(shared.to_ne(), 1);
+        drop(owned);
+    }
+}
@@ -1,10 +1,14 @@
-use ::mischief::{Frame, In, Metadata, RegionalAllocator};
+use ::mischief::{Frame, In, Metadata, Region, RegionalAllocator, Slot};
 use ::ptr_meta::Pointee;
-use ::rel_core::Emplace;
-use ::situ::{DropRaw, OwnedVal};
+use ::rel_core::{Emplace, EmplaceExt};
+use ::situ::{DropRaw, Mut, OwnedVal};
 
 /// An extension trait for `Emplace` that provides an allocating emplacement
 /// function.
+///
+/// Use `emplace_in` when you need a new, owned value (like the root of a
+/// buffer). To emplace into memory which has already been allocated (like a
+/// field of a value being emplaced), use [`EmplaceInto`] instead.
 pub trait EmplaceIn<A: RegionalAllocator> {
     /// Emplaces a value into a new `OwnedVal` allocated from the given
     /// allocator and returns it.
@@ -20,6 +24,7 @@ impl<E, A> EmplaceIn<A> for E
 where
     A: RegionalAllocator,
 {
+    #[inline]
     #[must_use]
     fn emplace_in<T>(self, alloc: A) -> OwnedVal<T, A>
     where
@@ -45,3 +50,29 @@ where
         unsafe { OwnedVal::assume_init(In::into_inner(frame)) }
     }
 }
+
+/// An extension trait for `Emplace` that provides a non-allocating emplacement
+/// function.
+///
+/// Use `emplace_into` when you already have a slot for the value, like a field
+/// of a value being emplaced or an element reserved in a `RelVec`. To allocate
+/// a new value to emplace into, use [`EmplaceIn`] instead.
+pub trait EmplaceInto<R: Region> {
+    /// Emplaces a value into the given slot and returns a mutable reference to
+    /// it.
+    fn emplace_into<'a, T>(self, out: In<Slot<'a, T>, R>) -> In<Mut<'a, T>, R>
+    where
+        T: DropRaw,
+        Self: Emplace<T, R>;
+}
+
+impl<E, R: Region> EmplaceInto<R> for E {
+    #[inline]
+    fn emplace_into<'a, T>(self, out: In<Slot<'a, T>, R>) -> In<Mut<'a, T>, R>
+    where
+        T: DropRaw,
+        Self: Emplace<T, R>,
+    {
+        EmplaceExt::emplace_mut(self, out)
+    }
+}
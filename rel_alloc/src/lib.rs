@@ -12,15 +12,151 @@
 )]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc as builtin_alloc;
+
 pub mod alloc;
+pub mod bit_set;
 pub mod boxed;
+pub mod cow;
 mod emplace_in;
+pub mod prelude;
+pub mod small_vec;
 pub mod string;
+mod try_emplace;
 pub mod vec;
 
 pub use self::{
+    bit_set::RelBitSet,
     boxed::RelBox,
-    emplace_in::EmplaceIn,
+    cow::RelCow,
+    emplace_in::{EmplaceIn, EmplaceInto},
+    small_vec::RelSmallVec,
     string::RelString,
+    try_emplace::{TryEmplace, TryEmplaceIn},
     vec::RelVec,
 };
+
+#[cfg(test)]
+mod tests {
+    use ::core::{
+        alloc::Layout,
+        cell::UnsafeCell,
+        ptr::{slice_from_raw_parts_mut, NonNull},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use ::heresy::alloc::{AllocError, Allocator};
+    use ::mischief::{In, Region, RegionalAllocator, Slot};
+    use ::ptr_meta::Pointee;
+    use ::rel_core::{Emplace, Move, Portable};
+    use ::situ::{
+        alloc::{RawAllocator, RawRegionalAllocator},
+        DropRaw,
+        Ref,
+    };
+
+    use crate::alloc::RelAllocator;
+
+    const ARENA_SIZE: usize = 1 << 16;
+
+    #[repr(align(16))]
+    struct Buffer(UnsafeCell<[u8; ARENA_SIZE]>);
+
+    // SAFETY: Disjoint blocks of the buffer are handed out by atomically
+    // bumping `USED`, so the same bytes are never shared between threads.
+    unsafe impl Sync for Buffer {}
+
+    static BUFFER: Buffer = Buffer(UnsafeCell::new([0; ARENA_SIZE]));
+    static USED: AtomicUsize = AtomicUsize::new(0);
+
+    fn allocate(layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let base = BUFFER.0.get().cast::<u8>();
+        let mut start = 0;
+        USED.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+            let padding = base.wrapping_add(used).align_offset(layout.align());
+            start = used.checked_add(padding)?;
+            let end = start.checked_add(layout.size())?;
+            (end <= ARENA_SIZE).then_some(end)
+        })
+        .map_err(|_| AllocError)?;
+        // SAFETY: `start + layout.size()` is at most `ARENA_SIZE`, so `start`
+        // is in bounds of the buffer.
+        let ptr = unsafe { base.add(start) };
+        let block = slice_from_raw_parts_mut(ptr, layout.size());
+        // SAFETY: `ptr` is in bounds of the buffer, which is non-null.
+        Ok(unsafe { NonNull::new_unchecked(block) })
+    }
+
+    /// The region that [`Arena`] allocates in.
+    pub struct ArenaRegion;
+
+    // SAFETY: `ArenaRegion` only locates values allocated by `Arena`, which are
+    // all in the same static buffer.
+    unsafe impl Region for ArenaRegion {}
+
+    /// An allocator for unit tests, which bumps through a static buffer and
+    /// never frees memory.
+    #[derive(Clone, Copy)]
+    pub struct Arena;
+
+    // SAFETY: Memory blocks returned from `Arena` point to disjoint parts of a
+    // static buffer, and are never reused.
+    unsafe impl Allocator for Arena {
+        fn allocate(
+            &self,
+            layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, _: NonNull<u8>, _: Layout) {}
+    }
+
+    // SAFETY: `Arena` only allocates from the buffer located by `ArenaRegion`.
+    unsafe impl RegionalAllocator for Arena {
+        type Region = ArenaRegion;
+    }
+
+    /// The relative counterpart to [`Arena`].
+    #[derive(DropRaw, Move, Portable)]
+    #[repr(C)]
+    pub struct RelArena;
+
+    // SAFETY: Memory blocks returned from `RelArena` point to disjoint parts of
+    // a static buffer, and are never reused.
+    unsafe impl RawAllocator for RelArena {
+        fn raw_allocate(
+            _: Ref<'_, Self>,
+            layout: Layout,
+        ) -> Result<NonNull<[u8]>, AllocError> {
+            allocate(layout)
+        }
+
+        unsafe fn raw_deallocate(_: Ref<'_, Self>, _: NonNull<u8>, _: Layout) {}
+    }
+
+    // SAFETY: `RelArena` only allocates from the buffer located by
+    // `ArenaRegion`.
+    unsafe impl RawRegionalAllocator for RelArena {
+        type Region = ArenaRegion;
+    }
+
+    // SAFETY:
+    // - `emplaced_meta` returns `()`, the only valid metadata for `Sized`
+    //   types.
+    // - `emplace_unsized_unchecked` initializes its `out` parameter by writing
+    //   a `RelArena` to it.
+    unsafe impl Emplace<RelArena, ArenaRegion> for Arena {
+        fn emplaced_meta(&self) -> <RelArena as Pointee>::Metadata {}
+
+        unsafe fn emplace_unsized_unchecked(
+            self,
+            out: In<Slot<'_, RelArena>, ArenaRegion>,
+        ) {
+            In::into_inner(out).write(RelArena);
+        }
+    }
+
+    // SAFETY: `Arena` and `RelArena` both allocate from the same buffer.
+    unsafe impl RelAllocator<RelArena> for Arena {}
+}
@@ -0,0 +1,26 @@
+//! Commonly used traits and types.
+//!
+//! This includes everything in the `rel_core` prelude.
+
+pub use ::rel_core::prelude::*;
+
+pub use crate::{
+    alloc::RelAllocator,
+    bit_set,
+    cow,
+    rel_string,
+    rel_vec,
+    small_vec,
+    string,
+    vec,
+    EmplaceIn,
+    EmplaceInto,
+    RelBitSet,
+    RelBox,
+    RelCow,
+    RelSmallVec,
+    RelString,
+    RelVec,
+    TryEmplace,
+    TryEmplaceIn,
+};
@@ -0,0 +1,968 @@
+//! A growable array type which stores a small number of elements inline,
+//! written `RelSmallVec<T, N>`.
+
+#[cfg(feature = "alloc")]
+use ::builtin_alloc::vec::Vec;
+use ::core::{
+    alloc::Layout,
+    fmt,
+    mem::{self, forget, MaybeUninit},
+    ptr,
+};
+use ::mischief::{In, Pointer, Region, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+#[cfg(feature = "alloc")]
+use ::rel_core::ToNative;
+use ::rel_core::{
+    rel_ptr::Null,
+    validate_slice,
+    Basis,
+    DefaultBasis,
+    Emplace,
+    EmplaceExt,
+    Move,
+    MoveExt,
+    Portable,
+    RelPtr,
+    Validate,
+    ValidateError,
+    Validator,
+};
+use ::situ::{
+    alloc::{RawAllocator, RawRegionalAllocator},
+    cmp::{EqRaw, PartialEqRaw},
+    fmt::DebugRaw,
+    ops::{DerefMutRaw, DerefRaw, IndexMutRaw, IndexRaw},
+    DropRaw,
+    Mut,
+    Ref,
+    Val,
+};
+
+use crate::alloc::{RelAllocator, TryReserveError};
+
+/// A relative counterpart to `Vec` which stores up to `N` elements inline.
+///
+/// While the `RelSmallVec` has a capacity of `N` or fewer elements, its
+/// elements are stored inline and its relative pointer is null. When it grows
+/// beyond `N` elements, it spills: its elements are moved into a new allocation
+/// and the relative pointer is set to point to them. A `RelSmallVec` never
+/// moves its elements back inline after spilling.
+#[derive(Portable)]
+#[repr(C)]
+pub struct RelSmallVec<
+    T,
+    const N: usize,
+    A: RawRegionalAllocator,
+    B: Basis = DefaultBasis,
+> {
+    ptr: RelPtr<T, A::Region, B>,
+    len: B::Usize,
+    cap: B::Usize,
+    alloc: A,
+    inline: [MaybeUninit<T>; N],
+}
+
+impl<T, const N: usize, A, B> DropRaw for RelSmallVec<T, N, A, B>
+where
+    T: DropRaw,
+    A: RawRegionalAllocator + DropRaw,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+{
+    #[inline]
+    unsafe fn drop_raw(mut this: Mut<'_, Self>) {
+        let spilled = this.spilled();
+        let layout = Layout::array::<T>(this.capacity()).unwrap();
+        let inner = Self::deref_mut_raw(this.as_mut());
+
+        let inner_ptr = inner.as_non_null();
+        // SAFETY: The elements contained in the `RelSmallVec` are always valid
+        // for dropping. This drop call has the last reference to them, so they
+        // will never be accessed again.
+        unsafe {
+            DropRaw::drop_raw(inner);
+        }
+
+        munge!(let RelSmallVec { ptr, len, cap, alloc, .. } = this);
+
+        if spilled {
+            // SAFETY: The `RelSmallVec` has spilled, so `ptr` is allocated in
+            // `alloc` with a layout of `layout`.
+            unsafe {
+                A::raw_deallocate(alloc.as_ref(), inner_ptr.cast(), layout);
+            }
+        }
+
+        // SAFETY: `ptr`, `len`, `cap`, and `alloc` are always valid for
+        // dropping and are not accessed again.
+        unsafe {
+            DropRaw::drop_raw(ptr);
+            DropRaw::drop_raw(len);
+            DropRaw::drop_raw(cap);
+            DropRaw::drop_raw(alloc);
+        }
+    }
+}
+
+impl<T, const N: usize, A, B> RelSmallVec<T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    /// Returns `true` if the `RelSmallVec` contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements in the `RelSmallVec`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        B::to_native_usize(self.len).unwrap()
+    }
+
+    /// Returns the maximum number of elements the `RelSmallVec` can contain
+    /// before resizing.
+    ///
+    /// This is `N` until the `RelSmallVec` spills.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        B::to_native_usize(self.cap).unwrap()
+    }
+
+    /// Returns `true` if the elements of the `RelSmallVec` have been moved
+    /// out of its inline storage and into an allocation.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        !self.ptr.is_null()
+    }
+
+    /// Returns a raw pointer to the `RelSmallVec`'s buffer.
+    ///
+    /// This points to the inline storage of the `RelSmallVec` until it spills.
+    #[inline]
+    pub fn as_ptr(this: Ref<'_, Self>) -> *const T {
+        munge!(let RelSmallVec { ptr, inline, .. } = this);
+
+        let inline_ptr: *const T = Ref::as_ptr(inline).cast();
+        RelPtr::as_ptr(ptr).unwrap_or(inline_ptr)
+    }
+
+    /// Returns an unsafe mutable pointer to the `RelSmallVec`'s buffer.
+    ///
+    /// This points to the inline storage of the `RelSmallVec` until it spills.
+    #[inline]
+    pub fn as_mut_ptr(this: Mut<'_, Self>) -> *mut T {
+        munge!(let RelSmallVec { ptr, inline, .. } = this);
+
+        let inline_ptr = inline.as_ptr().cast::<T>();
+        RelPtr::as_mut_ptr(ptr).unwrap_or(inline_ptr)
+    }
+
+    /// Forces the length of the vector to `new_len`.
+    ///
+    /// # Safety
+    ///
+    /// - `new_len` must be less than or equal to `capacity()`.
+    /// - The elements at `old_len..new_len` must be initialized.
+    pub unsafe fn set_len(this: Mut<'_, Self>, new_len: usize) {
+        munge!(let RelSmallVec { mut len, .. } = this);
+        *len = B::from_native_usize(new_len).unwrap();
+    }
+
+    /// Returns a reference to the underlying allocator.
+    #[inline]
+    pub fn allocator(this: Ref<'_, Self>) -> Ref<'_, A> {
+        munge!(let RelSmallVec { alloc, .. } = this);
+        alloc
+    }
+
+    /// Returns a `Ref` to a slice of the elements in the `RelSmallVec`.
+    #[inline]
+    pub fn as_slice(this: Ref<'_, Self>) -> Ref<'_, [T]> {
+        DerefRaw::deref_raw(this)
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be less than `capacity`.
+    unsafe fn slot(
+        this: Mut<'_, Self>,
+        index: usize,
+    ) -> In<Slot<'_, T>, A::Region> {
+        let ptr = Self::as_mut_ptr(this);
+        // SAFETY: The buffer of a `RelSmallVec` is always non-null, properly
+        // aligned, and valid for reads and writes of `capacity` elements.
+        // Because `this` is mutably borrowed for `'_`, the created reference
+        // cannot be aliased for `'_`.
+        let slot = unsafe { Slot::new_unchecked(ptr.add(index)) };
+        // SAFETY: Spilled elements are allocated in `self.alloc`, which
+        // guarantees that the memory it allocates is located in its region.
+        // Inline elements are located in the `RelSmallVec`, which contains a
+        // `RelPtr` that is `Pinned` to the same region.
+        unsafe { In::new_unchecked(slot) }
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be less than `len`. The returned `Val` may drop its
+    /// contained value when it is dropped. Special care must be taken to ensure
+    /// that this does not cause a dropped element to exist in the initialized
+    /// section of the `RelSmallVec`.
+    unsafe fn take(
+        this: Mut<'_, Self>,
+        index: usize,
+    ) -> In<Val<'_, T>, A::Region>
+    where
+        T: DropRaw,
+    {
+        // SAFETY: The caller has guaranteed that `index` is less than `len`.
+        let slot = unsafe { Self::slot(this, index) };
+        // SAFETY: The slot at `index` is guaranteed to be initialized and valid
+        // for dropping because `index < len`, and all elements of
+        // `RelSmallVec` are treated as pinned.
+        let initialize = |s| unsafe { Val::from_slot_unchecked(s) };
+        // SAFETY: `initialize` returns a `Val` of the given `Slot`, which is
+        // always located in the same region as the `Slot` it is derived from.
+        unsafe { In::map_unchecked(slot, initialize) }
+    }
+
+    /// Returns the layout of an allocation with room for `cap` elements, or
+    /// an error if it would be larger than `isize::MAX` bytes.
+    #[inline]
+    fn try_layout_for(cap: usize) -> Result<Layout, TryReserveError> {
+        Layout::array::<T>(cap).map_err(|_| TryReserveError::CapacityOverflow)
+    }
+
+    /// Returns the number of elements which fit in the memory returned by an
+    /// allocator, up to the requested capacity `cap`.
+    #[inline]
+    fn granted_cap(bytes: ptr::NonNull<[u8]>, cap: usize) -> usize {
+        match mem::size_of::<T>() {
+            0 => cap,
+            size => (::ptr_meta::metadata(bytes.as_ptr()) / size).min(cap),
+        }
+    }
+
+    /// Returns the layout of an allocation with room for `cap` elements.
+    ///
+    /// # Safety
+    ///
+    /// The layout for `cap` elements must have already been computed
+    /// successfully. This is always the case for the capacity of an existing
+    /// `RelSmallVec`, whether its elements are inline or spilled.
+    #[inline]
+    unsafe fn layout_for(cap: usize) -> Layout {
+        // SAFETY: The caller has guaranteed that `Layout::array::<T>(cap)` has
+        // already succeeded, so `size_of::<T>() * cap` does not overflow and
+        // rounds up to at most `isize::MAX` bytes.
+        unsafe {
+            Layout::from_size_align_unchecked(
+                mem::size_of::<T>() * cap,
+                mem::align_of::<T>(),
+            )
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more elements to be inserted
+    /// in the given `RelSmallVec`. If the new capacity is greater than `N`, the
+    /// `RelSmallVec` spills its elements into an allocation. Does nothing if
+    /// capacity is already sufficient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes or the allocator
+    /// fails to allocate the new buffer.
+    pub fn reserve(this: Mut<'_, Self>, additional: usize)
+    where
+        T: Move<A::Region>,
+    {
+        if let Err(e) = Self::try_reserve(this, additional) {
+            panic!("{e}");
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the given `RelSmallVec`. If the new capacity is greater than
+    /// `N`, the `RelSmallVec` spills its elements into an allocation. Does
+    /// nothing if capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows or the allocator reports a failure, then an
+    /// error is returned and the `RelSmallVec` is left unchanged.
+    pub fn try_reserve(
+        mut this: Mut<'_, Self>,
+        additional: usize,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Move<A::Region>,
+    {
+        let min_cap = this
+            .len()
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        if min_cap <= this.capacity() {
+            return Ok(());
+        }
+
+        let new_cap = min_cap
+            .checked_next_power_of_two()
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        B::from_native_usize(new_cap)
+            .map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        // SAFETY: `capacity` is the capacity of an existing `RelSmallVec`.
+        let old_layout = unsafe { Self::layout_for(this.capacity()) };
+        let new_layout = Self::try_layout_for(new_cap)?;
+
+        let spilled = this.spilled();
+        let ptr = Self::as_mut_ptr(this.as_mut());
+        // SAFETY: The buffer of a `RelSmallVec` is never null.
+        let old_ptr = unsafe { ptr::NonNull::new_unchecked(ptr.cast()) };
+
+        let grown = if spilled {
+            // SAFETY:
+            // - `old_ptr` is the memory for the spilled `RelSmallVec`, which
+            //   was allocated with `old_layout`.
+            // - `new_layout` has a strictly larger size than `old_layout`
+            //   because `new_cap` is greater than `min_cap`, which is greater
+            //   than `this.capacity()`.
+            unsafe {
+                RawAllocator::raw_grow_in_place(
+                    Self::allocator(this.as_ref()),
+                    old_ptr,
+                    old_layout,
+                    new_layout,
+                )
+            }
+            .ok()
+        } else {
+            None
+        };
+        // The allocator may grant less than the power-of-two capacity we asked
+        // for, so the capacity comes from the memory it returned. Growth which
+        // doesn't make room for `min_cap` elements doesn't count.
+        let grown_cap = grown
+            .map(|bytes| Self::granted_cap(bytes, new_cap))
+            .filter(|&cap| cap >= min_cap);
+
+        let cap = if let Some(cap) = grown_cap {
+            cap
+        } else {
+            let allocation = RawAllocator::raw_allocate(
+                Self::allocator(this.as_ref()),
+                new_layout,
+            );
+            let allocation = allocation.map_err(|_| {
+                TryReserveError::AllocError { layout: new_layout }
+            })?;
+            let new_ptr = allocation.as_ptr().cast::<T>();
+            if T::BYTEWISE {
+                // SAFETY:
+                // - The first `len` elements of `old_ptr` are initialized, so
+                //   it is valid for reads of `len` elements.
+                // - `new_ptr` is valid for writes of `new_cap` elements, which
+                //   is greater than `len`.
+                // - `new_ptr` is freshly-allocated, so it does not overlap
+                //   `old_ptr`.
+                // - `T` can be moved bytewise, so copying the elements moves
+                //   them. The old storage is either freed or no longer used
+                //   afterward, so the old elements can't be accessed again.
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        old_ptr.as_ptr().cast::<T>(),
+                        new_ptr,
+                        this.len(),
+                    );
+                }
+            } else {
+                for i in 0..this.len() {
+                    // SAFETY:
+                    // - `new_ptr` is the pointer of a `NonNull`, so it must be
+                    //   non-null. It is guaranteed to be aligned to
+                    //   `new_layout.align()` by the implementation of
+                    //   `RawAllocator`, which is at least `align_of::<T>()`.
+                    //   It is also guaranteed to be valid for reads and writes
+                    //   of at least `new_layout.size()` bytes, which covers
+                    //   every element slot in `new_ptr`.
+                    // - `new_ptr` is freshly-allocated, so only we have access
+                    //   to it. It is not currently aliased by any other
+                    //   pointers.
+                    let out = unsafe { Slot::new_unchecked(new_ptr.add(i)) };
+                    // SAFETY: `new_ptr` is allocated in `this.alloc`, and since
+                    // `A` implements `RawRegionalAllocator`, it guarantees that
+                    // memory it allocates is located in its region.
+                    let out = unsafe { In::new_unchecked(out) };
+                    // SAFETY: `i` is less than `len` and we move out of it then
+                    // either free the backing storage or stop using the inline
+                    // storage so it can't be accessed afterward.
+                    let value = unsafe { Self::take(this.as_mut(), i) };
+                    T::r#move(value, out);
+                }
+            }
+
+            munge!(let RelSmallVec { ptr, alloc, .. } = this.as_mut());
+            let new_ptr =
+                // SAFETY: `new_ptr` is allocated in `this.alloc`, and since
+                // `A` implements `RawRegionalAllocator` it guarantees that
+                // memory it allocates is located in its region.
+                unsafe { In::<_, A::Region>::new_unchecked(new_ptr) };
+            RelPtr::set(ptr, new_ptr);
+
+            if spilled {
+                // SAFETY:
+                // - `old_ptr` is currently allocated because the `RelSmallVec`
+                //   had spilled and has not been deallocated since.
+                // - `old_layout` was the layout used to allocate `old_ptr`. If
+                //   `grow_in_place` grew it too little, it still fits because
+                //   it is no larger than the grown block.
+                unsafe {
+                    RawAllocator::raw_deallocate(
+                        alloc.as_ref(),
+                        old_ptr,
+                        old_layout,
+                    );
+                }
+            }
+
+            Self::granted_cap(allocation, new_cap)
+        };
+
+        // `cap` is at most `new_cap`, which we already converted to the basis
+        // successfully.
+        let basis_cap = B::from_native_usize(cap).unwrap();
+        munge!(let RelSmallVec { cap: mut out_cap, .. } = this);
+        *out_cap = basis_cap;
+
+        Ok(())
+    }
+
+    /// Appends an element to the back of a collection.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn push<E>(mut this: Mut<'_, Self>, value: E)
+    where
+        T: Move<A::Region>,
+        E: Emplace<T, A::Region>,
+    {
+        Self::reserve(this.as_mut(), 1);
+        let len = this.len();
+
+        // SAFETY: `len` is definitely less than `capacity` because we reserved
+        // one slot at the end of our storage and `len` is equal to the length
+        // of the `RelSmallVec`.
+        let slot = unsafe { Self::slot(this.as_mut(), len) };
+        value.emplace(slot);
+
+        // SAFETY: `len + 1` must be less than or equal to `capacity` because we
+        // reserved space for one additional element. We just initialized that
+        // element by emplacing to it.
+        unsafe {
+            Self::set_len(this, len + 1);
+        }
+    }
+
+    /// Extends the `RelSmallVec` with the contents of an iterator.
+    pub fn extend<I>(mut this: Mut<'_, Self>, mut values: I)
+    where
+        T: Move<A::Region>,
+        I: Iterator,
+        I::Item: Emplace<T, A::Region>,
+    {
+        // This works the same way as `RelVec::extend`: we reserve space for
+        // the estimated size of the iterator and emplace as many values as we
+        // can before reserving again.
+        let mut next = values.next();
+        while next.is_some() {
+            let additional = values.size_hint().0.saturating_add(1);
+            Self::reserve(this.as_mut(), additional);
+
+            // If emplacing a value or advancing the iterator panics, the guard
+            // still sets the length to cover every value emplaced so far.
+            let mut guard = SetLenOnDrop {
+                len: this.len(),
+                vec: this.as_mut(),
+            };
+            while guard.len < guard.vec.capacity() {
+                if let Some(value) = next.take() {
+                    // SAFETY: `guard.len` is less than `capacity`.
+                    let slot =
+                        unsafe { Self::slot(guard.vec.as_mut(), guard.len) };
+                    value.emplace(slot);
+                    guard.len += 1;
+                    next = values.next();
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Clears the `RelSmallVec`, removing all values.
+    ///
+    /// Note that this method has no effect on the capacity of the
+    /// `RelSmallVec`, and does not move its elements back inline.
+    pub fn clear(mut this: Mut<'_, Self>)
+    where
+        T: DropRaw,
+    {
+        for i in 0..this.len() {
+            // SAFETY: `i` is less than `this.len()` and we set the `len` to 0
+            // so the removed element won't be accessed again.
+            let val = unsafe { Self::take(this.as_mut(), i) };
+            drop(val);
+        }
+        // SAFETY: 0 indicates that there are no initialized elements, and must
+        // be less than or equal to the current capacity because capacity cannot
+        // be less than 0.
+        unsafe {
+            Self::set_len(this, 0);
+        }
+    }
+}
+
+/// Sets the length of a `RelSmallVec` when dropped.
+///
+/// `len` must only count elements which have been initialized, and must be
+/// less than or equal to the capacity of `vec`.
+struct SetLenOnDrop<'a, T, const N: usize, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    vec: Mut<'a, RelSmallVec<T, N, A, B>>,
+    len: usize,
+}
+
+impl<T, const N: usize, A, B> Drop for SetLenOnDrop<'_, T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn drop(&mut self) {
+        // SAFETY: `len` is always less than or equal to the capacity of `vec`,
+        // and only counts elements which have been initialized.
+        unsafe {
+            RelSmallVec::set_len(self.vec.as_mut(), self.len);
+        }
+    }
+}
+
+/// # Safety
+///
+/// - `this` must point to an initialized field of a value which is being
+///   moved. The field must be valid for dropping and never accessed again.
+/// - `out` must point to the corresponding field of the slot being moved into.
+/// - Both fields must be located in `R`.
+unsafe fn move_field<U: Move<R>, R: Region>(this: *mut U, out: *mut U) {
+    // SAFETY: The caller has guaranteed that `this` points to an initialized
+    // field which we own and that is located in `R`.
+    let this = unsafe { In::new_unchecked(Val::new_unchecked(this)) };
+    // SAFETY: The caller has guaranteed that `out` points to a field of the
+    // slot being moved into, which is located in `R`.
+    let out = unsafe { In::new_unchecked(Slot::new_unchecked(out)) };
+    U::r#move(this, out);
+}
+
+// SAFETY: `move_unsized_unchecked` initializes its `out` parameter by moving
+// each of its fields and, if it has not spilled, each of its inline elements.
+// The inline elements are copied all at once if they can be moved bytewise.
+unsafe impl<T, const N: usize, A, B> Move<A::Region> for RelSmallVec<T, N, A, B>
+where
+    T: Move<A::Region>,
+    A: RawRegionalAllocator + Move<A::Region>,
+    B: Basis,
+    <B as Basis>::Usize: Move<A::Region>,
+{
+    unsafe fn move_unsized_unchecked(
+        this: In<Val<'_, Self>, A::Region>,
+        out: In<Slot<'_, Self>, A::Region>,
+    ) {
+        let len = this.len();
+        let spilled = this.spilled();
+        let this_ptr = Pointer::target(this.ptr());
+        let out_ptr = Pointer::target(out.ptr());
+        forget(this);
+
+        // SAFETY:
+        // - `this_ptr` points to a value which we own and have forgotten, so
+        //   each of its fields is only moved out of once.
+        // - `out_ptr` points to the slot being moved into.
+        // - `this_ptr` and `out_ptr` are both located in `A::Region`, so their
+        //   fields are as well.
+        unsafe {
+            move_field(
+                ptr::addr_of_mut!((*this_ptr).ptr),
+                ptr::addr_of_mut!((*out_ptr).ptr),
+            );
+            move_field(
+                ptr::addr_of_mut!((*this_ptr).len),
+                ptr::addr_of_mut!((*out_ptr).len),
+            );
+            move_field(
+                ptr::addr_of_mut!((*this_ptr).cap),
+                ptr::addr_of_mut!((*out_ptr).cap),
+            );
+            move_field(
+                ptr::addr_of_mut!((*this_ptr).alloc),
+                ptr::addr_of_mut!((*out_ptr).alloc),
+            );
+        }
+
+        if !spilled {
+            // SAFETY: `this_ptr` and `out_ptr` point to valid values, so the
+            // pointers to their inline storage are in bounds.
+            let (this_inline, out_inline) = unsafe {
+                (
+                    ptr::addr_of_mut!((*this_ptr).inline).cast::<T>(),
+                    ptr::addr_of_mut!((*out_ptr).inline).cast::<T>(),
+                )
+            };
+            if T::BYTEWISE {
+                // SAFETY:
+                // - The first `len` inline elements of `this` are initialized,
+                //   and we own them.
+                // - The inline storage of `out` has room for `N` elements, and
+                //   `len` is at most `N` because the `RelSmallVec` has not
+                //   spilled.
+                // - `this` and `out` are distinct values, so their inline
+                //   storage does not overlap.
+                // - `T` can be moved bytewise, so copying the elements moves
+                //   them.
+                unsafe {
+                    ptr::copy_nonoverlapping(this_inline, out_inline, len);
+                }
+            } else {
+                for i in 0..len {
+                    // SAFETY:
+                    // - `i` is less than `len`, so the inline element at `i`
+                    //   is initialized. We own it, and it is only moved out of
+                    //   once.
+                    // - The inline storage of `out` has room for `N` elements,
+                    //   and `len` is at most `N` because the `RelSmallVec` has
+                    //   not spilled.
+                    // - Both elements are located in their `RelSmallVec`s,
+                    //   which are located in `A::Region`.
+                    unsafe {
+                        move_field(this_inline.add(i), out_inline.add(i));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, A, B> DerefRaw for RelSmallVec<T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Target = [T];
+
+    fn deref_raw(this: Ref<'_, Self>) -> Ref<'_, [T]> {
+        let ptr = Self::as_ptr(this);
+        let slice_ptr = ptr::slice_from_raw_parts(ptr, this.len());
+
+        // SAFETY:
+        // - `ptr` is never null, and is always properly aligned and valid for
+        //   reads. `slice_ptr` is just a copy of `ptr`.
+        // - `this` is borrowed for `'_` so it cannot alias any other mutable
+        //   references for `'_`.
+        // - The first `len` elements of a `RelSmallVec` are always initialized.
+        unsafe { Ref::new_unchecked(slice_ptr) }
+    }
+}
+
+impl<T, const N: usize, A, B> DerefMutRaw for RelSmallVec<T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn deref_mut_raw(mut this: Mut<'_, Self>) -> Mut<'_, [T]> {
+        let ptr = Self::as_mut_ptr(this.as_mut());
+        let slice_ptr = ptr::slice_from_raw_parts_mut(ptr, this.len());
+
+        // SAFETY:
+        // - `ptr` is never null, and is always properly aligned and valid for
+        //   reads and writes. `slice_ptr` is just a copy of `ptr`.
+        // - `this` is borrowed for `'_` so it cannot alias any other accessible
+        //   references for `'_`.
+        // - The first `len` elements of a `RelSmallVec` are always initialized
+        //   and treated as immovable.
+        unsafe { Mut::new_unchecked(slice_ptr) }
+    }
+}
+
+impl<T, const N: usize, A, B> IndexRaw<usize> for RelSmallVec<T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Output = T;
+
+    fn index_raw(this: Ref<'_, Self>, index: usize) -> Ref<'_, Self::Output> {
+        IndexRaw::index_raw(DerefRaw::deref_raw(this), index)
+    }
+
+    unsafe fn index_raw_unchecked(
+        this: Ref<'_, Self>,
+        index: usize,
+    ) -> Ref<'_, Self::Output> {
+        // SAFETY: The caller has guaranteed that `index` is in bounds for
+        // indexing.
+        unsafe {
+            IndexRaw::index_raw_unchecked(DerefRaw::deref_raw(this), index)
+        }
+    }
+}
+
+impl<T, const N: usize, A, B> IndexMutRaw<usize> for RelSmallVec<T, N, A, B>
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn index_mut_raw(
+        this: Mut<'_, Self>,
+        index: usize,
+    ) -> Mut<'_, Self::Output> {
+        IndexMutRaw::index_mut_raw(DerefMutRaw::deref_mut_raw(this), index)
+    }
+
+    unsafe fn index_mut_raw_unchecked(
+        this: Mut<'_, Self>,
+        index: usize,
+    ) -> Mut<'_, Self::Output> {
+        // SAFETY: The caller has guaranteed that `index` is in bounds for
+        // indexing.
+        unsafe {
+            IndexMutRaw::index_mut_raw_unchecked(
+                DerefMutRaw::deref_mut_raw(this),
+                index,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize, A, B> DebugRaw for RelSmallVec<T, N, A, B>
+where
+    T: DebugRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        f.debug_list()
+            .entries((0..this.len()).map(|i| IndexRaw::index_raw(this, i)))
+            .finish()
+    }
+}
+
+impl<T, const N: usize, A, B> PartialEqRaw for RelSmallVec<T, N, A, B>
+where
+    T: PartialEqRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn eq_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> bool {
+        let other_elements =
+            (0..other.len()).map(|i| IndexRaw::index_raw(other, i));
+        (0..this.len())
+            .map(|i| IndexRaw::index_raw(this, i))
+            .eq(other_elements)
+    }
+}
+
+impl<T, const N: usize, A, B> EqRaw for RelSmallVec<T, N, A, B>
+where
+    T: EqRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+}
+
+// SAFETY: `validate` checks that the length is at most the capacity. If the
+// `RelSmallVec` has spilled, it claims the whole buffer and validates the
+// initialized elements. Otherwise, it checks that the capacity is `N` and
+// validates the initialized inline elements, which were claimed along with the
+// `RelSmallVec`. Then it validates the allocator.
+unsafe impl<T, const N: usize, A, B> Validate for RelSmallVec<T, N, A, B>
+where
+    T: Validate,
+    A: RawRegionalAllocator + Validate,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads. Every bit pattern is a valid `RelPtr`
+        // and `B::Usize`, so these fields are initialized.
+        let (rel_ptr, len, cap) = unsafe {
+            (
+                Ref::new_unchecked(ptr::addr_of!((*ptr).ptr)),
+                ptr::addr_of!((*ptr).len).read(),
+                ptr::addr_of!((*ptr).cap).read(),
+            )
+        };
+        let len = B::to_native_usize(len)
+            .map_err(|_| ValidateError::LayoutOverflow)?;
+        let cap = B::to_native_usize(cap)
+            .map_err(|_| ValidateError::LayoutOverflow)?;
+
+        if rel_ptr.is_null() {
+            if cap != N {
+                return Err(ValidateError::InvalidLength { len: cap, cap: N });
+            }
+            if len > N {
+                return Err(ValidateError::InvalidLength { len, cap: N });
+            }
+            // SAFETY: The caller has guaranteed that `ptr` is valid for reads,
+            // so its inline storage is as well.
+            let inline = unsafe { ptr::addr_of!((*ptr).inline).cast::<T>() };
+            for i in 0..len {
+                // SAFETY: `i` is less than `len`, which is at most `N`, so the
+                // element pointer is in bounds of the inline storage.
+                unsafe {
+                    T::validate(inline.add(i), validator)?;
+                }
+            }
+        } else {
+            let target = RelPtr::as_ptr_wrapping(rel_ptr)
+                .ok_or(ValidateError::NullPointer)?;
+            validate_slice(target, len, cap, validator)?;
+        }
+
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `alloc` field is as well.
+        unsafe { A::validate(ptr::addr_of!((*ptr).alloc), validator) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize, A, B> ToNative for RelSmallVec<T, N, A, B>
+where
+    T: ToNative,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Native = Vec<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        let slice = Self::as_slice(this);
+        (0..slice.len())
+            .map(|i| T::to_native(IndexRaw::index_raw(slice, i)))
+            .collect()
+    }
+}
+
+/// An emplacer for a new, empty `RelSmallVec`.
+///
+/// The new `RelSmallVec` stores its elements inline and does not allocate.
+pub struct New<R>(pub R);
+
+// SAFETY:
+// - `RelSmallVec` is `Sized` and always has metadata `()`, so `emplaced_meta`
+//   always returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   and writing to each field. The inline storage may be uninitialized.
+unsafe impl<T, const N: usize, A, B, R>
+    Emplace<RelSmallVec<T, N, A, B>, R::Region> for New<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelSmallVec<T, N, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelSmallVec<T, N, A, B>>, R::Region>,
+    ) {
+        let len = B::from_native_usize(0).unwrap();
+        let cap = B::from_native_usize(N).unwrap();
+
+        munge!(
+            let RelSmallVec {
+                ptr: out_ptr,
+                len: out_len,
+                cap: out_cap,
+                alloc: out_alloc,
+                ..
+            } = out;
+        );
+
+        Null.emplace(out_ptr);
+        In::into_inner(out_len).write(len);
+        In::into_inner(out_cap).write(cap);
+        self.0.emplace(out_alloc);
+    }
+}
+
+/// An emplacer for a new `RelSmallVec` with an initial capacity.
+///
+/// If the capacity is greater than `N`, the new `RelSmallVec` is spilled.
+pub struct WithCapacity<R>(pub R, pub usize);
+
+// SAFETY:
+// - `RelSmallVec` is `Sized` and always has metadata `()`, so `emplaced_meta`
+//   always returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   and writing to each field. The inline storage may be uninitialized.
+unsafe impl<T, const N: usize, A, B, R>
+    Emplace<RelSmallVec<T, N, A, B>, R::Region> for WithCapacity<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelSmallVec<T, N, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelSmallVec<T, N, A, B>>, R::Region>,
+    ) {
+        let Self(alloc, cap) = self;
+        if cap <= N {
+            New(alloc).emplace(out);
+            return;
+        }
+
+        // Everything that can panic is computed before allocating so that a
+        // panic can't leak the allocation.
+        let layout = Layout::array::<T>(cap).unwrap();
+        let len = B::from_native_usize(0).unwrap();
+        let cap = B::from_native_usize(cap).unwrap();
+
+        let ptr = alloc.allocate(layout).unwrap().cast().as_ptr();
+        // SAFETY: The pointer returned from `allocate` is guaranteed to be in
+        // the region of `R`.
+        let ptr = unsafe { In::new_unchecked(ptr) };
+
+        munge!(
+            let RelSmallVec {
+                ptr: out_ptr,
+                len: out_len,
+                cap: out_cap,
+                alloc: out_alloc,
+                ..
+            } = out;
+        );
+
+        ptr.emplace(out_ptr);
+        In::into_inner(out_len).write(len);
+        In::into_inner(out_cap).write(cap);
+        alloc.emplace(out_alloc);
+    }
+}
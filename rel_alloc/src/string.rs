@@ -1,21 +1,54 @@
 //! A UTF-8 encoded, growable string.
 
-use ::core::{fmt, ptr::copy_nonoverlapping};
+#[cfg(feature = "alloc")]
+use ::builtin_alloc::string::String;
+use ::core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ptr::{self, addr_of, copy_nonoverlapping},
+    str,
+};
 use ::mischief::{In, Slot};
 use ::munge::munge;
 use ::ptr_meta::Pointee;
-use ::rel_core::{Basis, DefaultBasis, Emplace, EmplaceExt, Move, Portable};
+#[cfg(feature = "alloc")]
+use ::rel_core::ToNative;
+use ::rel_core::{
+    Basis,
+    DefaultBasis,
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    Validate,
+    ValidateError,
+    Validator,
+};
+#[cfg(feature = "serde")]
+use ::serde::Serializer;
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
 use ::situ::{
     alloc::RawRegionalAllocator,
+    cmp::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw},
+    diff::{DiffPath, DiffRaw},
     fmt::{DebugRaw, DisplayRaw},
+    hash::HashRaw,
     ops::{DerefMutRaw, DerefRaw},
     str::{from_raw_utf8_unchecked, from_raw_utf8_unchecked_mut},
+    CloneRaw,
     DropRaw,
     Mut,
     Ref,
 };
 
-use crate::{alloc::RelAllocator, vec, RelVec};
+use crate::{
+    alloc::{RelAllocator, TryReserveError},
+    vec,
+    RelVec,
+    TryEmplace,
+};
 
 /// A relative counterpart to `String`.
 #[derive(DropRaw, Move, Portable)]
@@ -71,12 +104,118 @@ impl<A: RawRegionalAllocator, B: Basis> RelString<A, B> {
         unsafe { from_raw_utf8_unchecked_mut(bytes) }
     }
 
+    /// Converts this `RelString` to its ASCII upper case equivalent in place.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters
+    /// are unchanged.
+    #[inline]
+    pub fn make_ascii_uppercase(this: Mut<'_, Self>) {
+        Self::as_mut_str(this).make_ascii_uppercase();
+    }
+
+    /// Converts this `RelString` to its ASCII lower case equivalent in place.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters
+    /// are unchanged.
+    #[inline]
+    pub fn make_ascii_lowercase(this: Mut<'_, Self>) {
+        Self::as_mut_str(this).make_ascii_lowercase();
+    }
+
     /// Returns this `RelString`'s capacity, in bytes.
+    ///
+    /// Like [`len`](Self::len), this counts bytes rather than `char`s. A
+    /// `RelString` can hold `capacity - len` more bytes before reallocating,
+    /// which may be fewer `char`s since each `char` is one to four bytes long.
     #[inline]
     pub fn capacity(&self) -> usize {
         self.vec.capacity()
     }
 
+    /// Reserves capacity for at least `additional` more bytes to be appended
+    /// to the given `RelString`. The string may reserve more space to
+    /// speculatively avoid frequent reallocations. After calling `reserve`, the
+    /// capacity will be greater than or equal to `self.len() + additional`.
+    /// Does nothing if capacity is already sufficient.
+    ///
+    /// Note that `additional` is a number of bytes, not `char`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes or the allocator
+    /// fails to allocate the new buffer.
+    #[inline]
+    pub fn reserve(this: Mut<'_, Self>, additional: usize) {
+        munge!(let RelString { vec } = this);
+        RelVec::reserve(vec, additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes to be
+    /// appended to the given `RelString`. The string may reserve more space to
+    /// speculatively avoid frequent reallocations. After calling `try_reserve`,
+    /// the capacity will be greater than or equal to `self.len() + additional`
+    /// if it returns `Ok(())`. Does nothing if capacity is already sufficient.
+    ///
+    /// Note that `additional` is a number of bytes, not `char`s.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows or the allocator reports a failure, then an
+    /// error is returned and the `RelString` is left unchanged.
+    #[inline]
+    pub fn try_reserve(
+        this: Mut<'_, Self>,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        munge!(let RelString { vec } = this);
+        RelVec::try_reserve(vec, additional)
+    }
+
+    /// Inserts a string slice into this `RelString` at a byte position.
+    ///
+    /// The bytes after `idx` are shifted right to make room for `string`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the `RelString`'s length, or if it does
+    /// not lie on a `char` boundary.
+    pub fn insert_str(mut this: Mut<'_, Self>, idx: usize, string: &str) {
+        assert!(Self::as_str(this.as_ref()).is_char_boundary(idx));
+
+        let len = this.len();
+        let amt = string.len();
+        Self::reserve(this.as_mut(), amt);
+
+        // SAFETY: `string` is valid UTF-8 and is inserted at a `char`
+        // boundary, so the contents of the `RelString` remain valid UTF-8.
+        let mut vec = unsafe { Self::as_mut_vec(this) };
+        let ptr = RelVec::as_mut_ptr(vec.as_mut());
+        // SAFETY:
+        // - `idx` is a `char` boundary, so it is at most `len` and
+        //   `ptr.add(idx)` is valid for reads of `len - idx` bytes.
+        // - We reserved `amt` additional bytes, so `ptr.add(idx + amt)` is
+        //   valid for writes of `len - idx` bytes.
+        // - `ptr::copy` allows the source and destination to overlap.
+        unsafe {
+            ptr::copy(ptr.add(idx), ptr.add(idx + amt), len - idx);
+        }
+        // SAFETY:
+        // - `string` is valid for reads of `amt` bytes.
+        // - `ptr.add(idx)` is valid for writes of `amt` bytes because we
+        //   reserved `amt` additional bytes and shifted the tail out of the
+        //   way.
+        // - `this` is borrowed mutably, so `string` can't point into it and the
+        //   two regions can't overlap.
+        unsafe {
+            copy_nonoverlapping(string.as_ptr(), ptr.add(idx), amt);
+        }
+        // SAFETY: We reserved `amt` additional bytes, and all `len + amt` bytes
+        // are now initialized.
+        unsafe {
+            RelVec::set_len(vec, len + amt);
+        }
+    }
+
     /// Truncates this `RelString`, removing all contents.
     ///
     /// While this means the `String` will have a length of zero, it does not
@@ -100,6 +239,19 @@ impl<A: RawRegionalAllocator, B: Basis> RelString<A, B> {
     pub fn is_empty(&self) -> bool {
         self.vec.is_empty()
     }
+
+    /// Returns a native `String` containing a copy of this `RelString`'s
+    /// contents.
+    ///
+    /// This copies the bytes of the `RelString` directly, and does not use
+    /// formatting like `to_string` does.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_string_native(this: Ref<'_, Self>) -> String {
+        let bytes = Self::as_bytes(this).to_vec();
+        // SAFETY: The bytes of a `RelString` are always valid UTF-8.
+        unsafe { String::from_utf8_unchecked(bytes) }
+    }
 }
 
 impl<A: RawRegionalAllocator, B: Basis> DerefRaw for RelString<A, B> {
@@ -110,6 +262,73 @@ impl<A: RawRegionalAllocator, B: Basis> DerefRaw for RelString<A, B> {
     }
 }
 
+// SAFETY: `clone_raw` initializes its `out` parameter by emplacing a copy of
+// the string to it.
+unsafe impl<A, B, R> CloneRaw<R> for RelString<A, B>
+where
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: RelAllocator<A>,
+{
+    fn clone_raw(
+        this: Ref<'_, Self>,
+        alloc: R,
+        out: In<Slot<'_, Self>, R::Region>,
+    ) {
+        Clone(alloc, &Self::as_str(this)).emplace(out);
+    }
+}
+
+// SAFETY: `validate` validates the underlying `RelVec` and then checks that its
+// contents are valid UTF-8.
+unsafe impl<A, B> Validate for RelString<A, B>
+where
+    A: RawRegionalAllocator + Validate,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `vec` field is as well.
+        unsafe {
+            RelVec::validate(addr_of!((*ptr).vec), validator)?;
+        }
+
+        // SAFETY: We just validated the `RelVec` that `ptr` contains, so all of
+        // its bytes are initialized.
+        let this = unsafe { Ref::new_unchecked(ptr) };
+        str::from_utf8(&Self::as_bytes(this)).map_err(|e| {
+            ValidateError::InvalidUtf8 {
+                valid_up_to: e.valid_up_to(),
+            }
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: RawRegionalAllocator, B: Basis> ToNative for RelString<A, B> {
+    type Native = String;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        Self::to_string_native(this)
+    }
+}
+
+// `RelString`s serialize like the `str`s they contain.
+#[cfg(feature = "serde")]
+impl<A: RawRegionalAllocator, B: Basis> SerializeRaw for RelString<A, B> {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&Self::as_str(this))
+    }
+}
+
 /// An emplacer for a `RelString` that copies its bytes from a `str`.
 pub struct Clone<'a, R>(pub R, pub &'a str);
 
@@ -130,37 +349,110 @@ where
         self,
         out: In<Slot<'_, RelString<A, B>>, A::Region>,
     ) {
-        let len = self.1.len();
+        let Self(alloc, string) = self;
 
         munge!(let RelString { vec: out_vec } = out);
-        let mut vec =
-            In::into_inner(vec::WithCapacity(self.0, len).emplace_mut(out_vec));
-        // SAFETY:
-        // - `src.1.as_ptr()` is valid for reads of `len` bytes because it is a
-        //   pointer to a `&str` of length `len`.
-        // - `RelVec::as_mut_ptr` is valid for writes of `len` bytes because it
-        //   was emplaced with capacity `len`.
-        // - Both `str` and `RelVec<u8>` are allocated with the proper alignment
-        //   for `u8`.
-        // - The two regions of memory cannot overlap because `vec` is newly
-        //   allocated and points to unaliased memory.
+        let vec = In::into_inner(
+            vec::WithCapacity(alloc, string.len()).emplace_mut(out_vec),
+        );
+        // SAFETY: `vec` is empty and was emplaced with a capacity of
+        // `string.len()`.
         unsafe {
-            copy_nonoverlapping(
-                self.1.as_ptr(),
-                RelVec::as_mut_ptr(vec.as_mut()),
-                len,
-            );
+            copy_from_str(vec, string);
         }
+    }
+}
+
+// SAFETY:
+// - `RelString` is `Sized` and always has metadata `()`, so `emplaced_meta`
+//   always returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter by
+//   emplacing to each field whenever it returns `Ok`.
+unsafe impl<A, B, R> TryEmplace<RelString<A, B>, R::Region> for Clone<'_, R>
+where
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelString<A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelString<A, B>>, A::Region>,
+    ) -> Result<(), TryReserveError> {
+        let Self(alloc, string) = self;
+
+        munge!(let RelString { vec: out_vec } = out);
+        let vec_ptr = out_vec.ptr().as_ptr();
+        vec::WithCapacity(alloc, string.len()).try_emplace(out_vec)?;
         // SAFETY:
-        // - `len` is exactly equal to capacity.
-        // - We initialized all of the bytes of the `RelVec` by copying the
-        //   bytes of the emplaced string to them.
+        // - `vec_ptr` is the pointer of `out_vec`, so it is non-null, properly
+        //   aligned, and valid for reads and writes.
+        // - `out_vec` was consumed by `try_emplace`, so the returned `Mut` does
+        //   not alias any other accessible references.
+        // - `try_emplace` returned `Ok`, so `out_vec` was initialized in place
+        //   and is immovable.
+        let vec = unsafe { Mut::new_unchecked(vec_ptr) };
+        // SAFETY: `vec` is empty and was emplaced with a capacity of
+        // `string.len()`.
         unsafe {
-            RelVec::set_len(vec, len);
+            copy_from_str(vec, string);
         }
+
+        Ok(())
     }
 }
 
+/// Copies the bytes of `string` into `vec`.
+///
+/// # Safety
+///
+/// `vec` must be empty and have a capacity of at least `string.len()`.
+unsafe fn copy_from_str<A, B>(mut vec: Mut<'_, RelVec<u8, A, B>>, string: &str)
+where
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    let len = string.len();
+    // SAFETY:
+    // - `string.as_ptr()` is valid for reads of `len` bytes because it is a
+    //   pointer to a `&str` of length `len`.
+    // - `RelVec::as_mut_ptr` is valid for writes of `len` bytes because the
+    //   caller has guaranteed that `vec` has a capacity of at least `len`.
+    // - Both `str` and `RelVec<u8>` are allocated with the proper alignment for
+    //   `u8`.
+    // - The two regions of memory cannot overlap because `vec` is newly
+    //   allocated and points to unaliased memory.
+    unsafe {
+        copy_nonoverlapping(
+            string.as_ptr(),
+            RelVec::as_mut_ptr(vec.as_mut()),
+            len,
+        );
+    }
+    // SAFETY:
+    // - The caller has guaranteed that `len` is less than or equal to the
+    //   capacity of `vec`.
+    // - We initialized the first `len` bytes of the `RelVec` by copying the
+    //   bytes of the emplaced string to them.
+    unsafe {
+        RelVec::set_len(vec, len);
+    }
+}
+
+/// Creates an emplacer for a `RelString` that copies its bytes from a `str`.
+///
+/// The allocator is given first, followed by the string:
+/// `rel_string!(in alloc; "hello")`. The returned emplacer can be used with
+/// `emplace_in` or any other emplacement method, including as an element of
+/// [`rel_vec!`](crate::rel_vec).
+#[macro_export]
+macro_rules! rel_string {
+    (in $alloc:expr; $value:expr $(,)?) => {
+        $crate::string::Clone($alloc, $value)
+    };
+}
+
 impl<A: RawRegionalAllocator, B: Basis> DebugRaw for RelString<A, B> {
     fn fmt_raw(
         this: Ref<'_, Self>,
@@ -178,3 +470,43 @@ impl<A: RawRegionalAllocator, B: Basis> DisplayRaw for RelString<A, B> {
         fmt::Display::fmt(&*Self::as_str(this), f)
     }
 }
+
+// `RelString`s hash identically to the `str`s they contain.
+impl<A: RawRegionalAllocator, B: Basis> HashRaw for RelString<A, B> {
+    fn hash_raw<H: Hasher>(this: Ref<'_, Self>, state: &mut H) {
+        Self::as_str(this).hash(state);
+    }
+}
+
+impl<A: RawRegionalAllocator, B: Basis> PartialEqRaw for RelString<A, B> {
+    fn eq_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> bool {
+        *Self::as_str(this) == *Self::as_str(other)
+    }
+}
+
+impl<A: RawRegionalAllocator, B: Basis> EqRaw for RelString<A, B> {}
+
+impl<A: RawRegionalAllocator, B: Basis> DiffRaw for RelString<A, B> {
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        _: &mut P,
+    ) -> bool {
+        *Self::as_str(this) != *Self::as_str(other)
+    }
+}
+
+impl<A: RawRegionalAllocator, B: Basis> PartialOrdRaw for RelString<A, B> {
+    fn partial_cmp_raw(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+    ) -> Option<Ordering> {
+        Some(Self::cmp_raw(this, other))
+    }
+}
+
+impl<A: RawRegionalAllocator, B: Basis> OrdRaw for RelString<A, B> {
+    fn cmp_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> Ordering {
+        Self::as_str(this).cmp(&Self::as_str(other))
+    }
+}
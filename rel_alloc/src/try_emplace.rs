@@ -0,0 +1,127 @@
+use ::mischief::{Frame, In, Metadata, Region, RegionalAllocator, Slot};
+use ::ptr_meta::Pointee;
+use ::situ::{DropRaw, OwnedVal};
+
+use crate::alloc::TryReserveError;
+
+/// A value emplacer which may fail to allocate memory.
+///
+/// `TryEmplace` is the fallible counterpart to `Emplace`. Emplacers which
+/// allocate (like [`vec::WithCapacity`](crate::vec::WithCapacity)) implement
+/// both: `Emplace` panics when an allocation fails, and `TryEmplace` returns
+/// the error instead. Combined with [`TryEmplaceIn`], this lets allocation
+/// failures propagate all the way up to the caller so that a full allocator can
+/// be handled gracefully.
+///
+/// # Safety
+///
+/// - `emplaced_meta` must return valid metadata for the value emplaced with
+///   `try_emplace_unsized_unchecked`.
+/// - If `try_emplace_unsized_unchecked` returns `Ok`, it must have initialized
+///   its `out` parameter. If it returns `Err`, `out` must be treated as
+///   uninitialized.
+pub unsafe trait TryEmplace<T: DropRaw + Pointee + ?Sized, R: Region> {
+    /// Returns the metadata of the `T` that this emplaces.
+    ///
+    /// For sized `T`, this is always `()`.
+    fn emplaced_meta(&self) -> <T as Pointee>::Metadata;
+
+    /// Attempts to emplace a value into a given slot within some memory region.
+    ///
+    /// # Safety
+    ///
+    /// `out` must have the metadata returned by `emplaced_meta`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if emplacing the value requires allocating memory and the
+    /// allocation fails. Any memory which was allocated before the failure may
+    /// be leaked.
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, T>, R>,
+    ) -> Result<(), TryReserveError>;
+
+    /// Attempts to emplace a sized value into a given slot within some memory
+    /// region.
+    ///
+    /// This simply wraps a call to `try_emplace_unsized_unchecked`. Because `T`
+    /// is `Sized`, the metadata of the slot's pointer must always match the
+    /// metadata returned from `emplaced_meta`, and so it is safe.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if emplacing the value requires allocating memory and the
+    /// allocation fails.
+    #[inline]
+    fn try_emplace(self, out: In<Slot<'_, T>, R>) -> Result<(), TryReserveError>
+    where
+        T: Sized,
+        Self: Sized,
+    {
+        // SAFETY: `out` can only have the metadata `()` and so it must be the
+        // same as the metadata returned from `emplaced_meta`.
+        unsafe { self.try_emplace_unsized_unchecked(out) }
+    }
+}
+
+/// An extension trait for `TryEmplace` that provides a fallible allocating
+/// emplacement function.
+///
+/// This is the fallible counterpart to [`EmplaceIn`](crate::EmplaceIn).
+pub trait TryEmplaceIn<A: RegionalAllocator> {
+    /// Attempts to emplace a value into a new `OwnedVal` allocated from the
+    /// given allocator and returns it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if allocating the `OwnedVal` or emplacing the value into
+    /// it fails.
+    fn try_emplace_in<T>(
+        self,
+        alloc: A,
+    ) -> Result<OwnedVal<T, A>, TryReserveError>
+    where
+        T: DropRaw + Pointee + ?Sized,
+        <T as Pointee>::Metadata: Metadata<T>,
+        Self: TryEmplace<T, A::Region>;
+}
+
+impl<E, A> TryEmplaceIn<A> for E
+where
+    A: RegionalAllocator,
+{
+    #[inline]
+    fn try_emplace_in<T>(
+        self,
+        alloc: A,
+    ) -> Result<OwnedVal<T, A>, TryReserveError>
+    where
+        T: DropRaw + Pointee + ?Sized,
+        <T as Pointee>::Metadata: Metadata<T>,
+        Self: TryEmplace<T, A::Region>,
+    {
+        let metadata = self.emplaced_meta();
+        // SAFETY: The pointer metadata is from `emplaced_meta`, which is
+        // guaranteed to be valid for a pointer to `T`.
+        let frame = unsafe { Frame::try_new_unsized_in(metadata, alloc) }
+            .map_err(|_| TryReserveError::AllocError {
+                // SAFETY: The pointer metadata is from `emplaced_meta`, which
+                // is guaranteed to be valid for a pointer to `T`.
+                layout: unsafe { metadata.pointee_layout() },
+            })?;
+
+        let mut frame = In::new(frame);
+        let slot = frame.slot();
+
+        // SAFETY: We just allocated the slot in a frame with the metadata from
+        // `emplaced_meta`.
+        unsafe {
+            self.try_emplace_unsized_unchecked(slot)?;
+        }
+        // SAFETY: `try_emplace_unsized_unchecked` returned `Ok`, so it is
+        // guaranteed to have initialized the slot. That slot is from the frame,
+        // so the frame is initialized.
+        Ok(unsafe { OwnedVal::assume_init(In::into_inner(frame)) })
+    }
+}
@@ -1,11 +1,24 @@
 //! A contiguous growable array type with heap-allocated contents, written
 //! `RelVec<T>`.
 
-use ::core::{alloc::Layout, fmt, ptr};
+#[cfg(feature = "alloc")]
+use ::builtin_alloc::vec::Vec;
+use ::core::{
+    alloc::Layout,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    mem,
+    ptr,
+};
 use ::mischief::{In, Slot};
 use ::munge::munge;
 use ::ptr_meta::Pointee;
+#[cfg(feature = "alloc")]
+use ::rel_core::ToNative;
 use ::rel_core::{
+    validate_slice,
     Basis,
     DefaultBasis,
     Emplace,
@@ -14,18 +27,32 @@ use ::rel_core::{
     MoveExt,
     Portable,
     RelPtr,
+    Validate,
+    ValidateError,
+    Validator,
 };
+#[cfg(feature = "serde")]
+use ::serde::Serializer;
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
 use ::situ::{
     alloc::{RawAllocator, RawRegionalAllocator},
+    cmp::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw},
+    diff::{DiffPath, DiffRaw, PathSegment},
     fmt::DebugRaw,
+    hash::HashRaw,
     ops::{DerefMutRaw, DerefRaw, IndexMutRaw, IndexRaw},
+    CloneRaw,
     DropRaw,
     Mut,
     Ref,
     Val,
 };
 
-use crate::alloc::RelAllocator;
+use crate::{
+    alloc::{RelAllocator, TryReserveError},
+    TryEmplace,
+};
 
 /// A relative counterpart to `Vec`.
 #[derive(Move, Portable)]
@@ -46,7 +73,8 @@ where
 {
     #[inline]
     unsafe fn drop_raw(mut this: Mut<'_, Self>) {
-        let layout = Layout::array::<T>(this.capacity()).unwrap();
+        // SAFETY: `capacity` is the capacity of an existing `RelVec`.
+        let layout = unsafe { Self::layout_for(this.capacity()) };
         let inner = Self::deref_mut_raw(this.as_mut());
 
         let inner_ptr = inner.as_non_null();
@@ -141,6 +169,63 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
         DerefRaw::deref_raw(this)
     }
 
+    /// Returns a `Mut` to a slice of the elements in the `RelVec`.
+    #[inline]
+    pub fn as_slice_mut(this: Mut<'_, Self>) -> Mut<'_, [T]> {
+        DerefMutRaw::deref_mut_raw(this)
+    }
+
+    /// Returns an iterator over `Mut`s of the elements of the `RelVec`.
+    #[inline]
+    pub fn iter_mut(this: Mut<'_, Self>) -> IterMut<'_, T> {
+        IterMut {
+            rest: Some(Self::as_slice_mut(this)),
+        }
+    }
+
+    /// Returns an iterator over `Ref`s of the elements of the `RelVec`.
+    #[inline]
+    pub fn iter(this: Ref<'_, Self>) -> Iter<'_, T> {
+        let slice = Self::as_slice(this);
+        Iter {
+            slice,
+            start: 0,
+            end: slice.len(),
+        }
+    }
+
+    /// Returns an iterator over the elements of the `RelVec` paired with their
+    /// indices.
+    #[inline]
+    pub fn iter_enumerated(
+        this: Ref<'_, Self>,
+    ) -> impl Iterator<Item = (usize, Ref<'_, T>)> {
+        Self::iter(this).enumerate()
+    }
+
+    /// Returns `true` if the `RelVec` contains an element equal to `needle`.
+    ///
+    /// This stops searching at the first matching element.
+    #[inline]
+    pub fn contains(this: Ref<'_, Self>, needle: Ref<'_, T>) -> bool
+    where
+        T: PartialEqRaw,
+    {
+        Self::iter(this).any(|element| PartialEqRaw::eq_raw(element, needle))
+    }
+
+    /// Returns the index of the first element of the `RelVec` for which `pred`
+    /// returns `true`, or `None` if there are no such elements.
+    ///
+    /// This stops searching at the first matching element.
+    #[inline]
+    pub fn position_by<F>(this: Ref<'_, Self>, pred: F) -> Option<usize>
+    where
+        F: FnMut(Ref<'_, T>) -> bool,
+    {
+        Self::iter(this).position(pred)
+    }
+
     /// # Safety
     ///
     /// `index` must be less than `capacity`.
@@ -185,6 +270,43 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
         unsafe { In::map_unchecked(slot, initialize) }
     }
 
+    /// Returns the layout of an allocation with room for `cap` elements, or
+    /// an error if it would be larger than `isize::MAX` bytes.
+    #[inline]
+    fn try_layout_for(cap: usize) -> Result<Layout, TryReserveError> {
+        Layout::array::<T>(cap).map_err(|_| TryReserveError::CapacityOverflow)
+    }
+
+    /// Returns the number of elements which fit in the memory returned by an
+    /// allocator, up to the requested capacity `cap`.
+    #[inline]
+    fn granted_cap(bytes: ptr::NonNull<[u8]>, cap: usize) -> usize {
+        match mem::size_of::<T>() {
+            0 => cap,
+            size => (::ptr_meta::metadata(bytes.as_ptr()) / size).min(cap),
+        }
+    }
+
+    /// Returns the layout of an allocation with room for `cap` elements.
+    ///
+    /// # Safety
+    ///
+    /// The layout for `cap` elements must have already been computed
+    /// successfully. This is always the case for the capacity of an existing
+    /// `RelVec`.
+    #[inline]
+    unsafe fn layout_for(cap: usize) -> Layout {
+        // SAFETY: The caller has guaranteed that `Layout::array::<T>(cap)` has
+        // already succeeded, so `size_of::<T>() * cap` does not overflow and
+        // rounds up to at most `isize::MAX` bytes.
+        unsafe {
+            Layout::from_size_align_unchecked(
+                mem::size_of::<T>() * cap,
+                mem::align_of::<T>(),
+            )
+        }
+    }
+
     /// Reserves capacity for at least `additional` more elements to be inserted
     /// in the given `RelVec<T>`. The collection may reserve more space to
     /// speculatively avoid frequent reallocations. After calling `reserve`, the
@@ -193,17 +315,49 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
     ///
     /// # Panics
     ///
-    /// Panics if the new capacity exceeds `isize::MAX` bytes.
-    pub fn reserve(mut this: Mut<'_, Self>, additional: usize)
+    /// Panics if the new capacity exceeds `isize::MAX` bytes or the allocator
+    /// fails to allocate the new buffer.
+    pub fn reserve(this: Mut<'_, Self>, additional: usize)
     where
         T: Move<A::Region>,
     {
-        let min_cap = this.len() + additional;
+        if let Err(e) = Self::try_reserve(this, additional) {
+            panic!("{e}");
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements to be
+    /// inserted in the given `RelVec<T>`. The collection may reserve more space
+    /// to speculatively avoid frequent reallocations. After calling
+    /// `try_reserve`, the capacity will be greater than or equal to
+    /// `self.len() + additional` if it returns `Ok(())`. Does nothing if
+    /// capacity is already sufficient.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows or the allocator reports a failure, then an
+    /// error is returned and the `RelVec` is left unchanged.
+    pub fn try_reserve(
+        mut this: Mut<'_, Self>,
+        additional: usize,
+    ) -> Result<(), TryReserveError>
+    where
+        T: Move<A::Region>,
+    {
+        let min_cap = this
+            .len()
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
         if min_cap > this.capacity() {
-            let new_cap = min_cap.checked_next_power_of_two().unwrap();
+            let new_cap = min_cap
+                .checked_next_power_of_two()
+                .ok_or(TryReserveError::CapacityOverflow)?;
+            B::from_native_usize(new_cap)
+                .map_err(|_| TryReserveError::CapacityOverflow)?;
 
-            let old_layout = Layout::array::<T>(this.capacity()).unwrap();
-            let new_layout = Layout::array::<T>(new_cap).unwrap();
+            // SAFETY: `capacity` is the capacity of an existing `RelVec`.
+            let old_layout = unsafe { Self::layout_for(this.capacity()) };
+            let new_layout = Self::try_layout_for(new_cap)?;
 
             let ptr = Self::as_mut_ptr(this.as_mut());
             // SAFETY: The pointer of a `RelVec` is always non-null.
@@ -215,44 +369,79 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
             // - `new_layout` has a strictly larger size than `old_layout`
             //   because `new_cap` is greater than `min_cap`, which is greater
             //   than `this.capacity()`.
-            let grew_in_place = unsafe {
+            let grown = unsafe {
                 RawAllocator::raw_grow_in_place(
                     Self::allocator(this.as_ref()),
                     old_ptr,
                     old_layout,
                     new_layout,
                 )
-                .is_ok()
             };
+            // The allocator may grant less than the power-of-two capacity we
+            // asked for, so the capacity comes from the memory it returned.
+            // Growth which doesn't make room for `min_cap` elements doesn't
+            // count.
+            let grown_cap = grown
+                .ok()
+                .map(|bytes| Self::granted_cap(bytes, new_cap))
+                .filter(|&cap| cap >= min_cap);
 
-            if !grew_in_place {
+            let cap = if let Some(cap) = grown_cap {
+                cap
+            } else {
                 let allocation = RawAllocator::raw_allocate(
                     Self::allocator(this.as_ref()),
                     new_layout,
                 );
-                let new_ptr = allocation.unwrap().as_ptr().cast::<T>();
-                for i in 0..this.len() {
+                let allocation = allocation.map_err(|_| {
+                    TryReserveError::AllocError { layout: new_layout }
+                })?;
+                let new_ptr = allocation.as_ptr().cast::<T>();
+                if T::BYTEWISE {
                     // SAFETY:
-                    // - `new_ptr` is the pointer of a `NonNull`, so it must be
-                    //   non-null. It is guaranteed to be aligned to
-                    //   `new_layout.align()` by the implementation of
-                    //   `RawAllocator`, which is at least `align_of::<T>()`. It
-                    //   is also guaranteed to be valid for reads and writes of
-                    //   at least `new_layout.size()` bytes, which covers every
-                    //   element slot in `new_ptr`.
-                    // - `new_ptr` is freshly-allocated, so only we have access
-                    //   to it. It is not currently aliased by any other
-                    //   pointers.
-                    let out = unsafe { Slot::new_unchecked(new_ptr.add(i)) };
-                    // SAFETY: `new_ptr` is allocated in `this.alloc`, and since
-                    // `A` implements `RawRegionalAllocator`, it guarantees that
-                    // memory it allocates is located in its region.
-                    let out = unsafe { In::new_unchecked(out) };
-                    // SAFETY: `i` is less than `len` and we move out of it then
-                    // free the backing storage so it can't be accessed
-                    // afterward.
-                    let value = unsafe { Self::take(this.as_mut(), i) };
-                    T::r#move(value, out);
+                    // - The first `len` elements of `old_ptr` are initialized,
+                    //   so it is valid for reads of `len` elements.
+                    // - `new_ptr` is valid for writes of `new_cap` elements,
+                    //   which is greater than `len`.
+                    // - `new_ptr` is freshly-allocated, so it does not overlap
+                    //   `old_ptr`.
+                    // - `T` can be moved bytewise, so copying the elements
+                    //   moves them. The storage for the old elements is freed
+                    //   afterward, so they can't be accessed again.
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            old_ptr.as_ptr().cast::<T>(),
+                            new_ptr,
+                            this.len(),
+                        );
+                    }
+                } else {
+                    for i in 0..this.len() {
+                        // SAFETY:
+                        // - `new_ptr` is the pointer of a `NonNull`, so it must
+                        //   be non-null. It is guaranteed to be aligned to
+                        //   `new_layout.align()` by the implementation of
+                        //   `RawAllocator`, which is at least
+                        //   `align_of::<T>()`. It is also guaranteed to be
+                        //   valid for reads and writes of at least
+                        //   `new_layout.size()` bytes, which covers every
+                        //   element slot in `new_ptr`.
+                        // - `new_ptr` is freshly-allocated, so only we have
+                        //   access to it. It is not currently aliased by any
+                        //   other pointers.
+                        let out =
+                            unsafe { Slot::new_unchecked(new_ptr.add(i)) };
+                        // SAFETY: `new_ptr` is allocated in `this.alloc`, and
+                        // since `A` implements `RawRegionalAllocator`, it
+                        // guarantees that memory it allocates is located in its
+                        // region.
+                        let out = unsafe { In::new_unchecked(out) };
+                        // SAFETY: `i` is less than `len` and we move out of it
+                        // then free the backing storage so it can't be
+                        // accessed afterward.
+                        let value = unsafe { Self::take(this.as_mut(), i) };
+                        T::r#move(value, out);
+                    }
                 }
 
                 munge!(let RelVec { ptr, alloc, .. } = this.as_mut());
@@ -265,8 +454,10 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
 
                 // SAFETY:
                 // - `old_ptr` is currently allocated because it was previously
-                //   allocated and `grow_in_place` failed.
-                // - `old_layout` was the layout used to allocate `old_ptr`.
+                //   allocated and has not been deallocated since.
+                // - `old_layout` was the layout used to allocate `old_ptr`. If
+                //   `grow_in_place` grew it too little, it still fits because
+                //   it is no larger than the grown block.
                 unsafe {
                     RawAllocator::raw_deallocate(
                         alloc.as_ref(),
@@ -274,11 +465,18 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
                         old_layout,
                     );
                 }
-            }
 
-            munge!(let RelVec { mut cap, .. } = this);
-            *cap = B::from_native_usize(new_cap).unwrap();
+                Self::granted_cap(allocation, new_cap)
+            };
+
+            // `cap` is at most `new_cap`, which we already converted to the
+            // basis successfully.
+            let basis_cap = B::from_native_usize(cap).unwrap();
+            munge!(let RelVec { cap: mut out_cap, .. } = this);
+            *out_cap = basis_cap;
         }
+
+        Ok(())
     }
 
     /// Appends an element to the back of a collection.
@@ -316,37 +514,178 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
         I::Item: Emplace<T, A::Region>,
     {
         // We can avoid setting our length every time we push a new element by
-        // reserving the iterator's estimated size and pushing as many as we can
-        // up to that limit. Then
-        let reserve = values.size_hint().0;
-        let mut new_len = this.len();
-
-        Self::reserve(this.as_mut(), reserve);
-        while new_len < this.capacity() {
-            if let Some(value) = values.next() {
-                // SAFETY: `len + i` must be less than `capacity` because we
-                // reserved `reserve` slots at the end of our storage, `len`
-                // is equal to the length of the `RelVec`, and `i` is less than
-                // `reserve`.
-                let slot = unsafe { Self::slot(this.as_mut(), new_len) };
-                new_len += 1;
-                value.emplace(slot);
-            } else {
-                break;
+        // reserving the iterator's estimated size and emplacing as many values
+        // as we can up to that limit. The `size_hint` from `values` isn't
+        // required to be accurate, so we repeat this until the iterator is
+        // exhausted. We always reserve at least one more slot than we have, and
+        // `reserve` rounds up to the next power of two, so capacity grows
+        // geometrically even when the lower bound of the `size_hint` is zero.
+        let mut next = values.next();
+        while next.is_some() {
+            let additional = values.size_hint().0.saturating_add(1);
+            Self::reserve(this.as_mut(), additional);
+
+            // If emplacing a value or advancing the iterator panics, the guard
+            // still sets the length to cover every value emplaced so far. We
+            // only count a value after it has been successfully emplaced.
+            let mut guard = SetLenOnDrop {
+                len: this.len(),
+                vec: this.as_mut(),
+            };
+            while guard.len < guard.vec.capacity() {
+                if let Some(value) = next.take() {
+                    // SAFETY: `guard.len` is less than `capacity`.
+                    let slot =
+                        unsafe { Self::slot(guard.vec.as_mut(), guard.len) };
+                    value.emplace(slot);
+                    guard.len += 1;
+                    next = values.next();
+                } else {
+                    break;
+                }
             }
         }
+    }
+
+    /// Moves all of the elements of `other` onto the end of `this`, leaving
+    /// `other` empty.
+    ///
+    /// Both `RelVec`s have the same allocator type, so their elements are
+    /// located in the same region and may be moved between them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn append(mut this: Mut<'_, Self>, mut other: Mut<'_, Self>)
+    where
+        T: Move<A::Region>,
+    {
+        let count = other.len();
+        Self::reserve(this.as_mut(), count);
 
-        // SAFETY: `len + reserve` must be less than or equal to `capacity`
-        // because we reserved space for `reserve` additional elements. We just
-        // initialized those element by emplacing to them.
+        // The elements of `other` are moved out of, so `other` is emptied
+        // first. If moving an element panics, the rest of the elements of
+        // `other` are leaked instead of being dropped twice.
+        // SAFETY: 0 is always less than or equal to the capacity, and there
+        // are no elements in `0..0` which must be initialized.
         unsafe {
-            Self::set_len(this.as_mut(), new_len);
+            Self::set_len(other.as_mut(), 0);
+        }
+
+        let mut guard = SetLenOnDrop {
+            len: this.len(),
+            vec: this.as_mut(),
+        };
+        if T::BYTEWISE {
+            let src = Self::as_mut_ptr(other.as_mut());
+            let dst = Self::as_mut_ptr(guard.vec.as_mut());
+            // SAFETY:
+            // - `src` points to the `count` elements of `other`, which are
+            //   initialized and valid for reads.
+            // - We reserved space for `count` elements after the first `len`
+            //   elements of `this`, so `dst.add(len)` is valid for writes of
+            //   `count` elements.
+            // - `this` and `other` are both mutable borrows, so their buffers
+            //   do not overlap.
+            // - `T` can be moved bytewise, so copying the elements moves them.
+            //   `other` has already been emptied, so they will not be accessed
+            //   through it again.
+            unsafe {
+                ptr::copy_nonoverlapping(src, dst.add(guard.len), count);
+            }
+            guard.len += count;
+        } else {
+            for i in 0..count {
+                // SAFETY: We reserved space for `count` more elements, so
+                // `guard.len` is less than `capacity`.
+                let out = unsafe { Self::slot(guard.vec.as_mut(), guard.len) };
+                // SAFETY: `i` is less than the original length of `other`,
+                // and each element is only taken once. `other` has already
+                // been emptied, so the element won't be accessed again.
+                let value = unsafe { Self::take(other.as_mut(), i) };
+                T::r#move(value, out);
+                guard.len += 1;
+            }
+        }
+    }
+
+    /// Removes all but the first of consecutive elements in the `RelVec` for
+    /// which `same` returns `true`.
+    ///
+    /// `same` is passed each element along with the last element which was
+    /// kept before it. If it returns `true`, the element is dropped. Otherwise,
+    /// the element is kept and moved forward to follow the last kept element.
+    /// If the `RelVec` is sorted, this removes all duplicates.
+    ///
+    /// If `same` or dropping an element panics, the remaining elements are
+    /// leaked.
+    pub fn dedup_by<F>(mut this: Mut<'_, Self>, mut same: F)
+    where
+        T: Move<A::Region>,
+        F: FnMut(Ref<'_, T>, Ref<'_, T>) -> bool,
+    {
+        let len = this.len();
+        if len <= 1 {
+            return;
+        }
+
+        // Elements are dropped and moved out of while deduplicating, so the
+        // `RelVec` is emptied until we know how many elements were kept.
+        // SAFETY: 0 is always less than or equal to the capacity, and there
+        // are no elements in `0..0` which must be initialized.
+        unsafe {
+            Self::set_len(this.as_mut(), 0);
+        }
+
+        let ptr = Self::as_mut_ptr(this.as_mut());
+        // The elements in `0..kept` are kept, the elements in `kept..i` have
+        // been dropped or moved out of, and the elements in `i..len` have not
+        // been visited yet.
+        let mut kept = 1;
+        for i in 1..len {
+            // SAFETY: `i` and `kept - 1` are both less than `len`. The element
+            // at `i` has not been visited yet, and the element at `kept - 1`
+            // is kept, so both are initialized. No mutable references to them
+            // exist while `same` is called.
+            let is_same = unsafe {
+                same(
+                    Ref::new_unchecked(ptr.add(i)),
+                    Ref::new_unchecked(ptr.add(kept - 1)),
+                )
+            };
+            if is_same {
+                // SAFETY: `i` is less than `len`, so the element at `i` is
+                // initialized and valid for dropping. It is never accessed
+                // again.
+                drop(unsafe { Val::new_unchecked(ptr.add(i)) });
+            } else {
+                if kept != i {
+                    // SAFETY: `i` is less than `len`, so the element at `i` is
+                    // initialized and valid for dropping. It is moved out of
+                    // and never accessed again.
+                    let value = unsafe { Val::new_unchecked(ptr.add(i)) };
+                    // SAFETY: `kept` is less than `i`, which is less than
+                    // `len`, so it is less than `capacity`. The element at
+                    // `kept` was dropped or moved out of, so it is vacant and
+                    // does not alias `value`.
+                    let out = unsafe { Slot::new_unchecked(ptr.add(kept)) };
+                    // SAFETY: The elements of a `RelVec` are allocated in
+                    // `alloc`, and since `A` implements `RawRegionalAllocator`
+                    // it guarantees that the memory it allocates is located in
+                    // its region.
+                    let (value, out) = unsafe {
+                        (In::new_unchecked(value), In::new_unchecked(out))
+                    };
+                    T::r#move(value, out);
+                }
+                kept += 1;
+            }
         }
 
-        // The `size_hint` from `values` isn't required to be accurate, so we
-        // have to slowly push any remaining values.
-        for value in values {
-            Self::push(this.as_mut(), value);
+        // SAFETY: `kept` is at most `len`, which is less than or equal to
+        // `capacity`, and the elements in `0..kept` are initialized.
+        unsafe {
+            Self::set_len(this, kept);
         }
     }
 
@@ -373,6 +712,122 @@ impl<T, A: RawRegionalAllocator, B: Basis> RelVec<T, A, B> {
     }
 }
 
+/// Sets the length of a `RelVec` when dropped.
+///
+/// `len` must only count elements which have been initialized, and must be
+/// less than or equal to the capacity of `vec`.
+struct SetLenOnDrop<'a, T, A: RawRegionalAllocator, B: Basis> {
+    vec: Mut<'a, RelVec<T, A, B>>,
+    len: usize,
+}
+
+impl<T, A: RawRegionalAllocator, B: Basis> Drop for SetLenOnDrop<'_, T, A, B> {
+    fn drop(&mut self) {
+        // SAFETY: `len` is always less than or equal to the capacity of `vec`,
+        // and only counts elements which have been initialized.
+        unsafe {
+            RelVec::set_len(self.vec.as_mut(), self.len);
+        }
+    }
+}
+
+/// An iterator over `Ref`s of the elements of a `RelVec`.
+///
+/// This is created by [`RelVec::iter`].
+pub struct Iter<'a, T> {
+    slice: Ref<'a, [T]>,
+    start: usize,
+    end: usize,
+}
+
+impl<T> Clone for Iter<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            slice: self.slice,
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = Ref<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        // SAFETY: `start` is always less than `end`, which is at most the
+        // length of `slice`.
+        let element =
+            unsafe { IndexRaw::index_raw_unchecked(self.slice, self.start) };
+        self.start += 1;
+        Some(element)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: `end` was greater than `start` before it was decremented, so
+        // it is now less than the length of `slice`.
+        Some(unsafe { IndexRaw::index_raw_unchecked(self.slice, self.end) })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over `Mut`s of the elements of a `RelVec`.
+///
+/// This is created by [`RelVec::iter_mut`].
+pub struct IterMut<'a, T> {
+    rest: Option<Mut<'a, [T]>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = Mut<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.rest.take()?.split_first()?;
+        self.rest = Some(rest);
+        Some(first)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.as_ref().map_or(0, |rest| rest.len());
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = self.rest.take()?.split_last()?;
+        self.rest = Some(rest);
+        Some(last)
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
 impl<T, A: RawRegionalAllocator, B: Basis> DerefRaw for RelVec<T, A, B> {
     type Target = [T];
 
@@ -474,6 +929,209 @@ where
     }
 }
 
+// Like slices, `RelVec`s hash their length followed by each of their elements.
+impl<T, A, B> HashRaw for RelVec<T, A, B>
+where
+    T: HashRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn hash_raw<H: Hasher>(this: Ref<'_, Self>, state: &mut H) {
+        this.len().hash(state);
+        for i in 0..this.len() {
+            HashRaw::hash_raw(IndexRaw::index_raw(this, i), state);
+        }
+    }
+}
+
+impl<T, A, B> PartialEqRaw for RelVec<T, A, B>
+where
+    T: PartialEqRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn eq_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> bool {
+        let other_elements =
+            (0..other.len()).map(|i| IndexRaw::index_raw(other, i));
+        (0..this.len())
+            .map(|i| IndexRaw::index_raw(this, i))
+            .eq(other_elements)
+    }
+}
+
+impl<T, A, B> DiffRaw for RelVec<T, A, B>
+where
+    T: DiffRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        path: &mut P,
+    ) -> bool {
+        let len = this.len().min(other.len());
+        for i in 0..len {
+            let this_element = IndexRaw::index_raw(this, i);
+            let other_element = IndexRaw::index_raw(other, i);
+            if DiffRaw::diff_raw(this_element, other_element, path) {
+                path.push_segment(PathSegment::Index(i));
+                return true;
+            }
+        }
+        // If one is longer, then the first element past the end of the other
+        // is the first difference.
+        if this.len() != other.len() {
+            path.push_segment(PathSegment::Index(len));
+            return true;
+        }
+        false
+    }
+}
+
+impl<T, A, B> EqRaw for RelVec<T, A, B>
+where
+    T: EqRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+}
+
+impl<T, A, B> PartialOrdRaw for RelVec<T, A, B>
+where
+    T: PartialOrdRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn partial_cmp_raw(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+    ) -> Option<Ordering> {
+        let other_elements =
+            (0..other.len()).map(|i| IndexRaw::index_raw(other, i));
+        (0..this.len())
+            .map(|i| IndexRaw::index_raw(this, i))
+            .partial_cmp(other_elements)
+    }
+}
+
+impl<T, A, B> OrdRaw for RelVec<T, A, B>
+where
+    T: OrdRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn cmp_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> Ordering {
+        let other_elements =
+            (0..other.len()).map(|i| IndexRaw::index_raw(other, i));
+        (0..this.len())
+            .map(|i| IndexRaw::index_raw(this, i))
+            .cmp(other_elements)
+    }
+}
+
+// SAFETY: `clone_raw` initializes its `out` parameter by emplacing a `RelVec`
+// with enough capacity and then cloning each element into it.
+unsafe impl<T, A, B, R> CloneRaw<R> for RelVec<T, A, B>
+where
+    T: CloneRaw<R> + DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: Clone + RelAllocator<A>,
+{
+    fn clone_raw(
+        this: Ref<'_, Self>,
+        alloc: R,
+        out: In<Slot<'_, Self>, R::Region>,
+    ) {
+        let len = this.len();
+        let mut vec =
+            In::into_inner(WithCapacity(alloc.clone(), len).emplace_mut(out));
+        for i in 0..len {
+            // SAFETY: `i` is less than `len`, which is the capacity of `vec`.
+            let slot = unsafe { Self::slot(vec.as_mut(), i) };
+            T::clone_raw(IndexRaw::index_raw(this, i), alloc.clone(), slot);
+            // SAFETY: `i + 1` is less than or equal to `len`, which is the
+            // capacity of `vec`. We just initialized the element at `i` by
+            // cloning to it.
+            unsafe {
+                Self::set_len(vec.as_mut(), i + 1);
+            }
+        }
+    }
+}
+
+// SAFETY: `validate` checks that the relative pointer is not null and that the
+// length is at most the capacity. Then it claims the whole buffer, validates
+// the initialized elements, and validates the allocator.
+unsafe impl<T, A, B> Validate for RelVec<T, A, B>
+where
+    T: Validate,
+    A: RawRegionalAllocator + Validate,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads. Every bit pattern is a valid `RelPtr`
+        // and `B::Usize`, so these fields are initialized.
+        let (rel_ptr, len, cap) = unsafe {
+            (
+                Ref::new_unchecked(ptr::addr_of!((*ptr).ptr)),
+                ptr::addr_of!((*ptr).len).read(),
+                ptr::addr_of!((*ptr).cap).read(),
+            )
+        };
+        let len = B::to_native_usize(len)
+            .map_err(|_| ValidateError::LayoutOverflow)?;
+        let cap = B::to_native_usize(cap)
+            .map_err(|_| ValidateError::LayoutOverflow)?;
+        let target = RelPtr::as_ptr_wrapping(rel_ptr)
+            .ok_or(ValidateError::NullPointer)?;
+        validate_slice(target, len, cap, validator)?;
+
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `alloc` field is as well.
+        unsafe { A::validate(ptr::addr_of!((*ptr).alloc), validator) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, A, B> ToNative for RelVec<T, A, B>
+where
+    T: ToNative,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    type Native = Vec<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        let slice = Self::as_slice(this);
+        (0..slice.len())
+            .map(|i| T::to_native(IndexRaw::index_raw(slice, i)))
+            .collect()
+    }
+}
+
+// `RelVec`s serialize like the slices they contain.
+#[cfg(feature = "serde")]
+impl<T, A, B> SerializeRaw for RelVec<T, A, B>
+where
+    T: SerializeRaw,
+    A: RawRegionalAllocator,
+    B: Basis,
+{
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(Self::iter(this))
+    }
+}
+
 /// An emplacer for a new, empty `RelVec`.
 pub struct New<R>(pub R);
 
@@ -499,7 +1157,36 @@ where
     }
 }
 
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter whenever it
+//   returns `Ok`.
+unsafe impl<T, A, B, R> TryEmplace<RelVec<T, A, B>, R::Region> for New<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelVec<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) -> Result<(), TryReserveError> {
+        WithCapacity(self.0, 0).try_emplace(out)
+    }
+}
+
 /// An emplacer for a new `RelVec` with an initial capacity.
+///
+/// The storage of the new `RelVec` is left uninitialized. This is always safe
+/// to drop: the `RelVec` starts with a length of zero, and methods like `push`
+/// and `extend` only count an element in the length after it has been
+/// emplaced. So if emplacing an element panics, dropping the `RelVec` only
+/// drops the elements which were initialized before it.
 pub struct WithCapacity<R>(pub R, pub usize);
 
 // SAFETY:
@@ -525,28 +1212,292 @@ where
         out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
     ) {
         let Self(alloc, cap) = self;
+        emplace_with_capacity(alloc, cap, false, out);
+    }
+}
 
-        let ptr = alloc
-            .allocate(Layout::array::<T>(cap).unwrap())
-            .unwrap()
-            .cast()
-            .as_ptr();
-        // SAFETY: The pointer returned from `allocate` is guaranteed to be in
-        // the region of `R`.
-        let ptr = unsafe { In::new_unchecked(ptr) };
-
-        munge!(
-            let RelVec {
-                ptr: out_ptr,
-                len: out_len,
-                cap: out_cap,
-                alloc: out_alloc,
-            } = out;
-        );
-
-        ptr.emplace(out_ptr);
-        In::into_inner(out_len).write(B::from_native_usize(0).unwrap());
-        In::into_inner(out_cap).write(B::from_native_usize(cap).unwrap());
-        alloc.emplace(out_alloc);
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter by
+//   emplacing and writing to each field whenever it returns `Ok`.
+unsafe impl<T, A, B, R> TryEmplace<RelVec<T, A, B>, R::Region>
+    for WithCapacity<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelVec<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) -> Result<(), TryReserveError> {
+        let Self(alloc, cap) = self;
+        try_emplace_with_capacity(alloc, cap, false, out)
     }
 }
+
+/// An emplacer for a new `RelVec` with an initial capacity and zeroed storage.
+///
+/// The `RelVec` is still empty, but all of the bytes of its initial capacity
+/// are zeroed instead of left uninitialized. This can make buffers easier to
+/// inspect while debugging. Any capacity added later by growing the `RelVec` is
+/// not zeroed.
+pub struct WithCapacityZeroed<R>(pub R, pub usize);
+
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   and writing to each field.
+unsafe impl<T, A, B, R> Emplace<RelVec<T, A, B>, R::Region>
+    for WithCapacityZeroed<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(
+        &self,
+    ) -> <RelVec<T, A, B> as ptr_meta::Pointee>::Metadata {
+    }
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) {
+        let Self(alloc, cap) = self;
+        emplace_with_capacity(alloc, cap, true, out);
+    }
+}
+
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter by
+//   emplacing and writing to each field whenever it returns `Ok`.
+unsafe impl<T, A, B, R> TryEmplace<RelVec<T, A, B>, R::Region>
+    for WithCapacityZeroed<R>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    fn emplaced_meta(&self) -> <RelVec<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) -> Result<(), TryReserveError> {
+        let Self(alloc, cap) = self;
+        try_emplace_with_capacity(alloc, cap, true, out)
+    }
+}
+
+/// An emplacer for a new `RelVec` that emplaces each element of an array.
+///
+/// This is usually constructed with the [`rel_vec!`](crate::rel_vec) macro.
+pub struct FromArray<R, E, const N: usize>(pub R, pub [E; N]);
+
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   an empty `RelVec` to it.
+unsafe impl<T, A, B, R, E, const N: usize> Emplace<RelVec<T, A, B>, R::Region>
+    for FromArray<R, E, N>
+where
+    T: DropRaw + Move<R::Region>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+    E: Emplace<T, R::Region>,
+{
+    fn emplaced_meta(
+        &self,
+    ) -> <RelVec<T, A, B> as ptr_meta::Pointee>::Metadata {
+    }
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) {
+        let Self(alloc, elements) = self;
+        let vec = WithCapacity(alloc, N).emplace_mut(out);
+        RelVec::extend(In::into_inner(vec), elements.into_iter());
+    }
+}
+
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `try_emplace_unsized_unchecked` initializes its `out` parameter by
+//   emplacing an empty `RelVec` to it whenever it returns `Ok`.
+unsafe impl<T, A, B, R, E, const N: usize>
+    TryEmplace<RelVec<T, A, B>, R::Region> for FromArray<R, E, N>
+where
+    T: DropRaw + Move<R::Region>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+    E: Emplace<T, R::Region>,
+{
+    fn emplaced_meta(&self) -> <RelVec<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn try_emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) -> Result<(), TryReserveError> {
+        let Self(alloc, elements) = self;
+        let out_ptr = out.ptr().as_ptr();
+        WithCapacity(alloc, N).try_emplace(out)?;
+        // SAFETY:
+        // - `out_ptr` is the pointer of `out`, so it is non-null, properly
+        //   aligned, and valid for reads and writes.
+        // - `try_emplace` returned `Ok`, so `out` was initialized in place and
+        //   is immovable.
+        // - `out` was consumed by `try_emplace`, so the returned `Mut` does not
+        //   alias any other accessible references.
+        let vec = unsafe { Mut::new_unchecked(out_ptr) };
+        // The `RelVec` was emplaced with a capacity of `N`, so extending it
+        // with `N` elements never reallocates.
+        RelVec::extend(vec, elements.into_iter());
+        Ok(())
+    }
+}
+
+/// An emplacer for a new `RelVec` that emplaces each element of an iterator.
+///
+/// The `RelVec` is created with enough capacity for the lower bound of the
+/// iterator's `size_hint`, so iterators which know their exact size (like
+/// `ExactSizeIterator`s) never reallocate. Other iterators are still fully
+/// consumed, and the `RelVec` grows as needed to hold all of their elements.
+pub struct FromIter<R, I>(pub R, pub I);
+
+// SAFETY:
+// - `RelVec` is `Sized` and always has metadata `()`, so `emplaced_meta` always
+//   returns valid metadata for it.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   an empty `RelVec` to it.
+unsafe impl<T, A, B, R, I> Emplace<RelVec<T, A, B>, R::Region>
+    for FromIter<R, I>
+where
+    T: DropRaw + Move<R::Region>,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+    I: Iterator,
+    I::Item: Emplace<T, R::Region>,
+{
+    fn emplaced_meta(&self) -> <RelVec<T, A, B> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+    ) {
+        let Self(alloc, elements) = self;
+        let vec = WithCapacity(alloc, elements.size_hint().0).emplace_mut(out);
+        RelVec::extend(In::into_inner(vec), elements);
+    }
+}
+
+/// Creates an emplacer for a `RelVec` containing the given elements.
+///
+/// The allocator is given first, followed by the emplacers for each element:
+/// `rel_vec![in alloc; a, b, c]`. The returned emplacer can be used with
+/// `emplace_in` or any other emplacement method.
+///
+/// Each element is emplaced as it is given, and the allocator is not passed on
+/// to them. So elements which need an allocator (like `RelString`s) must be
+/// given one as well. For example, `rel_vec![in alloc; "a", "b"]` can't be
+/// emplaced as a `RelVec<RelString<_>>` because a `&str` has no allocator to
+/// copy its bytes into. Wrap each string with
+/// [`rel_string!`](crate::rel_string) instead, as in
+/// `rel_vec![in alloc; rel_string!(in alloc; "a"), ...]`.
+#[macro_export]
+macro_rules! rel_vec {
+    (in $alloc:expr $(;)?) => {
+        $crate::vec::New($alloc)
+    };
+    (in $alloc:expr; $($element:expr),+ $(,)?) => {
+        $crate::vec::FromArray($alloc, [$($element),+])
+    };
+}
+
+fn emplace_with_capacity<T, A, B, R>(
+    alloc: R,
+    cap: usize,
+    zeroed: bool,
+    out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+) where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    if let Err(e) = try_emplace_with_capacity(alloc, cap, zeroed, out) {
+        panic!("{e}");
+    }
+}
+
+fn try_emplace_with_capacity<T, A, B, R>(
+    alloc: R,
+    cap: usize,
+    zeroed: bool,
+    out: In<Slot<'_, RelVec<T, A, B>>, R::Region>,
+) -> Result<(), TryReserveError>
+where
+    T: DropRaw,
+    A: DropRaw + RawRegionalAllocator<Region = R::Region>,
+    B: Basis,
+    <B as Basis>::Usize: DropRaw,
+    R: RelAllocator<A>,
+{
+    // Everything that can fail is computed before allocating so that an error
+    // can't leak the allocation.
+    let layout = RelVec::<T, A, B>::try_layout_for(cap)?;
+    let len = B::from_native_usize(0).unwrap();
+    let cap = B::from_native_usize(cap)
+        .map_err(|_| TryReserveError::CapacityOverflow)?;
+
+    let allocation = if zeroed {
+        alloc.allocate_zeroed(layout)
+    } else {
+        alloc.allocate(layout)
+    };
+    let ptr = allocation
+        .map_err(|_| TryReserveError::AllocError { layout })?
+        .cast()
+        .as_ptr();
+    // SAFETY: The pointer returned from `allocate` and `allocate_zeroed` is
+    // guaranteed to be in the region of `R`.
+    let ptr = unsafe { In::new_unchecked(ptr) };
+
+    munge!(
+        let RelVec {
+            ptr: out_ptr,
+            len: out_len,
+            cap: out_cap,
+            alloc: out_alloc,
+        } = out;
+    );
+
+    ptr.emplace(out_ptr);
+    In::into_inner(out_len).write(len);
+    In::into_inner(out_cap).write(cap);
+    alloc.emplace(out_alloc);
+
+    Ok(())
+}
@@ -123,29 +123,40 @@ impl<B: Basis> BasisPointee<B> for str {
     }
 }
 
+#[cfg(feature = "basis_8")]
+macro_rules! choose_basis {
+    ($b8:ty, $b16:ty, $b32:ty, $b64:ty) => {
+        $b8
+    };
+}
+
 #[cfg(feature = "basis_16")]
 macro_rules! choose_basis {
-    ($b16:ty, $b32:ty, $b64:ty) => {
+    ($b8:ty, $b16:ty, $b32:ty, $b64:ty) => {
         $b16
     };
 }
 
 #[cfg(feature = "basis_32")]
 macro_rules! choose_basis {
-    ($b16:ty, $b32:ty, $b64:ty) => {
+    ($b8:ty, $b16:ty, $b32:ty, $b64:ty) => {
         $b32
     };
 }
 
 #[cfg(feature = "basis_64")]
 macro_rules! choose_basis {
-    ($b16:ty, $b32:ty, $b64:ty) => {
+    ($b8:ty, $b16:ty, $b32:ty, $b64:ty) => {
         $b64
     };
 }
 
 #[cfg(any(
-    all(target_pointer_width = "32", feature = "basis_16"),
+    all(target_pointer_width = "16", feature = "basis_8"),
+    all(
+        target_pointer_width = "32",
+        any(feature = "basis_8", feature = "basis_16"),
+    ),
     all(target_pointer_width = "64", not(feature = "basis_64")),
 ))]
 macro_rules! compare_basis_to_pointer_width {
@@ -176,7 +187,10 @@ macro_rules! compare_basis_to_pointer_width {
 }
 
 #[cfg(any(
-    all(target_pointer_width = "16", not(feature = "basis_16")),
+    all(
+        target_pointer_width = "16",
+        any(feature = "basis_32", feature = "basis_64"),
+    ),
     all(target_pointer_width = "32", feature = "basis_64"),
 ))]
 macro_rules! compare_basis_to_pointer_width {
@@ -196,13 +210,17 @@ macro_rules! compare_basis_to_pointer_width {
 /// flags.
 pub struct DefaultBasis;
 
+// `I8` and `U8` are plain integers, so their conversions are no-ops.
+#[cfg_attr(feature = "basis_8", allow(clippy::useless_conversion))]
 impl Basis for DefaultBasis {
     type Isize = choose_basis!(
+        crate::primitive::I8,
         crate::primitive::I16,
         crate::primitive::I32,
         crate::primitive::I64
     );
     type Usize = choose_basis!(
+        crate::primitive::U8,
         crate::primitive::U16,
         crate::primitive::U32,
         crate::primitive::U64
@@ -216,14 +234,18 @@ impl Basis for DefaultBasis {
             fn from_native_isize(
                 value: isize,
             ) -> Result<Self::Isize, Self::FromNativeError> {
-                Ok(Self::Isize::from_ne(value.try_into()?))
+                let value: choose_basis!(i8, i16, i32, i64) =
+                    value.try_into()?;
+                Ok(value.into())
             }
 
             #[inline]
             fn from_native_usize(
                 value: usize,
             ) -> Result<Self::Usize, Self::FromNativeError> {
-                Ok(Self::Usize::from_ne(value.try_into()?))
+                let value: choose_basis!(u8, u16, u32, u64) =
+                    value.try_into()?;
+                Ok(value.into())
             }
         }
         le {
@@ -233,18 +255,20 @@ impl Basis for DefaultBasis {
             fn to_native_isize(
                 value: Self::Isize,
             ) -> Result<isize, Self::ToNativeError> {
+                let value: choose_basis!(i8, i16, i32, i64) = value.into();
                 // TODO const_num_from_num: Use `isize::TryFrom` when const.
                 #[allow(clippy::as_conversions)]
-                Ok(value.to_ne() as isize)
+                Ok(value as isize)
             }
 
             #[inline]
             fn to_native_usize(
                 value: Self::Usize,
             ) -> Result<usize, Self::ToNativeError> {
+                let value: choose_basis!(u8, u16, u32, u64) = value.into();
                 // TODO const_num_from_num: Use `usize::TryFrom` when const.
                 #[allow(clippy::as_conversions)]
-                Ok(value.to_ne() as usize)
+                Ok(value as usize)
             }
         }
         ge {
@@ -287,3 +311,25 @@ impl Basis for DefaultBasis {
         }
     }
 }
+
+#[cfg(all(test, feature = "basis_8"))]
+mod tests {
+    use crate::{Basis, DefaultBasis};
+
+    #[test]
+    fn basis_8_round_trip() {
+        for value in [0, 1, 0x7f, 0xff] {
+            let based = DefaultBasis::from_native_usize(value).unwrap();
+            assert_eq!(DefaultBasis::to_native_usize(based).unwrap(), value);
+        }
+        for value in [i8::MIN.into(), -1, 0, 1, i8::MAX.into()] {
+            let based = DefaultBasis::from_native_isize(value).unwrap();
+            assert_eq!(DefaultBasis::to_native_isize(based).unwrap(), value);
+        }
+
+        assert!(DefaultBasis::from_native_usize(0x100).is_err());
+        assert!(DefaultBasis::from_native_usize(usize::MAX).is_err());
+        assert!(DefaultBasis::from_native_isize(0x80).is_err());
+        assert!(DefaultBasis::from_native_isize(-0x81).is_err());
+    }
+}
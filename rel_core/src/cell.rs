@@ -0,0 +1,416 @@
+//! Shareable mutable containers.
+
+use ::core::{
+    cell::{Cell, UnsafeCell},
+    fmt,
+    mem::{forget, MaybeUninit},
+    ptr::addr_of,
+};
+use ::mischief::{In, Region, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+use ::situ::{fmt::DebugRaw, DropRaw, Mut, Ref, Val};
+
+use crate::{
+    Emplace,
+    EmplaceExt,
+    Move,
+    MoveExt,
+    Portable,
+    Validate,
+    ValidateError,
+    Validator,
+};
+
+/// A relative counterpart to `Cell`.
+///
+/// `RelCell` allows the value it contains to be mutated through a shared
+/// reference, such as a `Ref`. Only `Copy` values can be read out of and
+/// written into a `RelCell`, so relative values which depend on their location
+/// can never be moved by it.
+///
+/// Like `Cell`, `RelCell` is not `Sync`. Values in an arena are accessed
+/// through an allocator which is tied to a `Unique` token, and the token
+/// ensures that the arena is only used from one thread at a time. `RelCell`
+/// relies on this to mutate its contents without synchronization.
+#[derive(Portable, Validate)]
+#[rel_core = "crate"]
+#[repr(transparent)]
+pub struct RelCell<T> {
+    inner: Cell<T>,
+}
+
+impl<T: Copy> RelCell<T> {
+    /// Returns a copy of the contained value.
+    #[inline]
+    pub fn get(&self) -> T {
+        self.inner.get()
+    }
+
+    /// Sets the contained value.
+    #[inline]
+    pub fn set(&self, value: T) {
+        self.inner.set(value);
+    }
+
+    /// Replaces the contained value with `value`, and returns the old
+    /// contained value.
+    #[inline]
+    pub fn replace(&self, value: T) -> T {
+        self.inner.replace(value)
+    }
+}
+
+impl<T: Copy> DropRaw for RelCell<T> {
+    #[inline]
+    unsafe fn drop_raw(_: Mut<'_, Self>) {}
+}
+
+// SAFETY:
+// - `move_unsized_unchecked` initializes its `out` parameter by writing a copy
+//   of the value to it.
+// - The value is `Copy`, so moving a `RelCell` is equivalent to copying its
+//   bytes.
+unsafe impl<T: Copy, R: Region> Move<R> for RelCell<T> {
+    const BYTEWISE: bool = true;
+
+    unsafe fn move_unsized_unchecked(
+        this: In<Val<'_, Self>, R>,
+        out: In<Slot<'_, Self>, R>,
+    ) {
+        let value = In::into_inner(this).get();
+        In::into_inner(out).write(RelCell {
+            inner: Cell::new(value),
+        });
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   its contained value to it.
+unsafe impl<T, E, R> Emplace<RelCell<T>, R> for Cell<E>
+where
+    T: Copy + DropRaw,
+    E: Emplace<T, R>,
+    R: Region,
+{
+    fn emplaced_meta(&self) -> <RelCell<T> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelCell<T>>, R>,
+    ) {
+        // SAFETY:
+        // - `RelCell<T>` is `repr(transparent)` over a `Cell<T>`, which has the
+        //   same in-memory representation as `T`. So `out` is suitable for
+        //   holding a `T`.
+        // - The cast slot points to the same memory as `out`, so it is also
+        //   completely contained in `R`.
+        let out = unsafe { In::map_unchecked(out, |s| s.cast::<T>()) };
+        self.into_inner().emplace(out);
+    }
+}
+
+impl<T: Copy + fmt::Debug> fmt::Debug for RelCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelCell")
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
+const EMPTY: u8 = 0;
+const INITIALIZING: u8 = 1;
+const INITIALIZED: u8 = 2;
+
+/// A relative counterpart to `OnceCell`.
+///
+/// `RelOnceCell` starts out empty and can be set exactly once through a shared
+/// reference. After it is set, its value can be borrowed but never replaced.
+/// This makes it suitable for memoizing derived values inside of an arena.
+///
+/// Like [`RelCell`], `RelOnceCell` is not `Sync` and relies on the `Unique`
+/// token of the arena's allocator to ensure single-threaded access.
+#[repr(C)]
+pub struct RelOnceCell<T> {
+    state: Cell<u8>,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: `RelOnceCell` is `repr(C)` and contains a `u8` followed by a `T`.
+// `Cell`, `UnsafeCell`, and `MaybeUninit` all have the same in-memory
+// representation as their contained types, so `RelOnceCell<T>` is `Portable`
+// if `T` is `Portable`.
+unsafe impl<T: Portable> Portable for RelOnceCell<T> {}
+
+struct ResetOnDrop<'a> {
+    state: &'a Cell<u8>,
+}
+
+impl Drop for ResetOnDrop<'_> {
+    fn drop(&mut self) {
+        self.state.set(EMPTY);
+    }
+}
+
+impl<T> RelOnceCell<T> {
+    fn value_ptr(this: Ref<'_, Self>) -> *mut T {
+        let ptr = this.as_ptr();
+        // SAFETY: `ptr` points to a valid `RelOnceCell`, so the pointer to its
+        // `value` field is in bounds.
+        let value = unsafe { addr_of!((*ptr).value) };
+        UnsafeCell::raw_get(value).cast()
+    }
+
+    /// Returns a `Ref` to the contained value, or `None` if the cell is
+    /// empty.
+    pub fn get(this: Ref<'_, Self>) -> Option<Ref<'_, T>> {
+        if this.state.get() == INITIALIZED {
+            // SAFETY: The cell is initialized, so its value is initialized.
+            // Initialized cells are never written to through shared
+            // references, so the value will not be mutated while the returned
+            // `Ref` is alive.
+            Some(unsafe { Ref::new_unchecked(Self::value_ptr(this)) })
+        } else {
+            None
+        }
+    }
+
+    /// Sets the contents of the cell by emplacing `value` into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(value)` if the cell is already initialized or is currently
+    /// being initialized.
+    pub fn set<E, R>(this: In<Ref<'_, Self>, R>, value: E) -> Result<(), E>
+    where
+        T: DropRaw,
+        E: Emplace<T, R>,
+        R: Region,
+    {
+        let this = In::into_inner(this);
+        if this.state.get() != EMPTY {
+            return Err(value);
+        }
+
+        // Marking the cell as initializing prevents any reentrant calls from
+        // accessing the value while it is being emplaced. If emplacing panics,
+        // the guard returns the cell to being empty.
+        this.state.set(INITIALIZING);
+        let guard = ResetOnDrop { state: &this.state };
+
+        // SAFETY: `value_ptr` returns a pointer to the value of the cell, which
+        // is properly aligned and valid for writes. The cell is initializing,
+        // so no other references to the value can exist.
+        let slot = unsafe { Slot::new_unchecked(Self::value_ptr(this)) };
+        // SAFETY: The value is located inside of `this`, which is located in
+        // `R`.
+        let slot = unsafe { In::new_unchecked(slot) };
+        value.emplace(slot);
+
+        forget(guard);
+        this.state.set(INITIALIZED);
+
+        Ok(())
+    }
+
+    /// Returns a `Ref` to the contained value, initializing it by emplacing
+    /// the result of `f` if the cell is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell is initialized reentrantly by `f` or while emplacing
+    /// its result.
+    pub fn get_or_init<'a, F, E, R>(
+        this: In<Ref<'a, Self>, R>,
+        f: F,
+    ) -> Ref<'a, T>
+    where
+        T: DropRaw,
+        F: FnOnce() -> E,
+        E: Emplace<T, R>,
+        R: Region,
+    {
+        let cell = *this.ptr();
+        if let Some(value) = Self::get(cell) {
+            return value;
+        }
+        assert!(cell.state.get() == EMPTY, "reentrant init");
+
+        if Self::set(this, f()).is_err() {
+            panic!("reentrant init");
+        }
+        match Self::get(cell) {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T: DropRaw> DropRaw for RelOnceCell<T> {
+    #[inline]
+    unsafe fn drop_raw(this: Mut<'_, Self>) {
+        if this.state.get() == INITIALIZED {
+            let value = Self::value_ptr(this.as_ref());
+            // SAFETY:
+            // - The cell is initialized, so its value is initialized.
+            // - The caller has guaranteed that the cell is valid for dropping
+            //   and will never be accessed again, so the same is true of its
+            //   value.
+            unsafe {
+                T::drop_raw(Mut::new_unchecked(value));
+            }
+        }
+    }
+}
+
+// SAFETY: `move_unsized_unchecked` initializes its `out` parameter by writing
+// its state and moving its value if it is initialized.
+unsafe impl<T: Move<R>, R: Region> Move<R> for RelOnceCell<T> {
+    unsafe fn move_unsized_unchecked(
+        this: In<Val<'_, Self>, R>,
+        out: In<Slot<'_, Self>, R>,
+    ) {
+        let this = Val::leak(In::into_inner(this));
+        munge!(let RelOnceCell { state: out_state, value: out_value } = out);
+
+        if this.state.get() == INITIALIZED {
+            let value = Self::value_ptr(this.as_ref());
+            // SAFETY:
+            // - The cell is initialized, so its value is initialized.
+            // - We own `this`, and it is leaked so its value is only taken
+            //   once.
+            let value = unsafe { Mut::take(Mut::new_unchecked(value)) };
+            // SAFETY: `value` is located in `this`, which is located in `R`.
+            let value = unsafe { In::new_unchecked(value) };
+            // SAFETY: `UnsafeCell<MaybeUninit<T>>` has the same in-memory
+            // representation as `T`. The cast slot points to the same memory
+            // as `out_value`, so it is also located in `R`.
+            let out_value =
+                unsafe { In::map_unchecked(out_value, |s| s.cast::<T>()) };
+            T::r#move(value, out_value);
+            In::into_inner(out_state).write(Cell::new(INITIALIZED));
+        } else {
+            In::into_inner(out_state).write(Cell::new(EMPTY));
+        }
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by writing its
+//   state and additionally emplacing a value for `Some`.
+unsafe impl<T: DropRaw, E, R: Region> Emplace<RelOnceCell<T>, R> for Option<E>
+where
+    E: Emplace<T, R>,
+{
+    fn emplaced_meta(&self) -> <RelOnceCell<T> as Pointee>::Metadata {}
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelOnceCell<T>>, R>,
+    ) {
+        munge!(let RelOnceCell { state, value } = out);
+
+        match self {
+            None => {
+                In::into_inner(state).write(Cell::new(EMPTY));
+            }
+            Some(emplacer) => {
+                // SAFETY: `UnsafeCell<MaybeUninit<T>>` has the same in-memory
+                // representation as `T`. The cast slot points to the same
+                // memory as `value`, so it is also located in `R`.
+                let value =
+                    unsafe { In::map_unchecked(value, |s| s.cast::<T>()) };
+                emplacer.emplace(value);
+                In::into_inner(state).write(Cell::new(INITIALIZED));
+            }
+        }
+    }
+}
+
+// SAFETY: `validate` only returns `Ok` if the cell is empty, or if it is
+// initialized and its value is valid.
+unsafe impl<T: Validate> Validate for RelOnceCell<T> {
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is properly aligned and
+        // valid for reads, and every bit pattern is a valid `u8`.
+        let state = unsafe { addr_of!((*ptr).state).cast::<u8>().read() };
+        match state {
+            EMPTY => Ok(()),
+            INITIALIZED => {
+                // SAFETY: The caller has guaranteed that `ptr` is valid for
+                // reads of the whole cell, so its value field is as well.
+                let value = unsafe { addr_of!((*ptr).value) };
+                let value = UnsafeCell::raw_get(value).cast::<T>();
+                // SAFETY: `value` is properly aligned and valid for reads of a
+                // `T`.
+                unsafe { T::validate(value, validator) }
+            }
+            _ => Err(ValidateError::InvalidDiscriminant(state)),
+        }
+    }
+}
+
+impl<T: DebugRaw> DebugRaw for RelOnceCell<T> {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        let mut d = f.debug_tuple("RelOnceCell");
+        match Self::get(this) {
+            Some(value) => d.field(&value),
+            None => d.field(&format_args!("<uninit>")),
+        };
+        d.finish()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::{cell::Cell, mem::MaybeUninit};
+    use ::mischief::In;
+    use ::std::format;
+
+    use crate::{
+        cell::{RelCell, RelOnceCell},
+        tests::{emplace, Local},
+        U32,
+    };
+
+    #[test]
+    fn rel_cell() {
+        let mut out = MaybeUninit::uninit();
+        let counter = emplace::<RelCell<U32>, _>(Cell::new(1u32), &mut out);
+        let shared = &*counter;
+        assert_eq!(shared.get().to_ne(), 1);
+        shared.set(U32::from_ne(2));
+        assert_eq!(shared.replace(U32::from_ne(3)).to_ne(), 2);
+        assert_eq!(counter.get().to_ne(), 3);
+    }
+
+    #[test]
+    fn rel_once_cell() {
+        let mut out = MaybeUninit::uninit();
+        let cell = emplace::<RelOnceCell<U32>, _>(None::<u32>, &mut out);
+        let shared = cell.as_ref();
+        // SAFETY: `cell` was emplaced in `Local`.
+        let cell = unsafe { In::<_, Local>::new_unchecked(shared) };
+
+        assert!(RelOnceCell::get(shared).is_none());
+        assert_eq!(format!("{:?}", shared), "RelOnceCell(<uninit>)");
+
+        let value = RelOnceCell::get_or_init(cell, || 42u32);
+        assert_eq!(value.to_ne(), 42);
+
+        // SAFETY: See above.
+        let cell = unsafe { In::<_, Local>::new_unchecked(shared) };
+        assert_eq!(RelOnceCell::set(cell, 7u32), Err(7));
+        assert_eq!(RelOnceCell::get(shared).unwrap().to_ne(), 42);
+        assert_eq!(format!("{:?}", shared), "RelOnceCell(42)");
+    }
+}
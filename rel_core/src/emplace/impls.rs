@@ -1,4 +1,4 @@
-use ::core::{mem::ManuallyDrop, ptr};
+use ::core::{marker::PhantomData, mem::ManuallyDrop, ptr};
 use ::mischief::{In, Region, Slot};
 use ::ptr_meta::Pointee;
 use ::situ::DropRaw;
@@ -14,8 +14,10 @@ macro_rules! impl_builtin {
             // - `emplace_unsized_unchecked` initializes its `out` parameter by
             //   writing to it.
             unsafe impl<R: Region> Emplace<$ty, R> for $ty {
+                #[inline]
                 fn emplaced_meta(&self) -> <Self as Pointee>::Metadata {}
 
+                #[inline]
                 unsafe fn emplace_unsized_unchecked(
                     self,
                     out: In<Slot<'_, $ty>, R>,
@@ -29,6 +31,25 @@ macro_rules! impl_builtin {
 
 impl_builtin!(i8, u8, bool, ());
 
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by writing to
+//   it.
+unsafe impl<T: ?Sized, R: Region> Emplace<PhantomData<T>, R>
+    for PhantomData<T>
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <PhantomData<T> as Pointee>::Metadata {}
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, PhantomData<T>>, R>,
+    ) {
+        In::into_inner(out).write(self);
+    }
+}
+
 // SAFETY:
 // - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
 // - `emplace_unsized_unchecked` emplaces to every element of the `out` slot,
@@ -38,6 +59,7 @@ where
     E: Emplace<T, R>,
     T: DropRaw,
 {
+    #[inline]
     fn emplaced_meta(&self) -> <Self as Pointee>::Metadata {}
 
     unsafe fn emplace_unsized_unchecked(self, out: In<Slot<'_, [T; N]>, R>) {
@@ -58,3 +80,79 @@ where
         }
     }
 }
+
+// SAFETY:
+// - `emplaced_meta` returns the metadata of the contained emplacer, which is
+//   the emplacer that `emplace_unsized_unchecked` emplaces with.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing
+//   the contained emplacer to it, or panics if there is no contained emplacer.
+unsafe impl<E, T, R> Emplace<T, R> for &mut Option<E>
+where
+    E: Emplace<T, R>,
+    T: DropRaw + Pointee + ?Sized,
+    R: Region,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <T as Pointee>::Metadata {
+        self.as_ref()
+            .expect("attempted to emplace an already-taken emplacer")
+            .emplaced_meta()
+    }
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(self, out: In<Slot<'_, T>, R>) {
+        let emplacer = self
+            .take()
+            .expect("attempted to emplace an already-taken emplacer");
+        // SAFETY: The caller has guaranteed that `out` has the metadata
+        // returned by `emplaced_meta`, which is the metadata of `emplacer`.
+        unsafe {
+            emplacer.emplace_unsized_unchecked(out);
+        }
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns the metadata of the referenced emplacer, which is
+//   copied and used to emplace in `emplace_unsized_unchecked`.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing a
+//   copy of the referenced emplacer to it.
+unsafe impl<E, T, R> Emplace<T, R> for &E
+where
+    E: Copy + Emplace<T, R>,
+    T: DropRaw + Pointee + ?Sized,
+    R: Region,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <T as Pointee>::Metadata {
+        <E as Emplace<T, R>>::emplaced_meta(*self)
+    }
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(self, out: In<Slot<'_, T>, R>) {
+        // SAFETY: The caller has guaranteed that `out` has the metadata
+        // returned by `emplaced_meta`, which is the metadata of the referenced
+        // emplacer.
+        unsafe {
+            <E as Emplace<T, R>>::emplace_unsized_unchecked(*self, out);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::MaybeUninit;
+
+    use crate::{tests::emplace, U32};
+
+    #[test]
+    fn emplace_ref() {
+        let value = 42u32;
+        let mut out = MaybeUninit::uninit();
+        let emplaced = emplace::<U32, _>(&value, &mut out);
+        assert_eq!(emplaced.to_ne(), 42);
+        // The referenced emplacer is copied, so it can be emplaced again.
+        let mut out = MaybeUninit::uninit();
+        assert_eq!(emplace::<U32, _>(&value, &mut out).to_ne(), 42);
+    }
+}
@@ -6,6 +6,9 @@ use ::situ::{DropRaw, Mut, Val};
 
 /// A value emplacer.
 ///
+/// Shared references to emplacers which are `Copy` are also emplacers. They
+/// emplace a copy of the referenced emplacer.
+///
 /// # Safety
 ///
 /// - `emplaced_meta` must return valid metadata for the value emplaced with
@@ -98,6 +101,7 @@ where
     T: DropRaw + Pointee + ?Sized,
     R: Region,
 {
+    #[inline]
     fn emplace_unsized(self, out: In<Slot<'_, T>, R>) {
         assert!(self.emplaced_meta() == metadata::<T>(out.ptr().as_ptr()));
         // SAFETY: We have asserted that the metadata of `self` and `out` are
@@ -117,6 +121,7 @@ where
         unsafe { self.emplace_unsized_unchecked(out) }
     }
 
+    #[inline]
     unsafe fn emplace_mut_unsized(
         self,
         out: In<Slot<'_, T>, R>,
@@ -137,6 +142,7 @@ where
         unsafe { self.emplace_mut_unsized(out) }
     }
 
+    #[inline]
     #[must_use]
     unsafe fn emplace_val_unsized(
         self,
@@ -1,4 +1,6 @@
 //! Public re-exports of dependencies.
 
+#[cfg(feature = "bytemuck")]
+pub use ::bytemuck;
 pub use ::mischief;
 pub use ::situ;
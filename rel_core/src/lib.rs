@@ -12,17 +12,29 @@
 )]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod basis;
+pub mod cell;
 mod emplace;
 pub mod export;
 mod r#move;
+#[cfg(feature = "std")]
+pub mod net;
 pub mod option;
 mod portable;
+pub mod prelude;
 mod primitive;
+pub mod range;
 pub mod rel_mem;
 pub mod rel_ptr;
 pub mod rel_ref;
 pub mod rel_tuple;
+pub mod time;
+#[cfg(feature = "alloc")]
+mod to_native;
+mod validate;
 
 pub use self::{
     basis::*,
@@ -32,4 +44,34 @@ pub use self::{
     r#move::*,
     rel_ptr::RelPtr,
     rel_ref::RelRef,
+    validate::*,
 };
+#[cfg(feature = "alloc")]
+pub use self::to_native::*;
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::MaybeUninit;
+    use ::mischief::{In, Region, Slot};
+    use ::situ::{DropRaw, Mut};
+
+    use crate::{Emplace, EmplaceExt};
+
+    /// The region that [`emplace`] locates values in.
+    pub struct Local;
+
+    // SAFETY: `Local` is only used to locate values emplaced on the stack in
+    // tests, and relative pointers are never followed between them.
+    unsafe impl Region for Local {}
+
+    /// Emplaces `value` into `out` and returns a `Mut` of the emplaced value.
+    pub fn emplace<T, E>(value: E, out: &mut MaybeUninit<T>) -> Mut<'_, T>
+    where
+        T: DropRaw,
+        E: Emplace<T, Local>,
+    {
+        // SAFETY: Every value emplaced by tests is located in `Local`.
+        let out = unsafe { In::<_, Local>::new_unchecked(Slot::new(out)) };
+        In::into_inner(value.emplace_mut(out))
+    }
+}
@@ -1,15 +1,20 @@
-use ::core::marker::PhantomData;
+use ::core::{marker::PhantomData, ptr};
 use ::mischief::{In, Region, Slot};
-use ::situ::{ops::IndexMutRaw, Mut, Val};
+use ::situ::{ops::IndexMutRaw, DropRaw, Mut, Val};
 
 use crate::{Move, MoveExt};
 
 macro_rules! impl_builtin {
     ($($ty:ty),*) => {
         $(
-            // SAFETY: `move_unsized_unchecked` initializes `out` by emplacing
-            // to it.
+            // SAFETY:
+            // - `move_unsized_unchecked` initializes `out` by emplacing to it.
+            // - `$ty` does not contain any relative pointers, so moving it is
+            //   equivalent to copying its bytes.
             unsafe impl<R: Region> Move<R> for $ty {
+                const BYTEWISE: bool = true;
+
+                #[inline]
                 unsafe fn move_unsized_unchecked(
                     this: In<Val<'_, Self>, R>,
                     out: In<Slot<'_, Self>, R>,
@@ -30,16 +35,30 @@ macro_rules! impl_builtin {
 
 impl_builtin!(i8, u8, bool, ());
 
-// SAFETY: `move_unsized_unchecked` initializes its `out` parameter by emplacing
-// to every element in it.
+// SAFETY:
+// - `move_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   every element in it, or by copying all of the elements at once if they can
+//   be moved bytewise.
+// - An array can be moved bytewise if its elements can be moved bytewise.
 unsafe impl<T, R: Region, const N: usize> Move<R> for [T; N]
 where
     T: Move<R>,
 {
+    const BYTEWISE: bool = T::BYTEWISE;
+
     unsafe fn move_unsized_unchecked(
         this: In<Val<'_, Self>, R>,
         out: In<Slot<'_, Self>, R>,
     ) {
+        if T::BYTEWISE {
+            // SAFETY: `T` can be moved bytewise, so copying all `N` elements
+            // from `this` to `out` moves them.
+            unsafe {
+                move_bytewise::<_, T, _>(this, out, N);
+            }
+            return;
+        }
+
         let mut this = Val::leak(In::into_inner(this));
         let mut out = In::into_inner(out);
 
@@ -68,17 +87,33 @@ where
     }
 }
 
-// SAFETY: `move_unsized_unchecked` initializes its `out` parameter by emplacing
-// to every element in it.
+// SAFETY:
+// - `move_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   every element in it, or by copying all of the elements at once if they can
+//   be moved bytewise.
+// - A slice can be moved bytewise if its elements can be moved bytewise.
 unsafe impl<T, R: Region> Move<R> for [T]
 where
     T: Move<R>,
 {
+    const BYTEWISE: bool = T::BYTEWISE;
+
     unsafe fn move_unsized_unchecked(
         this: In<Val<'_, Self>, R>,
         out: In<Slot<'_, Self>, R>,
     ) {
         let len = this.len();
+
+        if T::BYTEWISE {
+            // SAFETY: `T` can be moved bytewise, and the caller has guaranteed
+            // that `out` has the same length as `this`. So copying all `len`
+            // elements from `this` to `out` moves them.
+            unsafe {
+                move_bytewise::<_, T, _>(this, out, len);
+            }
+            return;
+        }
+
         let mut this = Val::leak(In::into_inner(this));
         let mut out = In::into_inner(out);
 
@@ -107,12 +142,50 @@ where
     }
 }
 
-// SAFETY: `move_unsized_unchecked` does not have to initialize `out` because
-// `PhantomData` is zero-sized and so always initialized.
+// SAFETY:
+// - `move_unsized_unchecked` does not have to initialize `out` because
+//   `PhantomData` is zero-sized and so always initialized.
+// - `PhantomData` has no bytes, so copying them is trivially equivalent to
+//   moving it.
 unsafe impl<T: ?Sized, R: Region> Move<R> for PhantomData<T> {
+    const BYTEWISE: bool = true;
+
+    #[inline]
     unsafe fn move_unsized_unchecked(
         _: In<Val<'_, Self>, R>,
         _: In<Slot<'_, Self>, R>,
     ) {
     }
 }
+
+/// Moves the `len` elements of type `T` in `this` to `out` by copying their
+/// bytes.
+///
+/// # Safety
+///
+/// - `T` must be able to be moved bytewise.
+/// - `this` and `out` must both point to `len` contiguous elements of type `T`.
+#[inline]
+unsafe fn move_bytewise<S, T, R>(
+    this: In<Val<'_, S>, R>,
+    out: In<Slot<'_, S>, R>,
+    len: usize,
+) where
+    S: DropRaw + ?Sized,
+    R: Region,
+{
+    let this = Val::forget(In::into_inner(this));
+    let out = In::into_inner(out);
+    // SAFETY:
+    // - The caller has guaranteed that `this` and `out` both point to `len`
+    //   contiguous elements, so they are valid for reads and writes of `len`
+    //   elements respectively. They are both properly aligned.
+    // - `this` is a `Val` and `out` is a `Slot`, so they do not overlap.
+    unsafe {
+        ptr::copy_nonoverlapping(
+            this.as_ptr().cast::<T>(),
+            out.as_ptr().cast::<T>(),
+            len,
+        );
+    }
+}
@@ -9,8 +9,18 @@ use ::situ::{DropRaw, Val};
 ///
 /// # Safety
 ///
-/// `move_unsized_unchecked` must initialize its `out` parameter.
+/// - `move_unsized_unchecked` must initialize its `out` parameter.
+/// - If `BYTEWISE` is `true`, then copying the bytes of a value into a slot
+///   must be equivalent to moving it into that slot.
 pub unsafe trait Move<R: Region>: DropRaw {
+    /// Whether values of this type can be moved by copying their bytes.
+    ///
+    /// Types which contain relative pointers generally need to fix them up when
+    /// they move, so this defaults to `false`. When it is `true`, containers
+    /// may move many values at once with a single bulk copy instead of moving
+    /// each value individually.
+    const BYTEWISE: bool = false;
+
     /// Moves a value into a given slot within some memory region.
     ///
     /// # Safety
@@ -53,6 +63,7 @@ pub unsafe trait MoveExt<R: Region>: Move<R> {
 // SAFETY: `move_unsized` and `r#move`/`move_` initialize their `out` paramters
 // by calling `move_unsized_unchecked`.
 unsafe impl<T: Move<R> + Pointee + ?Sized, R: Region> MoveExt<R> for T {
+    #[inline]
     fn move_unsized(this: In<Val<'_, Self>, R>, out: In<Slot<'_, Self>, R>) {
         assert!(metadata(this.ptr().as_ptr()) == metadata(out.ptr().as_ptr()));
         // SAFETY: We have asserted that `out` has the same metadata as `this`.
@@ -61,6 +72,7 @@ unsafe impl<T: Move<R> + Pointee + ?Sized, R: Region> MoveExt<R> for T {
         }
     }
 
+    #[inline]
     fn r#move(this: In<Val<'_, Self>, R>, out: In<Slot<'_, Self>, R>)
     where
         Self: Sized,
@@ -73,6 +85,7 @@ unsafe impl<T: Move<R> + Pointee + ?Sized, R: Region> MoveExt<R> for T {
         }
     }
 
+    #[inline]
     fn move_(this: In<Val<'_, Self>, R>, out: In<Slot<'_, Self>, R>)
     where
         Self: Sized,
@@ -80,3 +93,100 @@ unsafe impl<T: Move<R> + Pointee + ?Sized, R: Region> MoveExt<R> for T {
         Self::r#move(this, out);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ::core::{marker::PhantomData, mem::MaybeUninit};
+    use ::mischief::{In, Region, Slot};
+    use ::munge::munge;
+    use ::ptr_meta::Pointee;
+    use ::situ::DropRaw;
+
+    use crate::{
+        tests::{emplace, Local},
+        DefaultBasis,
+        Emplace,
+        EmplaceExt,
+        Move,
+        Portable,
+        RelPtr,
+        U32,
+    };
+
+    #[test]
+    fn derive_phantom_data() {
+        #[derive(DropRaw, Move, Portable)]
+        #[rel_core = "crate"]
+        #[repr(C)]
+        struct Tagged<T: ?Sized> {
+            value: U32,
+            tag: PhantomData<T>,
+        }
+
+        // SAFETY:
+        // - `emplaced_meta` returns `()`, the only valid metadata for `Sized`
+        //   types.
+        // - `emplace_unsized_unchecked` initializes its `out` parameter by
+        //   emplacing to each of its fields.
+        unsafe impl<T: ?Sized, R: Region> Emplace<Tagged<T>, R> for u32 {
+            fn emplaced_meta(&self) -> <Tagged<T> as Pointee>::Metadata {}
+
+            unsafe fn emplace_unsized_unchecked(
+                self,
+                out: In<Slot<'_, Tagged<T>>, R>,
+            ) {
+                munge!(let Tagged { value, tag } = out);
+                self.emplace(value);
+                PhantomData.emplace(tag);
+            }
+        }
+
+        let mut out = MaybeUninit::uninit();
+        let tagged = emplace::<Tagged<str>, _>(42u32, &mut out);
+        assert_eq!(tagged.value.to_ne(), 42);
+        const _: () = assert!(<Tagged<str> as Move<Local>>::BYTEWISE);
+    }
+
+    #[test]
+    fn derive_const_generics() {
+        #[derive(DropRaw, Move, Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Inline<T, const N: usize> {
+            len: U32,
+            items: [T; N],
+        }
+
+        #[derive(DropRaw, Move, Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(C)]
+        struct Defaulted<const N: usize = 4>([U32; N]);
+
+        #[derive(DropRaw, Move, Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(u8)]
+        enum Either<T, const N: usize> {
+            Inline([T; N]),
+            Single(T),
+        }
+
+        type Ptr = RelPtr<U32, Local, DefaultBasis>;
+
+        fn assert_portable<T: Portable>() {}
+
+        assert_portable::<Inline<U32, 3>>();
+        assert_portable::<Defaulted>();
+        assert_portable::<Either<U32, 0>>();
+
+        const _: () = {
+            assert!(<Inline<U32, 3> as Move<Local>>::BYTEWISE);
+            assert!(<Defaulted as Move<Local>>::BYTEWISE);
+            assert!(<Either<U32, 2> as Move<Local>>::BYTEWISE);
+            assert!(!<Inline<Ptr, 3> as Move<Local>>::BYTEWISE);
+            assert!(!<Either<Ptr, 2> as Move<Local>>::BYTEWISE);
+        };
+    }
+}
@@ -0,0 +1,150 @@
+//! Relative versions of networking types.
+
+use ::core::fmt;
+use ::mischief::{In, Region, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+use ::situ::{
+    fmt::{DebugRaw, DisplayRaw},
+    DropRaw,
+    Ref,
+};
+use ::std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    ToNative,
+    Validate,
+};
+
+macro_rules! impl_ip_addr {
+    ($rel:ident, $native:ident, $len:literal, $doc:literal) => {
+        #[doc = concat!(
+            "A relative counterpart to `",
+            stringify!($native),
+            "`.",
+        )]
+        ///
+        #[doc = $doc]
+        #[derive(Clone, Copy, Eq, Hash, PartialEq)]
+        #[derive(DropRaw, Move, Portable, Validate)]
+        #[rel_core = "crate"]
+        #[repr(transparent)]
+        pub struct $rel {
+            octets: [u8; $len],
+        }
+
+        impl $rel {
+            /// Returns the octets that make up the address.
+            #[inline]
+            pub fn octets(&self) -> [u8; $len] {
+                self.octets
+            }
+
+            #[doc = concat!(
+                "Returns the `",
+                stringify!($native),
+                "` that this is a relative version of.",
+            )]
+            #[inline]
+            pub fn to_addr(&self) -> $native {
+                $native::from(self.octets)
+            }
+        }
+
+        // SAFETY:
+        // - `emplaced_meta` returns `()`, the only valid metadata for `Sized`
+        //   types.
+        // - `emplace_unsized_unchecked` initializes its `out` parameter by
+        //   emplacing to its only field.
+        unsafe impl<R: Region> Emplace<$rel, R> for $native {
+            #[inline]
+            fn emplaced_meta(&self) -> <$rel as Pointee>::Metadata {}
+
+            #[inline]
+            unsafe fn emplace_unsized_unchecked(
+                self,
+                out: In<Slot<'_, $rel>, R>,
+            ) {
+                munge!(let $rel { octets } = out);
+                self.octets().emplace(octets);
+            }
+        }
+
+        impl DebugRaw for $rel {
+            fn fmt_raw(
+                this: Ref<'_, Self>,
+                f: &mut fmt::Formatter<'_>,
+            ) -> Result<(), fmt::Error> {
+                fmt::Debug::fmt(&this.to_addr(), f)
+            }
+        }
+
+        impl DisplayRaw for $rel {
+            fn fmt_raw(
+                this: Ref<'_, Self>,
+                f: &mut fmt::Formatter<'_>,
+            ) -> Result<(), fmt::Error> {
+                fmt::Display::fmt(&this.to_addr(), f)
+            }
+        }
+
+        impl ToNative for $rel {
+            type Native = $native;
+
+            #[inline]
+            fn to_native(this: Ref<'_, Self>) -> Self::Native {
+                this.to_addr()
+            }
+        }
+    };
+}
+
+impl_ip_addr!(
+    RelIpv4Addr,
+    Ipv4Addr,
+    4,
+    "The four octets of the address are stored in network order, so every \
+    `RelIpv4Addr` has the same layout on every platform."
+);
+
+impl_ip_addr!(
+    RelIpv6Addr,
+    Ipv6Addr,
+    16,
+    "The sixteen octets of the address are stored in network order, so every \
+    `RelIpv6Addr` has the same layout on every platform."
+);
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::MaybeUninit;
+    use ::std::{
+        format,
+        net::{Ipv4Addr, Ipv6Addr},
+    };
+
+    use super::{RelIpv4Addr, RelIpv6Addr};
+    use crate::{tests::emplace, ToNative};
+
+    #[test]
+    fn rel_ip_addr() {
+        let v4 = Ipv4Addr::new(192, 168, 0, 1);
+        let mut out = MaybeUninit::uninit();
+        let rel_v4 = emplace::<RelIpv4Addr, _>(v4, &mut out);
+        assert_eq!(rel_v4.octets(), [192, 168, 0, 1]);
+        assert_eq!(ToNative::to_native(rel_v4.as_ref()), v4);
+        assert_eq!(format!("{}", rel_v4), "192.168.0.1");
+
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1);
+        let mut out = MaybeUninit::uninit();
+        let rel_v6 = emplace::<RelIpv6Addr, _>(v6, &mut out);
+        assert_eq!(rel_v6.octets(), v6.octets());
+        assert_eq!(rel_v6.to_addr(), v6);
+        assert_eq!(format!("{}", rel_v6), "2001:db8::1");
+        assert_eq!(format!("{:?}", rel_v6), "2001:db8::1");
+    }
+}
@@ -1,15 +1,31 @@
 //! A value that may or may not exist.
 
-use ::core::{hint::unreachable_unchecked, ptr::addr_of_mut};
+use ::core::{
+    fmt,
+    hint::unreachable_unchecked,
+    ptr::{addr_of, addr_of_mut},
+};
 use ::mischief::{In, Region, Slot};
 use ::ptr_meta::Pointee;
 use ::raw_enum_macro::raw_enum;
-use ::situ::DropRaw;
+#[cfg(feature = "serde")]
+use ::serde::Serializer;
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
+use ::situ::{
+    diff::{DiffPath, DiffRaw, PathSegment},
+    fmt::DebugRaw,
+    CloneRaw,
+    DropRaw,
+    Ref,
+};
 
-use crate::{Emplace, EmplaceExt, Move, Portable};
+#[cfg(feature = "alloc")]
+use crate::ToNative;
+use crate::{Emplace, EmplaceExt, Move, Portable, Validate};
 
 /// A relative counterpart to `Option`.
-#[derive(DropRaw, Move, Portable)]
+#[derive(CloneRaw, DropRaw, Move, Portable, Validate)]
 #[rel_core = "crate"]
 #[repr(u8)]
 #[raw_enum]
@@ -75,3 +91,115 @@ where
         }
     }
 }
+
+impl<T: DebugRaw> DebugRaw for RelOption<T> {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        match raw_rel_option_variant(raw_rel_option(this.as_ptr())) {
+            RawRelOptionVariants::None(_) => f.write_str("None"),
+            RawRelOptionVariants::Some(this_ptr) => {
+                // SAFETY: `this_ptr` points to the `Some` variant of a valid
+                // `RelOption`, so its value is initialized and valid for reads.
+                let value =
+                    unsafe { Ref::new_unchecked(addr_of!((*this_ptr).1)) };
+                f.debug_tuple("Some").field(&value).finish()
+            }
+        }
+    }
+}
+
+impl<T: DiffRaw> DiffRaw for RelOption<T> {
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        path: &mut P,
+    ) -> bool {
+        match (
+            raw_rel_option_variant(raw_rel_option(this.as_ptr())),
+            raw_rel_option_variant(raw_rel_option(other.as_ptr())),
+        ) {
+            (RawRelOptionVariants::None(_), RawRelOptionVariants::None(_)) => {
+                false
+            }
+            (
+                RawRelOptionVariants::Some(this_ptr),
+                RawRelOptionVariants::Some(other_ptr),
+            ) => {
+                // SAFETY: `this_ptr` and `other_ptr` point to the `Some`
+                // variants of valid `RelOption`s, so their values are
+                // initialized and valid for reads.
+                let (this_value, other_value) = unsafe {
+                    (
+                        Ref::new_unchecked(addr_of!((*this_ptr).1)),
+                        Ref::new_unchecked(addr_of!((*other_ptr).1)),
+                    )
+                };
+                let differs = DiffRaw::diff_raw(this_value, other_value, path);
+                if differs {
+                    path.push_segment(PathSegment::Field("0"));
+                    path.push_segment(PathSegment::Variant("Some"));
+                }
+                differs
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToNative> ToNative for RelOption<T> {
+    type Native = Option<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        match raw_rel_option_variant(raw_rel_option(this.as_ptr())) {
+            RawRelOptionVariants::None(_) => None,
+            RawRelOptionVariants::Some(this_ptr) => {
+                // SAFETY: `this_ptr` points to the `Some` variant of a valid
+                // `RelOption`, so its value is initialized and valid for reads.
+                let value =
+                    unsafe { Ref::new_unchecked(addr_of!((*this_ptr).1)) };
+                Some(T::to_native(value))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: SerializeRaw> SerializeRaw for RelOption<T> {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match raw_rel_option_variant(raw_rel_option(this.as_ptr())) {
+            RawRelOptionVariants::None(_) => serializer.serialize_none(),
+            RawRelOptionVariants::Some(this_ptr) => {
+                // SAFETY: `this_ptr` points to the `Some` variant of a valid
+                // `RelOption`, so its value is initialized and valid for reads.
+                let value =
+                    unsafe { Ref::new_unchecked(addr_of!((*this_ptr).1)) };
+                serializer.serialize_some(&value)
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::mem::MaybeUninit;
+    use ::std::format;
+
+    use crate::{option::RelOption, tests::emplace, U32};
+
+    #[test]
+    fn debug() {
+        let mut out = MaybeUninit::uninit();
+        let none = emplace::<RelOption<U32>, _>(None::<u32>, &mut out);
+        assert_eq!(format!("{:?}", none), "None");
+
+        let mut out = MaybeUninit::uninit();
+        let some = emplace::<RelOption<U32>, _>(Some(42u32), &mut out);
+        assert_eq!(format!("{:?}", some), "Some(42)");
+    }
+}
@@ -5,15 +5,69 @@ use ::core::{
 };
 use ::mischief::{GhostRef, StaticToken};
 pub use ::rel_core_derive::Portable;
+use ::situ::{Mut, Ref};
 
 /// A type that has the same representation on all targets.
 ///
+/// Having the same representation does not make every bit pattern valid. Some
+/// portable types like `bool` and [`Char`](crate::Char) have invalid bit
+/// patterns, so a buffer loaded from an untrusted source must be checked with
+/// [`Validate`](crate::Validate) before any of its values are accessed.
+///
 /// # Safety
 ///
 /// `Portable` types must have the same layout and bytewise representation on
 /// all targets.
 pub unsafe trait Portable {}
 
+/// An extension trait for `Ref` that converts references to `Portable` values
+/// into native references.
+pub trait AsNative<'a, T: Portable + ?Sized> {
+    /// Returns a native reference to the referenced value.
+    ///
+    /// Unlike dereferencing the `Ref`, the returned reference lives for the
+    /// entire lifetime of the `Ref`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_native(self) -> &'a T;
+}
+
+impl<'a, T: Portable + ?Sized> AsNative<'a, T> for Ref<'a, T> {
+    #[inline]
+    fn as_native(self) -> &'a T {
+        // SAFETY: The pointer of a `Ref<'a, T>` is always non-null, properly
+        // aligned, valid for reads, and points to an initialized `T` which is
+        // not mutably aliased for `'a`.
+        unsafe { &*self.as_ptr() }
+    }
+}
+
+/// An extension trait for `Mut` that converts mutable references to `Portable`
+/// values into native mutable references.
+///
+/// The referenced type must also be `Unpin`, because native mutable references
+/// allow values to be moved out of them (for example with `mem::swap`).
+pub trait AsNativeMut<'a, T: Portable + Unpin + ?Sized> {
+    /// Returns a native mutable reference to the referenced value.
+    ///
+    /// Unlike dereferencing the `Mut`, the returned reference lives for the
+    /// entire lifetime of the `Mut`.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_native_mut(self) -> &'a mut T;
+}
+
+impl<'a, T: Portable + Unpin + ?Sized> AsNativeMut<'a, T> for Mut<'a, T> {
+    #[inline]
+    fn as_native_mut(self) -> &'a mut T {
+        // SAFETY:
+        // - The pointer of a `Mut<'a, T>` is always non-null, properly aligned,
+        //   valid for reads and writes, and points to an initialized `T` which
+        //   is not aliased for `'a`. The `Mut` is consumed, so the returned
+        //   reference is the only way to access the value for `'a`.
+        // - `T` is `Unpin`, so it may be moved out of the returned reference.
+        unsafe { &mut *self.as_ptr() }
+    }
+}
+
 // Sources:
 // https://doc.rust-lang.org/reference/types/boolean.html
 // https://doc.rust-lang.org/reference/types/numeric.html
@@ -68,3 +122,65 @@ unsafe impl<T> Portable for GhostRef<T> {}
 // SAFETY: `StaticToken` has a size of 0 and an alignment of 1. It has no bit
 // patterns.
 unsafe impl Portable for StaticToken<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use ::situ::{Mut, Ref};
+
+    use crate::{AsNative, AsNativeMut, Portable, F32, I32, U16, U32};
+
+    #[test]
+    fn derive_enum() {
+        #[derive(Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(u8)]
+        enum GameType {
+            Survival,
+            Creative,
+        }
+
+        #[derive(Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(C, i8)]
+        enum Shape {
+            Circle { radius: U32 },
+            Rect(U16, U16),
+            Empty,
+        }
+
+        #[derive(Portable)]
+        #[rel_core = "crate"]
+        #[allow(dead_code)]
+        #[repr(u8)]
+        enum Wrapper<T> {
+            Value(T),
+            Pair(T, GameType),
+        }
+
+        fn assert_portable<T: Portable + ?Sized>() {}
+
+        assert_portable::<GameType>();
+        assert_portable::<Shape>();
+        assert_portable::<Wrapper<Shape>>();
+        assert_portable::<Wrapper<U32>>();
+    }
+
+    #[test]
+    fn as_native() {
+        let float = F32::from_ne(1.5);
+        // SAFETY: `float` is a valid `F32` which is never mutated.
+        let float = unsafe { Ref::new_unchecked(&float) };
+        let native: &F32 = float.as_native();
+        assert_eq!(native.to_ne(), 1.5);
+
+        let mut int = I32::from_ne(-3);
+        // SAFETY: `int` is a valid `I32` which is not otherwise accessed while
+        // the `Mut` is live.
+        let mut int = unsafe { Mut::new_unchecked(&mut int) };
+        let native: &mut I32 = int.as_mut().as_native_mut();
+        *native = I32::from_ne(native.to_ne() * 2);
+        assert_eq!(int.to_ne(), -6);
+    }
+}
@@ -0,0 +1,24 @@
+//! Commonly used traits and types.
+//!
+//! Glob importing the prelude also brings the extension traits into scope, so
+//! that functions like `emplace` and `r#move` resolve.
+
+pub use ::situ::{DropRaw, Mut, Ref, Val};
+
+#[cfg(feature = "std")]
+pub use crate::net::{RelIpv4Addr, RelIpv6Addr};
+pub use crate::{
+    cell::{RelCell, RelOnceCell},
+    option::RelOption,
+    range::{RelRange, RelRangeInclusive},
+    time::RelDuration,
+    AsNative,
+    AsNativeMut,
+    Emplace,
+    EmplaceExt,
+    Move,
+    MoveExt,
+    Portable,
+    RelPtr,
+    RelRef,
+};
@@ -2,6 +2,7 @@ use ::core::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
+    num::TryFromIntError,
 };
 use ::mischief::{In, Region, Slot};
 use ::ptr_meta::Pointee;
@@ -77,6 +78,17 @@ macro_rules! impl_primitive {
             }
         }
 
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $portable {
+            #[inline]
+            fn serialize<S: ::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                ::serde::Serialize::serialize(&self.to_ne(), serializer)
+            }
+        }
+
         impl DropRaw for $portable {
             #[inline]
             unsafe fn drop_raw(_: Mut<'_, Self>) {}
@@ -87,8 +99,10 @@ macro_rules! impl_primitive {
         //  `emplaced_meta` always returns valid metadata for them.
         // - `emplace_unsized_unchecked` initializes `out` by writing to it.
         unsafe impl<R: Region> Emplace<$portable, R> for $native {
+            #[inline]
             fn emplaced_meta(&self) -> <$portable as Pointee>::Metadata {}
 
+            #[inline]
             unsafe fn emplace_unsized_unchecked(
                 self,
                 out: In<Slot<'_, $portable>, R>,
@@ -97,9 +111,15 @@ macro_rules! impl_primitive {
             }
         }
 
-        // SAFETY: `move_unsized_unchecked` initializes its `out` parameter by
-        // writing to it.
+        // SAFETY:
+        // - `move_unsized_unchecked` initializes its `out` parameter by
+        //   writing to it.
+        // - `$portable` does not contain any relative pointers, so moving it
+        //   is equivalent to copying its bytes.
         unsafe impl<R: Region> Move<R> for $portable {
+            const BYTEWISE: bool = true;
+
+            #[inline]
             unsafe fn move_unsized_unchecked(
                 this: In<Val<'_, Self>, R>,
                 out: In<Slot<'_, Self>, R>,
@@ -138,6 +158,35 @@ macro_rules! impl_primitive {
     }
 }
 
+macro_rules! impl_assign_raw {
+    ($portable:ty, $native:ty, $($trait:ident::$fn:ident => $op:tt,)*) => {
+        $(
+            impl ::situ::ops::$trait<$native> for $portable {
+                #[inline]
+                fn $fn(mut this: Mut<'_, Self>, rhs: $native) {
+                    let mut value = this.to_ne();
+                    value $op rhs;
+                    *this = <$portable>::from_ne(value);
+                }
+            }
+        )*
+    };
+    ($portable:ty, $native:ty) => {
+        impl_assign_raw!(
+            $portable,
+            $native,
+            AddAssignRaw::add_assign_raw => +=,
+            SubAssignRaw::sub_assign_raw => -=,
+            MulAssignRaw::mul_assign_raw => *=,
+            DivAssignRaw::div_assign_raw => /=,
+            RemAssignRaw::rem_assign_raw => %=,
+            BitAndAssignRaw::bitand_assign_raw => &=,
+            BitOrAssignRaw::bitor_assign_raw => |=,
+            BitXorAssignRaw::bitxor_assign_raw => ^=,
+        );
+    };
+}
+
 macro_rules! impl_multibyte_integer {
     ($portable:ident, $align:expr, $native:ty) => {
         #[doc = concat!("A portable `", stringify!($native), "`.")]
@@ -152,6 +201,16 @@ macro_rules! impl_multibyte_integer {
         // endianness.
         unsafe impl Portable for $portable {}
 
+        // SAFETY: `$portable` only contains a `$native`, for which the
+        // all-zeroes bit pattern is zero.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Zeroable for $portable {}
+
+        // SAFETY: `$portable` has an alignment equal to its size, so it has no
+        // padding. Every bit pattern is a valid `$native`.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl ::bytemuck::Pod for $portable {}
+
         impl $portable {
             #[doc = "Returns the `"]
             #[doc = stringify!($portable)]
@@ -193,6 +252,7 @@ macro_rules! impl_multibyte_integer {
         }
 
         impl_primitive!($portable, $native);
+        impl_assign_raw!($portable, $native);
     };
 }
 
@@ -205,7 +265,116 @@ impl_multibyte_integer!(U32, 4, u32);
 impl_multibyte_integer!(U64, 8, u64);
 impl_multibyte_integer!(U128, 16, u128);
 
+macro_rules! impl_from_portable {
+    ($($from:ty => $($to:ty),*;)*) => {
+        $($(
+            impl From<$from> for $to {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    <$to>::from_ne(value.to_ne().into())
+                }
+            }
+        )*)*
+    };
+}
+
+macro_rules! impl_try_from_portable {
+    ($($from:ty => $($to:ty),*;)*) => {
+        $($(
+            impl TryFrom<$from> for $to {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $from) -> Result<Self, Self::Error> {
+                    Ok(<$to>::from_ne(value.to_ne().try_into()?))
+                }
+            }
+        )*)*
+    };
+}
+
+macro_rules! impl_try_from_size {
+    ($($size:ty => $($portable:ty),*;)*) => {
+        $($(
+            impl TryFrom<$size> for $portable {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $size) -> Result<Self, Self::Error> {
+                    Ok(<$portable>::from_ne(value.try_into()?))
+                }
+            }
+        )*)*
+    };
+}
+
+macro_rules! impl_try_into_size {
+    ($($size:ty => $($portable:ty),*;)*) => {
+        $($(
+            impl TryFrom<$portable> for $size {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $portable) -> Result<Self, Self::Error> {
+                    value.to_ne().try_into()
+                }
+            }
+        )*)*
+    };
+}
+
+// These mirror the conversions between the native integer types provided by
+// the standard library.
+impl_from_portable! {
+    U16 => U32, U64, U128, I32, I64, I128;
+    U32 => U64, U128, I64, I128;
+    U64 => U128, I128;
+    I16 => I32, I64, I128;
+    I32 => I64, I128;
+    I64 => I128;
+}
+
+impl_try_from_portable! {
+    U16 => I16;
+    U32 => U16, I16, I32;
+    U64 => U16, U32, I16, I32, I64;
+    U128 => U16, U32, U64, I16, I32, I64, I128;
+    I16 => U16, U32, U64, U128;
+    I32 => U16, U32, U64, U128, I16;
+    I64 => U16, U32, U64, U128, I16, I32;
+    I128 => U16, U32, U64, U128, I16, I32, I64;
+}
+
+impl_try_from_size! {
+    usize => U16, U32, U64, U128, I16, I32, I64, I128;
+    isize => U16, U32, U64, U128, I16, I32, I64, I128;
+}
+
+impl_try_into_size! {
+    usize => U32, U64, U128, I16, I32, I64, I128;
+    isize => U16, U32, U64, U128, I32, I64, I128;
+}
+
+impl From<U16> for usize {
+    #[inline]
+    fn from(value: U16) -> Self {
+        usize::from(value.to_ne())
+    }
+}
+
+impl From<I16> for isize {
+    #[inline]
+    fn from(value: I16) -> Self {
+        isize::from(value.to_ne())
+    }
+}
+
 /// A portable `f32`.
+///
+/// Like `f32`, `F32` only implements `PartialEq` and `PartialOrd`. Comparing
+/// a NaN with any value returns `None`, so sorting or binary searching `F32`s
+/// with `partial_cmp` can silently produce wrong results when NaNs are present.
+/// Use [`total_cmp_raw`](Self::total_cmp_raw) when a total order is required.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct F32 {
@@ -216,6 +385,15 @@ pub struct F32 {
 // the same layout and bytewise representation guarantees as `U32`.
 unsafe impl Portable for F32 where U32: Portable {}
 
+// SAFETY: `F32` is `repr(transparent)` over a `U32`, which is `Zeroable`.
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Zeroable for F32 {}
+
+// SAFETY: `F32` is `repr(transparent)` over a `U32`, which is `Pod`. Every
+// bit pattern is a valid float.
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Pod for F32 {}
+
 impl F32 {
     /// Returns the `F32` corresponding to the given `f32`.
     #[inline]
@@ -230,11 +408,26 @@ impl F32 {
     pub fn to_ne(self) -> f32 {
         f32::from_bits(self.int_repr.to_ne())
     }
+
+    /// Returns the ordering between `self` and `other` according to the
+    /// IEEE 754 `totalOrder` predicate.
+    ///
+    /// This is the same ordering as [`f32::total_cmp`], and is a total order
+    /// even when NaNs are present.
+    #[inline]
+    pub fn total_cmp_raw(&self, other: &Self) -> Ordering {
+        self.to_ne().total_cmp(&other.to_ne())
+    }
 }
 
 impl_primitive!(@base F32, f32);
 
 /// A portable `f64`.
+///
+/// Like `f64`, `F64` only implements `PartialEq` and `PartialOrd`. Comparing
+/// a NaN with any value returns `None`, so sorting or binary searching `F64`s
+/// with `partial_cmp` can silently produce wrong results when NaNs are present.
+/// Use [`total_cmp_raw`](Self::total_cmp_raw) when a total order is required.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct F64 {
@@ -245,6 +438,15 @@ pub struct F64 {
 // the same layout and bytewise representation guarantees as `U64`.
 unsafe impl Portable for F64 where U64: Portable {}
 
+// SAFETY: `F64` is `repr(transparent)` over a `U64`, which is `Zeroable`.
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Zeroable for F64 {}
+
+// SAFETY: `F64` is `repr(transparent)` over a `U64`, which is `Pod`. Every
+// bit pattern is a valid float.
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Pod for F64 {}
+
 impl F64 {
     /// Returns the `F64` corresponding to the given `f64`.
     #[inline]
@@ -259,6 +461,16 @@ impl F64 {
     pub fn to_ne(self) -> f64 {
         f64::from_bits(self.int_repr.to_ne())
     }
+
+    /// Returns the ordering between `self` and `other` according to the
+    /// IEEE 754 `totalOrder` predicate.
+    ///
+    /// This is the same ordering as [`f64::total_cmp`], and is a total order
+    /// even when NaNs are present.
+    #[inline]
+    pub fn total_cmp_raw(&self, other: &Self) -> Ordering {
+        self.to_ne().total_cmp(&other.to_ne())
+    }
 }
 
 impl_primitive!(@base F64, f64);
@@ -274,6 +486,12 @@ pub struct Char {
 // the same layout and bytewise representation guarantees as `U32`.
 unsafe impl Portable for Char where U32: Portable {}
 
+// SAFETY: `Char` is `repr(transparent)` over a `U32`, and the all-zeroes bit
+// pattern is `'\0'`. `Char` is not `Pod` because not every `U32` is a valid
+// `char`.
+#[cfg(feature = "bytemuck")]
+unsafe impl ::bytemuck::Zeroable for Char {}
+
 impl Char {
     /// Returns the `Char` corresponding to the given `char`.
     #[inline]
@@ -283,7 +501,21 @@ impl Char {
         }
     }
 
+    /// Returns the `char` corresponding to this `Char`, or `None` if it does
+    /// not contain a valid Unicode scalar value.
+    ///
+    /// A `Char` constructed with [`from_ne`](Self::from_ne) is always valid,
+    /// but one read from an untrusted buffer may not be. Unlike
+    /// [`to_ne`](Self::to_ne), this is always safe to call on such a `Char`.
+    #[inline]
+    pub fn try_to_ne(self) -> Option<char> {
+        char::from_u32(self.int_repr.to_ne())
+    }
+
     /// Returns the `char` corresponding to this `Char`.
+    ///
+    /// A `Char` read from an untrusted buffer must be validated before calling
+    /// this. Use [`try_to_ne`](Self::try_to_ne) to check it instead.
     #[inline]
     pub fn to_ne(self) -> char {
         // SAFETY: `int_repr` always contains a `u32` that is a valid `char`.
@@ -292,3 +524,117 @@ impl Char {
 }
 
 impl_primitive!(Char, char);
+
+#[cfg(test)]
+mod tests {
+    use ::core::cmp::Ordering;
+
+    use crate::{Char, F32, F64, I16, I64, U16, U32, U64};
+
+    #[test]
+    fn int_conversions() {
+        assert_eq!(U64::from(U16::from_ne(0x1234)).to_ne(), 0x1234);
+        assert_eq!(I64::from(U32::from_ne(u32::MAX)).to_ne(), 0xffff_ffff);
+        assert_eq!(I64::from(I16::from_ne(-2)).to_ne(), -2);
+
+        assert_eq!(
+            U16::try_from(U32::from_ne(0xffff)).unwrap().to_ne(),
+            0xffff,
+        );
+        assert!(U16::try_from(U32::from_ne(0x1_0000)).is_err());
+        assert!(U32::try_from(I16::from_ne(-1)).is_err());
+
+        assert_eq!(U64::try_from(42usize).unwrap().to_ne(), 42);
+        assert_eq!(usize::try_from(U64::from_ne(42)).unwrap(), 42);
+        assert_eq!(usize::from(U16::from_ne(7)), 7);
+    }
+
+    #[test]
+    fn char_try_to_ne() {
+        assert_eq!(Char::from_ne('a').try_to_ne(), Some('a'));
+
+        // SAFETY: `Char` is `repr(transparent)` over a `U32`.
+        let surrogate = unsafe {
+            ::core::mem::transmute::<U32, Char>(U32::from_ne(0xd800))
+        };
+        assert_eq!(surrogate.try_to_ne(), None);
+    }
+
+    #[test]
+    fn assign_raw() {
+        use ::situ::{
+            ops::{AddAssignRaw, BitXorAssignRaw, MulAssignRaw, SubAssignRaw},
+            Mut,
+        };
+
+        use crate::I32;
+
+        let mut counter = I32::from_ne(10);
+        // SAFETY: `counter` is a valid `I32` which is not otherwise accessed
+        // while the `Mut` is live.
+        let mut counter = unsafe { Mut::new_unchecked(&mut counter) };
+        I32::add_assign_raw(counter.as_mut(), 5);
+        assert_eq!(counter.to_ne(), 15);
+        I32::sub_assign_raw(counter.as_mut(), 20);
+        assert_eq!(counter.to_ne(), -5);
+        I32::mul_assign_raw(counter.as_mut(), -3);
+        assert_eq!(counter.to_ne(), 15);
+
+        let mut flags = U16::from_ne(0x0f0f);
+        // SAFETY: `flags` is a valid `U16` which is not otherwise accessed
+        // while the `Mut` is live.
+        let mut flags = unsafe { Mut::new_unchecked(&mut flags) };
+        U16::bitxor_assign_raw(flags.as_mut(), 0xffff);
+        assert_eq!(flags.to_ne(), 0xf0f0);
+    }
+
+    #[test]
+    fn float_total_cmp() {
+        let nan = F32::from_ne(f32::NAN);
+        let one = F32::from_ne(1.0);
+        assert_eq!(nan.partial_cmp(&one), None);
+        assert_eq!(nan.total_cmp_raw(&one), Ordering::Greater);
+
+        let mut values =
+            [F64::from_ne(2.0), F64::from_ne(-0.0), F64::from_ne(0.0)];
+        values.sort_by(F64::total_cmp_raw);
+        assert_eq!(values.map(F64::to_ne), [-0.0, 0.0, 2.0]);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_round_trip() {
+        use ::bytemuck::{bytes_of, from_bytes, Zeroable};
+
+        use crate::Portable;
+
+        #[derive(Clone, Copy, Portable)]
+        #[rel_core = "crate"]
+        #[bytemuck]
+        #[repr(C)]
+        struct Sample {
+            id: U32,
+            weight: F32,
+            lo: U16,
+            hi: U16,
+        }
+
+        let zeroed = Sample::zeroed();
+        assert!(bytes_of(&zeroed).iter().all(|&b| b == 0));
+
+        let sample = Sample {
+            id: U32::from_ne(0x1234_5678),
+            weight: F32::from_ne(1.5),
+            lo: U16::from_ne(7),
+            hi: U16::from_ne(u16::MAX),
+        };
+        let bytes = bytes_of(&sample);
+        assert_eq!(bytes.len(), 12);
+
+        let read = from_bytes::<Sample>(bytes);
+        assert_eq!(read.id.to_ne(), 0x1234_5678);
+        assert_eq!(read.weight.to_ne(), 1.5);
+        assert_eq!(read.lo.to_ne(), 7);
+        assert_eq!(read.hi.to_ne(), u16::MAX);
+    }
+}
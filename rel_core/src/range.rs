@@ -0,0 +1,319 @@
+//! Relative versions of ranges.
+
+use ::core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Range, RangeInclusive},
+};
+use ::mischief::{In, Region, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+#[cfg(feature = "serde")]
+use ::serde::{ser::SerializeStruct, Serializer};
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
+use ::situ::{fmt::DebugRaw, CloneRaw, DropRaw, Ref};
+
+#[cfg(feature = "alloc")]
+use crate::ToNative;
+use crate::{
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    Validate,
+    U128,
+    U16,
+    U32,
+    U64,
+};
+
+/// A relative counterpart to `Range`.
+///
+/// Like `Range`, a `RelRange` contains all values with `start <= x < end`.
+#[derive(CloneRaw, DropRaw, Move, Portable, Validate)]
+#[rel_core = "crate"]
+#[repr(C)]
+pub struct RelRange<T> {
+    /// The lower bound of the range (inclusive).
+    pub start: T,
+    /// The upper bound of the range (exclusive).
+    pub end: T,
+}
+
+impl<T> RelRange<T> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        T: PartialOrd<U>,
+        U: PartialOrd<T> + ?Sized,
+    {
+        *item >= self.start && *item < self.end
+    }
+
+    /// Returns `true` if the range contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        !matches!(self.start.partial_cmp(&self.end), Some(Ordering::Less))
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   each of its fields.
+unsafe impl<T, E, R> Emplace<RelRange<T>, R> for Range<E>
+where
+    T: DropRaw,
+    E: Emplace<T, R>,
+    R: Region,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelRange<T> as Pointee>::Metadata {}
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelRange<T>>, R>,
+    ) {
+        munge!(let RelRange { start, end } = out);
+        self.start.emplace(start);
+        self.end.emplace(end);
+    }
+}
+
+impl<T: DebugRaw> DebugRaw for RelRange<T> {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        munge!(let RelRange { start, end } = this);
+        DebugRaw::fmt_raw(start, f)?;
+        f.write_str("..")?;
+        DebugRaw::fmt_raw(end, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToNative> ToNative for RelRange<T> {
+    type Native = Range<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        munge!(let RelRange { start, end } = this);
+        T::to_native(start)..T::to_native(end)
+    }
+}
+
+// Relative ranges serialize like the native ranges they correspond to.
+#[cfg(feature = "serde")]
+impl<T: SerializeRaw> SerializeRaw for RelRange<T> {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        munge!(let RelRange { start, end } = this);
+        let mut state = serializer.serialize_struct("Range", 2)?;
+        state.serialize_field("start", &start)?;
+        state.serialize_field("end", &end)?;
+        state.end()
+    }
+}
+
+/// A relative counterpart to `RangeInclusive`.
+///
+/// Like `RangeInclusive`, a `RelRangeInclusive` contains all values with
+/// `start <= x <= end`.
+#[derive(CloneRaw, DropRaw, Move, Portable, Validate)]
+#[rel_core = "crate"]
+#[repr(C)]
+pub struct RelRangeInclusive<T> {
+    /// The lower bound of the range (inclusive).
+    pub start: T,
+    /// The upper bound of the range (inclusive).
+    pub end: T,
+}
+
+impl<T> RelRangeInclusive<T> {
+    /// Returns `true` if `item` is contained in the range.
+    #[inline]
+    pub fn contains<U>(&self, item: &U) -> bool
+    where
+        T: PartialOrd<U>,
+        U: PartialOrd<T> + ?Sized,
+    {
+        *item >= self.start && *item <= self.end
+    }
+
+    /// Returns `true` if the range contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        !matches!(
+            self.start.partial_cmp(&self.end),
+            Some(Ordering::Less | Ordering::Equal),
+        )
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   each of its fields.
+unsafe impl<T, E, R> Emplace<RelRangeInclusive<T>, R> for RangeInclusive<E>
+where
+    T: DropRaw,
+    E: Emplace<T, R>,
+    R: Region,
+{
+    #[inline]
+    fn emplaced_meta(&self) -> <RelRangeInclusive<T> as Pointee>::Metadata {}
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelRangeInclusive<T>>, R>,
+    ) {
+        let (start_value, end_value) = self.into_inner();
+        munge!(let RelRangeInclusive { start, end } = out);
+        start_value.emplace(start);
+        end_value.emplace(end);
+    }
+}
+
+impl<T: DebugRaw> DebugRaw for RelRangeInclusive<T> {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        munge!(let RelRangeInclusive { start, end } = this);
+        DebugRaw::fmt_raw(start, f)?;
+        f.write_str("..=")?;
+        DebugRaw::fmt_raw(end, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToNative> ToNative for RelRangeInclusive<T> {
+    type Native = RangeInclusive<T::Native>;
+
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        munge!(let RelRangeInclusive { start, end } = this);
+        T::to_native(start)..=T::to_native(end)
+    }
+}
+
+// Relative ranges serialize like the native ranges they correspond to.
+#[cfg(feature = "serde")]
+impl<T: SerializeRaw> SerializeRaw for RelRangeInclusive<T> {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        munge!(let RelRangeInclusive { start, end } = this);
+        let mut state = serializer.serialize_struct("RangeInclusive", 2)?;
+        state.serialize_field("start", &start)?;
+        state.serialize_field("end", &end)?;
+        state.end()
+    }
+}
+
+macro_rules! impl_len {
+    ($($portable:ty => $native:ty),*) => {
+        $(
+            impl RelRange<$portable> {
+                /// Returns the number of values in the range.
+                ///
+                /// Empty ranges, including those with `start > end`, have a
+                /// length of zero.
+                #[inline]
+                pub fn len(&self) -> $native {
+                    self.end.to_ne().saturating_sub(self.start.to_ne())
+                }
+            }
+
+            impl RelRangeInclusive<$portable> {
+                /// Returns the number of values in the range.
+                ///
+                /// Empty ranges, where `start > end`, have a length of zero.
+                ///
+                /// # Panics
+                ///
+                #[doc = concat!(
+                    "Panics if the range spans every `",
+                    stringify!($native),
+                    "`, since its length does not fit in one.",
+                )]
+                #[inline]
+                pub fn len(&self) -> $native {
+                    let (start, end) = (self.start.to_ne(), self.end.to_ne());
+                    if start > end {
+                        0
+                    } else {
+                        (end - start)
+                            .checked_add(1)
+                            .expect("range length overflowed")
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_len!(U16 => u16, U32 => u32, U64 => u64, U128 => u128);
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::mem::MaybeUninit;
+    use ::std::format;
+
+    use crate::{
+        range::{RelRange, RelRangeInclusive},
+        tests::emplace,
+        ToNative,
+        U32,
+    };
+
+    #[test]
+    fn rel_range() {
+        let mut out = MaybeUninit::uninit();
+        let range = emplace::<RelRange<U32>, _>(2u32..5, &mut out);
+        assert_eq!(range.len(), 3);
+        assert!(!range.is_empty());
+        assert!(!range.contains(&U32::from_ne(1)));
+        assert!(range.contains(&U32::from_ne(2)));
+        assert!(range.contains(&U32::from_ne(4)));
+        assert!(!range.contains(&U32::from_ne(5)));
+        assert_eq!(format!("{:?}", range), "2..5");
+        assert_eq!(ToNative::to_native(range.as_ref()), 2..5);
+
+        let mut out = MaybeUninit::uninit();
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = emplace::<RelRange<U32>, _>(5u32..2, &mut out);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn rel_range_inclusive() {
+        let mut out = MaybeUninit::uninit();
+        let inclusive =
+            emplace::<RelRangeInclusive<U32>, _>(2u32..=5, &mut out);
+        assert_eq!(inclusive.len(), 4);
+        assert!(!inclusive.is_empty());
+        assert!(inclusive.contains(&U32::from_ne(5)));
+        assert!(!inclusive.contains(&U32::from_ne(6)));
+        assert_eq!(format!("{:?}", inclusive), "2..=5");
+        assert_eq!(ToNative::to_native(inclusive.as_ref()), 2..=5);
+
+        let mut out = MaybeUninit::uninit();
+        let single = emplace::<RelRangeInclusive<U32>, _>(3u32..=3, &mut out);
+        assert_eq!(single.len(), 1);
+        assert!(!single.is_empty());
+    }
+}
@@ -1,13 +1,16 @@
 //! Relative pointers and related types.
 
+#[cfg(feature = "std")]
+use ::core::cell::Cell;
 use ::core::{
+    fmt,
     marker::{PhantomData, PhantomPinned},
     mem::MaybeUninit,
 };
 use ::mischief::{In, Region, Slot};
 use ::munge::munge;
 use ::ptr_meta::{metadata, Pointee};
-use ::situ::{DropRaw, Mut, Pinned, Ref, Val};
+use ::situ::{fmt::DebugRaw, DropRaw, Mut, Pinned, Ref, Val};
 
 use crate::{
     rel_mem,
@@ -20,17 +23,37 @@ use crate::{
 };
 
 /// A pointer that stores the difference between itself and its pointee.
+///
+/// Null relative pointers are stored with an offset of `0`. To allow relative
+/// pointers to point to themselves, non-negative offsets are stored biased by
+/// one. This means that the maximum offset a relative pointer can store is one
+/// less than the maximum of `B::Isize`.
+///
+/// The offset of the pointer is stored using the basis `B`, and the metadata of
+/// the pointer is stored using the basis `BM`. By default, both use the same
+/// basis.
 #[repr(C)]
 #[derive(DropRaw, Portable)]
 #[rel_core = "crate"]
-pub struct RelPtr<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> {
+pub struct RelPtr<
+    T: BasisPointee<BM> + ?Sized,
+    R: Region,
+    B: Basis,
+    BM: Basis = B,
+> {
     offset: B::Isize,
-    metadata: MaybeUninit<<T as BasisPointee<B>>::BasisMetadata>,
+    metadata: MaybeUninit<<T as BasisPointee<BM>>::BasisMetadata>,
     _phantom: PhantomData<(*mut T, R)>,
     _pinned: PhantomPinned,
 }
 
-impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
+impl<T, R, B, BM> RelPtr<T, R, B, BM>
+where
+    T: BasisPointee<BM> + ?Sized,
+    R: Region,
+    B: Basis,
+    BM: Basis,
+{
     /// Returns the base pointer for the relative pointer.
     ///
     /// The base of the relative pointer is always its location in memory.
@@ -48,15 +71,22 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
     }
 
     /// Returns the offset of the relative pointer's target from its base.
+    ///
+    /// Null relative pointers return an offset of `0`.
     #[inline]
     pub fn offset(&self) -> isize {
-        B::to_native_isize(self.offset).unwrap()
+        let stored = B::to_native_isize(self.offset).unwrap();
+        if stored > 0 {
+            stored - 1
+        } else {
+            stored
+        }
     }
 
-    /// Returns whether the offset of the relative pointer is `0`.
+    /// Returns whether the relative pointer is null.
     #[inline]
     pub fn is_null(&self) -> bool {
-        self.offset() == 0
+        B::to_native_isize(self.offset).unwrap() == 0
     }
 
     /// Returns the metadata of the relative pointer's pointee if it is not
@@ -115,6 +145,24 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
         ::ptr_meta::from_raw_parts(data_address.cast(), metadata)
     }
 
+    /// Returns the target of the relative pointer if it is not null, computing
+    /// its address with wrapping arithmetic.
+    ///
+    /// Unlike `as_ptr`, this does not assume that the target is located in the
+    /// same contiguous memory segment as the relative pointer, so it can be
+    /// used on relative pointers that have not been validated yet.
+    #[inline]
+    pub fn as_ptr_wrapping(this: Ref<'_, Self>) -> Option<*const T> {
+        if this.is_null() {
+            None
+        } else {
+            let data_address = Self::base(this).wrapping_offset(this.offset());
+            // SAFETY: We checked that the relative pointer is not null.
+            let metadata = unsafe { this.metadata_unchecked() };
+            Some(::ptr_meta::from_raw_parts(data_address.cast(), metadata))
+        }
+    }
+
     /// Returns a `Ref` to the target of the relative pointer.
     ///
     /// # Safety
@@ -188,11 +236,47 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
         rel_mem::replace(In::new(this), ptr);
     }
 
+    /// Emplaces a `RelPtr` pointing to `ptr` into `out`.
+    ///
+    /// Unlike emplacing with an `In<*mut T, R>`, this returns an error instead
+    /// of panicking if the offset to `ptr` or its metadata cannot be
+    /// represented with `B`. If this returns an error, `out` is left
+    /// uninitialized.
+    ///
+    /// # Errors
+    ///
+    /// - Returns `RelPtrError::Offset` if the offset from `out` to `ptr` does
+    ///   not fit in `B::Isize`.
+    /// - Returns `RelPtrError::Metadata` if the metadata of `ptr` cannot be
+    ///   converted to the metadata for `B`.
+    pub fn try_emplace(
+        ptr: In<*mut T, R>,
+        out: In<Slot<'_, Self>, R>,
+    ) -> Result<(), RelPtrError<T, B, BM>> {
+        let (ptr, out) = (In::into_inner(ptr), In::into_inner(out));
+        // SAFETY: `ptr` and `out` are both located in `R`, so they must be
+        // located in the same contiguous memory segment.
+        unsafe { Self::try_emplace_new(ptr, out) }
+    }
+
     /// # Safety
     ///
     /// The memory pointed to by `ptr` and `slot` must be located in the same
     /// contiguous memory segment.
-    unsafe fn emplace_new(ptr: *mut T, mut out: Slot<'_, Self>) {
+    unsafe fn emplace_new(ptr: *mut T, out: Slot<'_, Self>) {
+        // SAFETY: The caller has guaranteed that `ptr` and `out` are located
+        // in the same contiguous memory segment.
+        unsafe { Self::try_emplace_new(ptr, out).unwrap() }
+    }
+
+    /// # Safety
+    ///
+    /// The memory pointed to by `ptr` and `slot` must be located in the same
+    /// contiguous memory segment.
+    unsafe fn try_emplace_new(
+        ptr: *mut T,
+        mut out: Slot<'_, Self>,
+    ) -> Result<(), RelPtrError<T, B, BM>> {
         let base = out.as_ptr().cast();
 
         // SAFETY:
@@ -202,9 +286,14 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
         // - The size of a `u8` is 1, so the distance between the base and
         //   target is always a multiple of it.
         let offset = unsafe { ptr.cast::<u8>().offset_from(base) };
-        let offset = B::from_native_isize(offset).unwrap();
+        // Non-negative offsets are biased by one so that an offset of `0` can
+        // be used to represent null.
+        let stored = if offset >= 0 { offset + 1 } else { offset };
+        let offset =
+            B::from_native_isize(stored).map_err(RelPtrError::Offset)?;
 
-        let metadata = T::from_native_metadata(metadata(ptr)).unwrap();
+        let metadata = T::from_native_metadata(metadata(ptr))
+            .map_err(RelPtrError::Metadata)?;
 
         munge!(
             let RelPtr {
@@ -215,6 +304,8 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
         );
         out_offset.write(offset);
         out_metadata.write(MaybeUninit::new(metadata));
+
+        Ok(())
     }
 
     fn emplace_null(mut slot: Slot<'_, Self>) {
@@ -230,14 +321,184 @@ impl<T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelPtr<T, R, B> {
     }
 }
 
-// SAFETY: Values of type `RelPtr<T, R, B>` can only be created in `R` and may
-// only be moved within `R`. Therefore, all values of the type must be located
-// in `R`.
-unsafe impl<T, R, B> Pinned<R> for RelPtr<T, R, B>
+/// An error that occurred while emplacing a `RelPtr`.
+pub enum RelPtrError<T, B, BM = B>
+where
+    T: BasisPointee<BM> + ?Sized,
+    B: Basis,
+    BM: Basis,
+{
+    /// The offset from the relative pointer to its target could not be
+    /// represented in the chosen basis.
+    Offset(B::FromNativeError),
+    /// The metadata of the target could not be represented in the chosen
+    /// basis.
+    Metadata(<T as BasisPointee<BM>>::FromNativeError),
+}
+
+impl<T, B, BM> fmt::Debug for RelPtrError<T, B, BM>
+where
+    T: BasisPointee<BM> + ?Sized,
+    B: Basis,
+    BM: Basis,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Offset(e) => f.debug_tuple("Offset").field(e).finish(),
+            Self::Metadata(e) => f.debug_tuple("Metadata").field(e).finish(),
+        }
+    }
+}
+
+impl<T, B, BM> fmt::Display for RelPtrError<T, B, BM>
+where
+    T: BasisPointee<BM> + ?Sized,
+    B: Basis,
+    BM: Basis,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Offset(_) => f.write_str(
+                "the offset to the relative pointer's target could not be \
+                represented in the chosen basis",
+            ),
+            Self::Metadata(_) => f.write_str(
+                "the metadata of the relative pointer's target could not be \
+                represented in the chosen basis",
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, B, BM> ::std::error::Error for RelPtrError<T, B, BM>
+where
+    T: BasisPointee<BM> + ?Sized,
+    B: Basis,
+    BM: Basis,
+{
+}
+
+#[cfg(feature = "std")]
+::std::thread_local! {
+    static DEBUG_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Sets the number of levels of relative pointers that `DebugRaw` follows on
+/// this thread, and restores the previous number when dropped.
+#[cfg(feature = "std")]
+struct DebugDepthGuard {
+    previous: usize,
+}
+
+#[cfg(feature = "std")]
+impl DebugDepthGuard {
+    fn set(depth: usize) -> Self {
+        Self {
+            previous: DEBUG_DEPTH.with(|d| d.replace(depth)),
+        }
+    }
+
+    fn descend() -> Option<Self> {
+        match DEBUG_DEPTH.with(Cell::get) {
+            0 => None,
+            depth => Some(Self::set(depth - 1)),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for DebugDepthGuard {
+    fn drop(&mut self) {
+        DEBUG_DEPTH.with(|d| d.set(self.previous));
+    }
+}
+
+/// Formats a value with `DebugRaw`, including the targets of the relative
+/// pointers in it.
+///
+/// Relative pointers may point to uninitialized memory (e.g. the unused
+/// capacity of a `RelVec`), so by default they only format their offset and
+/// the address of their target. While a `DebugTargets` is formatted, relative
+/// pointers up to `depth` levels deep also format their targets. Relative
+/// pointers past that depth fall back to formatting their offset and address,
+/// so formatting cyclic structures still terminates.
+#[cfg(feature = "std")]
+pub struct DebugTargets<'a, T: ?Sized> {
+    value: Ref<'a, T>,
+    depth: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: ?Sized> DebugTargets<'a, T> {
+    /// Returns a new `DebugTargets` which formats `value` and follows up to
+    /// `depth` levels of relative pointers.
+    ///
+    /// # Safety
+    ///
+    /// Every non-null relative pointer followed while formatting `value` must
+    /// point to a valid value for as long as the `DebugTargets` exists.
+    #[inline]
+    pub unsafe fn new(value: Ref<'a, T>, depth: usize) -> Self {
+        Self { value, depth }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: DebugRaw + ?Sized> fmt::Debug for DebugTargets<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let _guard = DebugDepthGuard::set(self.depth);
+        DebugRaw::fmt_raw(self.value, f)
+    }
+}
+
+// The target of a relative pointer is only formatted while a `DebugTargets` is
+// being formatted on this thread, since relative pointers may point to
+// uninitialized memory. See `DebugTargets` for details.
+impl<T, R, B, BM> DebugRaw for RelPtr<T, R, B, BM>
 where
-    T: BasisPointee<B> + Pointee + ?Sized,
+    T: BasisPointee<BM> + DebugRaw + ?Sized,
     R: Region,
     B: Basis,
+    BM: Basis,
+{
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        if let Some(target) = RelPtr::as_ptr(this) {
+            #[cfg(feature = "std")]
+            if let Some(_guard) = DebugDepthGuard::descend() {
+                // SAFETY: The depth is only nonzero while a `DebugTargets` is
+                // being formatted, and the caller of `DebugTargets::new` has
+                // guaranteed that every relative pointer followed while
+                // formatting it points to a valid value.
+                let target = unsafe { Ref::new_unchecked(target) };
+                return f
+                    .debug_struct("RelPtr")
+                    .field("offset", &this.offset())
+                    .field("target", &target)
+                    .finish();
+            }
+            f.debug_struct("RelPtr")
+                .field("offset", &this.offset())
+                .field("target", &target)
+                .finish()
+        } else {
+            f.write_str("RelPtr(null)")
+        }
+    }
+}
+
+// SAFETY: Values of type `RelPtr<T, R, B, BM>` can only be created in `R` and
+// may only be moved within `R`. Therefore, all values of the type must be
+// located in `R`.
+unsafe impl<T, R, B, BM> Pinned<R> for RelPtr<T, R, B, BM>
+where
+    T: BasisPointee<BM> + Pointee + ?Sized,
+    R: Region,
+    B: Basis,
+    BM: Basis,
 {
 }
 
@@ -245,17 +506,18 @@ where
 // - `RelPtr` is `Sized` and always has metadata `()`, so `emplaced_meta` always
 //   returns valid metadata for it.
 // - `emplace_unsized_unchecked` initializes its `out` parameter.
-unsafe impl<T, R, B> Emplace<RelPtr<T, R, B>, R> for In<*mut T, R>
+unsafe impl<T, R, B, BM> Emplace<RelPtr<T, R, B, BM>, R> for In<*mut T, R>
 where
-    T: BasisPointee<B> + Pointee + ?Sized,
+    T: BasisPointee<BM> + Pointee + ?Sized,
     R: Region,
     B: Basis,
+    BM: Basis,
 {
-    fn emplaced_meta(&self) -> <RelPtr<T, R, B> as Pointee>::Metadata {}
+    fn emplaced_meta(&self) -> <RelPtr<T, R, B, BM> as Pointee>::Metadata {}
 
     unsafe fn emplace_unsized_unchecked(
         self,
-        out: In<Slot<'_, RelPtr<T, R, B>>, R>,
+        out: In<Slot<'_, RelPtr<T, R, B, BM>>, R>,
     ) {
         let ptr = In::into_inner(self);
 
@@ -274,28 +536,30 @@ pub struct Null;
 // - `RelPtr` is `Sized` and always has metadata `()`, so `emplaced_meta` always
 //   returns valid metadata for it.
 // - `emplace_unsized_unchecked` initializes its `out` parameter.
-unsafe impl<T, R, B> Emplace<RelPtr<T, R, B>, R> for Null
+unsafe impl<T, R, B, BM> Emplace<RelPtr<T, R, B, BM>, R> for Null
 where
-    T: BasisPointee<B> + ?Sized,
+    T: BasisPointee<BM> + ?Sized,
     R: Region,
     B: Basis,
+    BM: Basis,
 {
-    fn emplaced_meta(&self) -> <RelPtr<T, R, B> as Pointee>::Metadata {}
+    fn emplaced_meta(&self) -> <RelPtr<T, R, B, BM> as Pointee>::Metadata {}
 
     unsafe fn emplace_unsized_unchecked(
         self,
-        out: In<Slot<'_, RelPtr<T, R, B>>, R>,
+        out: In<Slot<'_, RelPtr<T, R, B, BM>>, R>,
     ) {
         RelPtr::emplace_null(In::into_inner(out));
     }
 }
 
 // SAFETY: `move_unsized_unchecked` initializes `out` by emplacing into it.
-unsafe impl<T, R, B> Move<R> for RelPtr<T, R, B>
+unsafe impl<T, R, B, BM> Move<R> for RelPtr<T, R, B, BM>
 where
-    T: BasisPointee<B> + ?Sized,
+    T: BasisPointee<BM> + ?Sized,
     R: Region,
     B: Basis,
+    BM: Basis,
 {
     unsafe fn move_unsized_unchecked(
         this: In<Val<'_, Self>, R>,
@@ -313,3 +577,176 @@ where
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::{
+        fmt,
+        mem::MaybeUninit,
+        ptr::{addr_of, addr_of_mut},
+    };
+    use ::mischief::{In, Region, Slot};
+    use ::munge::munge;
+    use ::situ::{fmt::DebugRaw, Ref};
+    use ::std::{format, string::ToString};
+
+    use crate::{
+        rel_ptr::{DebugTargets, Null, RelPtrError},
+        Basis,
+        DefaultBasis,
+        EmplaceExt,
+        RelPtr,
+    };
+
+    struct Nodes;
+
+    // SAFETY: `Nodes` is only used to locate values in a single `Pair`.
+    unsafe impl Region for Nodes {}
+
+    struct Node {
+        value: u32,
+        next: RelPtr<Node, Nodes, DefaultBasis>,
+    }
+
+    impl DebugRaw for Node {
+        fn fmt_raw(
+            this: Ref<'_, Self>,
+            f: &mut fmt::Formatter<'_>,
+        ) -> Result<(), fmt::Error> {
+            // SAFETY: `next` is a field of a valid `Node`.
+            let next = unsafe { Ref::new_unchecked(&this.next) };
+            f.debug_struct("Node")
+                .field("value", &this.value)
+                .field("next", &next)
+                .finish()
+        }
+    }
+
+    #[repr(C)]
+    struct Pair {
+        a: Node,
+        b: Node,
+    }
+
+    /// Emplaces a pair of nodes where `a` points to `b`, and `b` points back to
+    /// `a` if `cyclic` is true.
+    fn pair(out: &mut MaybeUninit<Pair>, cyclic: bool) -> &Pair {
+        let mut slot = Slot::new(out);
+        let ptr = slot.as_ptr();
+        // SAFETY: `a` and `b` are both fields of `out`.
+        let (a, b) = unsafe {
+            (
+                In::<_, Nodes>::new_unchecked(addr_of_mut!((*ptr).a)),
+                In::<_, Nodes>::new_unchecked(addr_of_mut!((*ptr).b)),
+            )
+        };
+        munge!(let Pair { a: a_slot, b: b_slot } = slot.as_mut());
+        munge!(let Node { value: mut a_value, next: a_next } = a_slot);
+        munge!(let Node { value: mut b_value, next: b_next } = b_slot);
+        a_value.write(1);
+        b_value.write(2);
+        // SAFETY: Both nodes are located in the `Pair` that `Nodes` locates.
+        unsafe {
+            b.emplace(In::new_unchecked(a_next));
+            if cyclic {
+                a.emplace(In::new_unchecked(b_next));
+            } else {
+                Null.emplace(In::new_unchecked(b_next));
+            }
+        }
+        // SAFETY: Every field of `out` was just initialized.
+        unsafe { out.assume_init_ref() }
+    }
+
+    #[test]
+    fn error_display() {
+        // Offsets only fail to convert if the basis is narrower than a pointer.
+        if let Err(e) = DefaultBasis::from_native_isize(isize::MAX) {
+            let error = RelPtrError::<u8, DefaultBasis>::Offset(e);
+            assert_eq!(
+                error.to_string(),
+                "the offset to the relative pointer's target could not be \
+                represented in the chosen basis",
+            );
+        }
+    }
+
+    #[test]
+    fn to_self() {
+        let mut out = MaybeUninit::<RelPtr<u8, Nodes, DefaultBasis>>::uninit();
+        let slot = Slot::new(&mut out);
+        let base = slot.as_ptr().cast::<u8>();
+        // SAFETY: The relative pointer and its target are both located in
+        // `out`.
+        unsafe {
+            In::<_, Nodes>::new_unchecked(base)
+                .emplace(In::new_unchecked(slot));
+        }
+        // SAFETY: `out` was just initialized.
+        let ptr = unsafe { out.assume_init_ref() };
+        assert!(!ptr.is_null());
+        assert_eq!(ptr.offset(), 0);
+        // SAFETY: `ptr` is a valid `RelPtr` which is never mutated.
+        let ptr = unsafe { Ref::new_unchecked(ptr) };
+        assert_eq!(RelPtr::as_ptr(ptr), Some(base.cast_const()));
+    }
+
+    #[test]
+    fn debug_targets() {
+        let mut out = MaybeUninit::uninit();
+        let pair = pair(&mut out, false);
+        // SAFETY: `pair.a` is a valid `Node`.
+        let a = unsafe { Ref::new_unchecked(&pair.a) };
+        let offset = pair.a.next.offset();
+
+        // Without a `DebugTargets`, only the offset and address are printed.
+        assert_eq!(
+            format!("{:?}", a),
+            format!(
+                "Node {{ value: 1, next: RelPtr {{ offset: {offset}, target: \
+                {:?} }} }}",
+                addr_of!(pair.b),
+            ),
+        );
+
+        // SAFETY: Every relative pointer in `pair` points to a valid `Node`.
+        let debug = unsafe { DebugTargets::new(a, 8) };
+        assert_eq!(
+            format!("{:?}", debug),
+            format!(
+                "Node {{ value: 1, next: RelPtr {{ offset: {offset}, target: \
+                Node {{ value: 2, next: RelPtr(null) }} }} }}",
+            ),
+        );
+    }
+
+    #[test]
+    fn debug_targets_depth() {
+        let mut out = MaybeUninit::uninit();
+        let pair = pair(&mut out, true);
+        // SAFETY: `pair.a` is a valid `Node`.
+        let a = unsafe { Ref::new_unchecked(&pair.a) };
+        let a_offset = pair.a.next.offset();
+        let b_offset = pair.b.next.offset();
+
+        // The nodes point to each other, so only the first relative pointer is
+        // followed before falling back to the offset and address.
+        // SAFETY: Every relative pointer in `pair` points to a valid `Node`.
+        let debug = unsafe { DebugTargets::new(a, 1) };
+        assert_eq!(
+            format!("{:?}", debug),
+            format!(
+                "Node {{ value: 1, next: RelPtr {{ offset: {a_offset}, \
+                target: Node {{ value: 2, next: RelPtr {{ offset: \
+                {b_offset}, target: {:?} }} }} }} }}",
+                addr_of!(pair.a),
+            ),
+        );
+
+        // The depth is reset once the `DebugTargets` is done formatting.
+        assert!(format!("{:?}", a).ends_with(&format!(
+            "target: {:?} }} }}",
+            addr_of!(pair.b),
+        )));
+    }
+}
@@ -44,6 +44,15 @@ impl<'a, T: BasisPointee<B> + ?Sized, R: Region, B: Basis> RelRef<'a, T, R, B> {
         // references, and is guaranteed to point to an initialized value.
         unsafe { RelPtr::as_ref(inner) }
     }
+
+    /// Returns a pointer to the underlying value if it is not null, computing
+    /// its address with wrapping arithmetic.
+    ///
+    /// This can be used on `RelRef`s that have not been validated yet.
+    pub fn as_ptr_wrapping(this: Ref<'_, Self>) -> Option<*const T> {
+        munge!(let RelRef { inner, .. } = this);
+        RelPtr::as_ptr_wrapping(inner)
+    }
 }
 
 // SAFETY:
@@ -3,9 +3,17 @@
 use ::mischief::{In, Region, Slot};
 use ::munge::munge;
 use ::ptr_meta::Pointee;
-use ::situ::DropRaw;
+#[cfg(feature = "serde")]
+use ::serde::{ser::SerializeTuple, Serializer};
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
+#[cfg(any(feature = "alloc", feature = "serde"))]
+use ::situ::Ref;
+use ::situ::{CloneRaw, DropRaw};
 
-use crate::{Emplace, EmplaceExt, Move, Portable};
+#[cfg(feature = "alloc")]
+use crate::ToNative;
+use crate::{Emplace, EmplaceExt, Move, Portable, Validate};
 
 macro_rules! define_tuple {
     (
@@ -15,7 +23,7 @@ macro_rules! define_tuple {
         $($indices:tt,)*
     ) => {
         #[doc = concat!("A relative ", stringify!($n), "-tuple")]
-        #[derive(DropRaw, Move, Portable)]
+        #[derive(CloneRaw, DropRaw, Move, Portable, Validate)]
         #[rel_core = "crate"]
         #[repr(C)]
         pub struct $ident<$($types),*>($($types),*);
@@ -48,6 +56,34 @@ macro_rules! define_tuple {
                 )*
             }
         }
+
+        #[cfg(feature = "alloc")]
+        impl<$($types: ToNative),*> ToNative for $ident<$($types),*> {
+            type Native = ($($types::Native,)*);
+
+            #[allow(non_snake_case)]
+            fn to_native(this: Ref<'_, Self>) -> Self::Native {
+                munge!(let $ident($($types,)*) = this);
+                ($(ToNative::to_native($types),)*)
+            }
+        }
+
+        // Relative tuples serialize like the native tuples they correspond to.
+        #[cfg(feature = "serde")]
+        impl<$($types: SerializeRaw),*> SerializeRaw for $ident<$($types),*> {
+            #[allow(non_snake_case)]
+            fn serialize_raw<S: Serializer>(
+                this: Ref<'_, Self>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                munge!(let $ident($($types,)*) = this);
+                let mut tuple = serializer.serialize_tuple($n)?;
+                $(
+                    tuple.serialize_element(&$types)?;
+                )*
+                tuple.end()
+            }
+        }
     }
 }
 
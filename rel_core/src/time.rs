@@ -0,0 +1,198 @@
+//! Relative versions of time types.
+
+use ::core::{fmt, ptr::addr_of, time::Duration};
+use ::mischief::{In, Region, Slot};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+#[cfg(feature = "serde")]
+use ::serde::{ser::SerializeStruct, Serializer};
+#[cfg(feature = "serde")]
+use ::situ::ser::SerializeRaw;
+use ::situ::{fmt::DebugRaw, CloneRaw, DropRaw, Ref};
+
+#[cfg(feature = "alloc")]
+use crate::ToNative;
+use crate::{
+    Emplace,
+    EmplaceExt,
+    Move,
+    Portable,
+    Validate,
+    ValidateError,
+    Validator,
+    U32,
+    U64,
+};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// A relative counterpart to `Duration`.
+///
+/// Like `Duration`, a `RelDuration` is made up of a whole number of seconds
+/// and a number of nanoseconds which is always less than one second.
+#[derive(CloneRaw, DropRaw, Move, Portable)]
+#[rel_core = "crate"]
+#[repr(C)]
+pub struct RelDuration {
+    secs: U64,
+    nanos: U32,
+}
+
+impl RelDuration {
+    /// Returns the number of whole seconds in the duration.
+    #[inline]
+    pub fn as_secs(&self) -> u64 {
+        self.secs.to_ne()
+    }
+
+    /// Returns the fractional part of the duration in nanoseconds.
+    #[inline]
+    pub fn subsec_nanos(&self) -> u32 {
+        self.nanos.to_ne()
+    }
+
+    /// Returns the `Duration` that this is a relative version of.
+    ///
+    /// Durations which haven't been validated may have nanoseconds which are
+    /// out of range. These are clamped to just under one second.
+    #[inline]
+    pub fn to_duration(&self) -> Duration {
+        Duration::new(
+            self.as_secs(),
+            self.subsec_nanos().min(NANOS_PER_SEC - 1),
+        )
+    }
+}
+
+// SAFETY: `validate` checks that the nanoseconds are less than one second.
+// Every bit pattern is valid for the seconds and nanoseconds themselves.
+unsafe impl Validate for RelDuration {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        _: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is non-null, properly
+        // aligned, and valid for reads, so its `nanos` field is as well. Every
+        // bit pattern is a valid `U32`.
+        let nanos = unsafe { addr_of!((*ptr).nanos).read() }.to_ne();
+        if nanos >= NANOS_PER_SEC {
+            Err(ValidateError::InvalidNanos(nanos))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// SAFETY:
+// - `emplaced_meta` returns `()`, the only valid metadata for `Sized` types.
+// - `emplace_unsized_unchecked` initializes its `out` parameter by emplacing to
+//   each of its fields.
+unsafe impl<R: Region> Emplace<RelDuration, R> for Duration {
+    #[inline]
+    fn emplaced_meta(&self) -> <RelDuration as Pointee>::Metadata {}
+
+    #[inline]
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelDuration>, R>,
+    ) {
+        munge!(let RelDuration { secs, nanos } = out);
+        self.as_secs().emplace(secs);
+        self.subsec_nanos().emplace(nanos);
+    }
+}
+
+impl DebugRaw for RelDuration {
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        fmt::Debug::fmt(&this.to_duration(), f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ToNative for RelDuration {
+    type Native = Duration;
+
+    #[inline]
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        this.to_duration()
+    }
+}
+
+// Relative durations serialize like the native durations they correspond to.
+#[cfg(feature = "serde")]
+impl SerializeRaw for RelDuration {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Duration", 2)?;
+        state.serialize_field("secs", &this.as_secs())?;
+        state.serialize_field("nanos", &this.subsec_nanos())?;
+        state.end()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::{alloc::Layout, mem::MaybeUninit, time::Duration};
+    use ::std::format;
+
+    use super::RelDuration;
+    use crate::{
+        tests::emplace,
+        ToNative,
+        Validate,
+        ValidateError,
+        Validator,
+        U32,
+    };
+
+    /// A validator with an empty buffer, which rejects every claim.
+    struct NoClaims;
+
+    // SAFETY: `claim` never returns `Ok`.
+    unsafe impl Validator for NoClaims {
+        fn base(&self) -> *const u8 {
+            ::core::ptr::null()
+        }
+
+        #[allow(clippy::as_conversions)]
+        fn claim(
+            &mut self,
+            ptr: *const u8,
+            layout: Layout,
+        ) -> Result<(), ValidateError> {
+            Err(ValidateError::OutOfBounds {
+                address: ptr as usize,
+                size: layout.size(),
+            })
+        }
+    }
+
+    #[test]
+    fn rel_duration() {
+        let duration = Duration::new(3, 500_000_000);
+        let mut out = MaybeUninit::uninit();
+        let mut rel = emplace::<RelDuration, _>(duration, &mut out);
+        assert_eq!(rel.as_secs(), 3);
+        assert_eq!(rel.subsec_nanos(), 500_000_000);
+        assert_eq!(rel.to_duration(), duration);
+        assert_eq!(ToNative::to_native(rel.as_ref()), duration);
+        assert_eq!(format!("{:?}", rel), "3.5s");
+        let ptr = rel.as_ptr();
+        // SAFETY: `ptr` is non-null, properly aligned, and valid for reads.
+        let result = unsafe { RelDuration::validate(ptr, &mut NoClaims) };
+        assert_eq!(result, Ok(()));
+
+        rel.nanos = U32::from_ne(1_000_000_000);
+        // SAFETY: `ptr` is non-null, properly aligned, and valid for reads.
+        let result = unsafe { RelDuration::validate(ptr, &mut NoClaims) };
+        assert_eq!(result, Err(ValidateError::InvalidNanos(1_000_000_000)));
+        // Unvalidated nanoseconds are clamped to just under one second.
+        assert_eq!(rel.to_duration(), Duration::new(3, 999_999_999));
+    }
+}
@@ -0,0 +1,90 @@
+use ::core::{
+    array,
+    marker::{PhantomData, PhantomPinned},
+};
+pub use ::rel_core_derive::ToNative;
+use ::situ::{ops::IndexRaw, Ref};
+
+use crate::{Char, F32, F64, I128, I16, I32, I64, U128, U16, U32, U64};
+
+/// A type that can be converted back into a native owned value.
+pub trait ToNative {
+    /// The native type that this type converts into.
+    type Native;
+
+    /// Converts the referenced value into a native owned value.
+    fn to_native(this: Ref<'_, Self>) -> Self::Native;
+}
+
+macro_rules! impl_copy {
+    ($($ty:ty),*) => {
+        $(
+            impl ToNative for $ty {
+                type Native = $ty;
+
+                #[inline]
+                fn to_native(this: Ref<'_, Self>) -> Self::Native {
+                    *this
+                }
+            }
+        )*
+    };
+}
+
+impl_copy!(i8, u8, bool, ());
+
+macro_rules! impl_portable {
+    ($($portable:ty => $native:ty),*) => {
+        $(
+            impl ToNative for $portable {
+                type Native = $native;
+
+                #[inline]
+                fn to_native(this: Ref<'_, Self>) -> Self::Native {
+                    this.to_ne()
+                }
+            }
+        )*
+    };
+}
+
+impl_portable!(
+    I16 => i16,
+    I32 => i32,
+    I64 => i64,
+    I128 => i128,
+    U16 => u16,
+    U32 => u32,
+    U64 => u64,
+    U128 => u128,
+    F32 => f32,
+    F64 => f64,
+    Char => char
+);
+
+impl<T: ToNative, const N: usize> ToNative for [T; N] {
+    type Native = [T::Native; N];
+
+    #[inline]
+    fn to_native(this: Ref<'_, Self>) -> Self::Native {
+        array::from_fn(|i| T::to_native(IndexRaw::index_raw(this, i)))
+    }
+}
+
+impl<T: ?Sized> ToNative for PhantomData<T> {
+    type Native = PhantomData<T>;
+
+    #[inline]
+    fn to_native(_: Ref<'_, Self>) -> Self::Native {
+        PhantomData
+    }
+}
+
+impl ToNative for PhantomPinned {
+    type Native = PhantomPinned;
+
+    #[inline]
+    fn to_native(_: Ref<'_, Self>) -> Self::Native {
+        PhantomPinned
+    }
+}
@@ -0,0 +1,158 @@
+use ::core::{
+    cell::Cell,
+    marker::{PhantomData, PhantomPinned},
+    mem::MaybeUninit,
+};
+
+use crate::{
+    is_valid_bool,
+    Char,
+    Validate,
+    ValidateError,
+    Validator,
+    F32,
+    F64,
+    I128,
+    I16,
+    I32,
+    I64,
+    U128,
+    U16,
+    U32,
+    U64,
+};
+
+macro_rules! impl_always_valid {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: Every bit pattern is a valid value of `$ty`, and it does
+            // not contain any pointers.
+            unsafe impl Validate for $ty {
+                #[inline]
+                unsafe fn validate<V: Validator + ?Sized>(
+                    _: *const Self,
+                    _: &mut V,
+                ) -> Result<(), ValidateError> {
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_always_valid!(
+    i8,
+    u8,
+    (),
+    I16,
+    I32,
+    I64,
+    I128,
+    U16,
+    U32,
+    U64,
+    U128,
+    F32,
+    F64,
+    PhantomPinned
+);
+
+// SAFETY: `PhantomData` is a zero-sized type, so every pointer to it points to
+// a valid value.
+unsafe impl<T: ?Sized> Validate for PhantomData<T> {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        _: *const Self,
+        _: &mut V,
+    ) -> Result<(), ValidateError> {
+        Ok(())
+    }
+}
+
+// SAFETY: `MaybeUninit` may contain any bytes, including uninitialized ones.
+unsafe impl<T> Validate for MaybeUninit<T> {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        _: *const Self,
+        _: &mut V,
+    ) -> Result<(), ValidateError> {
+        Ok(())
+    }
+}
+
+// SAFETY: `bool` is only valid if its byte is `0` or `1`, which is checked.
+unsafe impl Validate for bool {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        _: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is valid for reads of
+        // one byte, and every bit pattern is a valid `u8`.
+        let value = unsafe { ptr.cast::<u8>().read() };
+        if !is_valid_bool(value) {
+            Err(ValidateError::InvalidBool(value))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// SAFETY: `Char` is only valid if it contains a Unicode scalar value, which is
+// checked.
+unsafe impl Validate for Char {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        _: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: The caller has guaranteed that `ptr` is properly aligned and
+        // valid for reads. `Char` only contains a `U32`, so reading it is
+        // sound even if it does not hold a valid `char`.
+        let value = unsafe { ptr.read() };
+        if value.try_to_ne().is_none() {
+            // SAFETY: `Char` is `repr(transparent)` over a `U32`, and the
+            // caller has guaranteed that `ptr` is properly aligned and valid
+            // for reads.
+            let int = unsafe { ptr.cast::<U32>().read() }.to_ne();
+            Err(ValidateError::InvalidChar(int))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// SAFETY: `Cell<T>` has the same in-memory representation as `T`, so it is
+// valid whenever its inner value is valid.
+unsafe impl<T: Validate> Validate for Cell<T> {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        // SAFETY: `Cell<T>` has the same in-memory representation as `T`, so
+        // `ptr` is also non-null, properly aligned, and valid for reads of
+        // `T`.
+        unsafe { T::validate(ptr.cast::<T>(), validator) }
+    }
+}
+
+// SAFETY: Arrays are valid when each of their elements is valid, which is
+// checked.
+unsafe impl<T: Validate, const N: usize> Validate for [T; N] {
+    #[inline]
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        let ptr = ptr.cast::<T>();
+        for i in 0..N {
+            // SAFETY: The caller has guaranteed that `ptr` is valid for reads
+            // of the whole array, and `i` is less than its length.
+            unsafe {
+                T::validate(ptr.add(i), validator)?;
+            }
+        }
+        Ok(())
+    }
+}
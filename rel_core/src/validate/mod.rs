@@ -0,0 +1,263 @@
+mod impls;
+
+use ::core::{alloc::Layout, fmt};
+pub use ::rel_core_derive::Validate;
+
+/// An error that occurred while validating a value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ValidateError {
+    /// Some memory was not located entirely within the buffer being validated.
+    OutOfBounds {
+        /// The address of the start of the memory.
+        address: usize,
+        /// The size of the memory.
+        size: usize,
+    },
+    /// Some memory was not properly aligned for the type located in it.
+    Misaligned {
+        /// The address of the start of the memory.
+        address: usize,
+        /// The required alignment of the memory.
+        align: usize,
+    },
+    /// Some memory overlapped memory which was already claimed by another
+    /// value.
+    Overlapping {
+        /// The address of the start of the memory.
+        address: usize,
+        /// The size of the memory.
+        size: usize,
+    },
+    /// The layout of some memory could not be computed because it was too
+    /// large.
+    LayoutOverflow,
+    /// A pointer which must not be null was null.
+    NullPointer,
+    /// A `bool` had a value other than `0` or `1`.
+    InvalidBool(u8),
+    /// A `Char` was not a valid Unicode scalar value.
+    InvalidChar(u32),
+    /// An enum had a discriminant which did not correspond to any of its
+    /// variants.
+    InvalidDiscriminant(u8),
+    /// A length was greater than the capacity it was paired with.
+    InvalidLength {
+        /// The length.
+        len: usize,
+        /// The capacity.
+        cap: usize,
+    },
+    /// A string contained invalid UTF-8.
+    InvalidUtf8 {
+        /// The index of the first byte which was not valid UTF-8.
+        valid_up_to: usize,
+    },
+    /// A duration had a number of nanoseconds which was not less than one
+    /// second.
+    InvalidNanos(u32),
+    /// A bit set was stored in a different number of bytes than its length
+    /// requires.
+    InvalidBitSetBytes {
+        /// The number of bits in the set.
+        len: usize,
+        /// The number of bytes the set was stored in.
+        bytes: usize,
+    },
+    /// A bit set had bits set past its length.
+    InvalidBitSetPadding {
+        /// The number of bits in the set.
+        len: usize,
+    },
+}
+
+impl fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { address, size } => write!(
+                f,
+                "{size} bytes at address {address:#x} are out of bounds",
+            ),
+            Self::Misaligned { address, align } => write!(
+                f,
+                "address {address:#x} is not aligned to {align} bytes",
+            ),
+            Self::Overlapping { address, size } => write!(
+                f,
+                "{size} bytes at address {address:#x} overlap memory which was \
+                already claimed",
+            ),
+            Self::LayoutOverflow => f.write_str("layout size overflowed"),
+            Self::NullPointer => f.write_str("pointer was unexpectedly null"),
+            Self::InvalidBool(value) => {
+                write!(f, "invalid bool value {value:#04x}")
+            }
+            Self::InvalidChar(value) => {
+                write!(f, "invalid char value {value:#x}")
+            }
+            Self::InvalidDiscriminant(value) => {
+                write!(f, "invalid enum discriminant {value}")
+            }
+            Self::InvalidLength { len, cap } => {
+                write!(f, "length {len} is greater than capacity {cap}")
+            }
+            Self::InvalidUtf8 { valid_up_to } => {
+                write!(f, "invalid UTF-8 after the first {valid_up_to} bytes",)
+            }
+            Self::InvalidNanos(nanos) => {
+                write!(f, "duration nanoseconds {nanos} exceed one second")
+            }
+            Self::InvalidBitSetBytes { len, bytes } => {
+                write!(f, "bit set of {len} bits is stored in {bytes} bytes")
+            }
+            Self::InvalidBitSetPadding { len } => {
+                write!(f, "bit set of {len} bits has bits set past its end")
+            }
+        }
+    }
+}
+
+/// A context that tracks which memory has been claimed during validation.
+///
+/// # Safety
+///
+/// `base` must return a pointer to the start of the buffer being validated, and
+/// `claim` must only return `Ok` if the claimed memory is:
+/// - located entirely within the buffer being validated,
+/// - properly aligned for `layout`,
+/// - initialized and valid for reads, and
+/// - not overlapping any other memory that was previously claimed.
+///
+/// Zero-sized claims only need to be non-null and properly aligned.
+pub unsafe trait Validator {
+    /// Returns a pointer to the start of the buffer being validated.
+    fn base(&self) -> *const u8;
+
+    /// Claims the memory described by `layout` starting at `ptr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memory is out of bounds, misaligned, or overlaps
+    /// memory which was previously claimed.
+    fn claim(
+        &mut self,
+        ptr: *const u8,
+        layout: Layout,
+    ) -> Result<(), ValidateError>;
+}
+
+/// A type whose values can be checked for validity before they are accessed.
+///
+/// # Safety
+///
+/// `validate` must only return `Ok` if `ptr` points to a valid value of
+/// `Self`, and every pointer reachable from it points to memory which was
+/// claimed from the validator.
+pub unsafe trait Validate {
+    /// Validates the value pointed to by `ptr`.
+    ///
+    /// Only the pointees of `ptr` should be claimed from `validator`. The
+    /// memory that `ptr` points to must already have been claimed.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, properly aligned, and valid for reads of
+    /// `size_of::<Self>()` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value pointed to by `ptr` is not a valid value
+    /// of `Self`.
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError>;
+}
+
+/// Returns whether the given byte is a valid `bool`.
+///
+/// Only `0` (`false`) and `1` (`true`) are valid. Reading any other byte as a
+/// `bool` is undefined behavior.
+#[inline]
+pub const fn is_valid_bool(byte: u8) -> bool {
+    byte <= 1
+}
+
+/// Claims and validates the value located at `ptr`.
+///
+/// `ptr` may point to any address. It is only read from after it has been
+/// claimed from `validator`.
+///
+/// # Errors
+///
+/// Returns an error if the memory for the value could not be claimed or the
+/// value is not valid.
+// `ptr` is only read from after `validator` has successfully claimed it.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn validate_ptr<T, V>(
+    ptr: *const T,
+    validator: &mut V,
+) -> Result<(), ValidateError>
+where
+    T: Validate,
+    V: Validator + ?Sized,
+{
+    validator.claim(ptr.cast(), Layout::new::<T>())?;
+    // SAFETY: `claim` succeeded, so `ptr` is non-null, properly aligned, and
+    // valid for reads of `size_of::<T>()` bytes.
+    unsafe { T::validate(ptr, validator) }
+}
+
+/// Claims memory for `cap` elements starting at `ptr` and validates the first
+/// `len` of them.
+///
+/// `ptr` may point to any address. It is only read from after it has been
+/// claimed from `validator`.
+///
+/// # Errors
+///
+/// Returns an error if `len` is greater than `cap`, the memory for the elements
+/// could not be claimed, or any of the first `len` elements is not valid.
+// `ptr` is only read from after `validator` has successfully claimed it.
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn validate_slice<T, V>(
+    ptr: *const T,
+    len: usize,
+    cap: usize,
+    validator: &mut V,
+) -> Result<(), ValidateError>
+where
+    T: Validate,
+    V: Validator + ?Sized,
+{
+    if len > cap {
+        return Err(ValidateError::InvalidLength { len, cap });
+    }
+
+    let layout =
+        Layout::array::<T>(cap).map_err(|_| ValidateError::LayoutOverflow)?;
+    validator.claim(ptr.cast(), layout)?;
+
+    for i in 0..len {
+        // SAFETY: `claim` succeeded, so `ptr` is valid for reads of `cap`
+        // elements. `i` is less than `len`, which is at most `cap`, so the
+        // offset pointer is in bounds.
+        unsafe {
+            T::validate(ptr.add(i), validator)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::is_valid_bool;
+
+    #[test]
+    fn valid_bool() {
+        assert!(is_valid_bool(0));
+        assert!(is_valid_bool(1));
+        assert!(!is_valid_bool(2));
+        assert!(!is_valid_bool(u8::MAX));
+    }
+}
@@ -12,6 +12,8 @@
 
 mod r#move;
 mod portable;
+mod to_native;
+mod validate;
 
 use ::proc_macro::TokenStream;
 use ::syn::{parse_macro_input, DeriveInput};
@@ -26,10 +28,46 @@ pub fn derive_move(input: TokenStream) -> TokenStream {
 }
 
 /// Derives `Portable` on the annotated type.
-#[proc_macro_derive(Portable, attributes(rel_core))]
+///
+/// Structs and unions must be `repr(C)` or `repr(transparent)`. Enums must be
+/// `repr(i8)`, `repr(u8)`, `repr(C, i8)`, or `repr(C, u8)` so that their
+/// discriminants have the same representation on every platform. Enums with
+/// the default representation are rejected. Every field of the type, including
+/// the fields of enum variants, must also be `Portable`.
+///
+/// With the `bytemuck` feature of `rel_core` enabled, non-generic structs may
+/// also be annotated with `#[bytemuck]` to implement `Zeroable` and `Pod` for
+/// them. This requires every field to be `Pod`, and fails to compile if the
+/// struct contains any padding.
+#[proc_macro_derive(Portable, attributes(bytemuck, rel_core))]
 pub fn derive_portable(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
     portable::derive(derive_input)
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+/// Derives `ToNative` on the annotated type.
+///
+/// The native type must be specified with a `#[native = "..."]` attribute. It
+/// must have the same fields or variants as the annotated type.
+///
+/// `ToNative` bounds are only added for fields whose types are type parameters.
+/// Any other bounds needed to convert the fields, like `T: ToNative` for a
+/// `RelVec<T, A>` field, must be added to the annotated type.
+#[proc_macro_derive(ToNative, attributes(native, rel_core))]
+pub fn derive_to_native(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    to_native::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `Validate` on the annotated type.
+#[proc_macro_derive(Validate, attributes(rel_core))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    validate::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
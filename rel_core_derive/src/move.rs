@@ -26,6 +26,9 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
     }
     let rel_core = rel_core.unwrap_or_else(|| parse_quote! { ::rel_core });
 
+    // Type and const parameters may be declared in any order, so `__R` can be
+    // appended even if the last parameter is a const parameter. Defaults are
+    // left off of the impl generics, so trailing defaults don't matter either.
     let mut move_generics = input.generics.clone();
     move_generics.params.push(parse_quote! {
         __R: #rel_core::export::mischief::Region
@@ -34,11 +37,13 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
     where_clause.predicates.push(parse_quote! {
         Self: #rel_core::export::situ::DropRaw
     });
+    let mut field_tys = Vec::new();
     visit_fields(&input.data, |f| {
         let ty = &f.ty;
         where_clause
             .predicates
             .push(parse_quote! { #ty: #rel_core::Move<__R> });
+        field_tys.push(ty.clone());
     });
 
     let (r#move, util) = match &input.data {
@@ -103,13 +108,21 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
         const _: () = {
             #util
 
-            // SAFETY: `move_unsized_unchecked` initializes its `out` parameter
-            // by destructuring it and moving all of the fields.
+            // SAFETY:
+            // - `move_unsized_unchecked` initializes its `out` parameter by
+            //   destructuring it and moving all of the fields.
+            // - A value can be moved bytewise if all of its fields can be moved
+            //   bytewise.
             #[allow(non_snake_case)]
             unsafe impl #impl_generics #rel_core::Move<__R>
                 for #ty_name #ty_generics
             #where_clause
             {
+                const BYTEWISE: bool = true #(
+                    && <#field_tys as #rel_core::Move<__R>>::BYTEWISE
+                )*;
+
+                #[inline]
                 unsafe fn move_unsized_unchecked(
                     this: #rel_core::export::mischief::In<
                         #rel_core::export::situ::Val<'_, Self>,
@@ -5,17 +5,28 @@ use ::macroix::{
 };
 use ::proc_macro2::{Span, TokenStream};
 use ::quote::quote;
-use ::syn::{parse2, parse_quote, Data, DeriveInput, Error, Path};
+use ::syn::{
+    parse2,
+    parse_quote,
+    Attribute,
+    Data,
+    DeriveInput,
+    Error,
+    Path,
+};
 
 pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
     let mut repr = None;
     let mut rel_core = None;
+    let mut bytemuck = None;
     for attr in input.attrs.iter() {
         if attr.path.is_ident("repr") {
             Repr::merge_attr(&mut repr, attr.tokens.clone())?;
         } else if attr.path.is_ident("rel_core") {
             rel_core =
                 Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+        } else if attr.path.is_ident("bytemuck") {
+            bytemuck = Some(attr);
         }
     }
     let rel_core = rel_core.unwrap_or_else(|| parse_quote! { ::rel_core });
@@ -85,6 +96,11 @@ pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
         }
     }
 
+    let bytemuck_impls = match bytemuck {
+        Some(attr) => derive_bytemuck(&input, attr, &rel_core)?,
+        None => TokenStream::new(),
+    };
+
     let where_clause = input.generics.make_where_clause();
     visit_fields(&input.data, |f| {
         let ty = &f.ty;
@@ -101,5 +117,68 @@ pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
         // fields.
         unsafe impl #impl_generics #rel_core::Portable
             for #ty_name #ty_generics #where_clause {}
+
+        #bytemuck_impls
+    })
+}
+
+fn derive_bytemuck(
+    input: &DeriveInput,
+    attr: &Attribute,
+    rel_core: &Path,
+) -> Result<TokenStream, Error> {
+    if !attr.tokens.is_empty() {
+        return Err(Error::new_spanned(
+            &attr.tokens,
+            "`#[bytemuck]` does not take any arguments",
+        ));
+    }
+
+    let data_struct = match &input.data {
+        Data::Struct(data_struct) => data_struct,
+        _ => {
+            return Err(Error::new_spanned(
+                attr,
+                "`#[bytemuck]` is only supported on structs",
+            ))
+        }
+    };
+
+    // Padding bytes can't be checked for generic structs until they're
+    // monomorphized, so we don't support them.
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`#[bytemuck]` is not supported on generic structs",
+        ));
+    }
+
+    let tys = data_struct.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let ty_name = &input.ident;
+    let bytemuck = quote! { #rel_core::export::bytemuck };
+    Ok(quote! {
+        // SAFETY: This type is `repr(C)` or `repr(transparent)`, and all of
+        // its fields are `Zeroable`. So the all-zeroes bit pattern is valid
+        // for every field, and so for the whole struct.
+        unsafe impl #bytemuck::Zeroable for #ty_name
+        where
+            #(#tys: #bytemuck::Zeroable,)*
+        {}
+
+        // SAFETY: This type is `repr(C)` or `repr(transparent)`, and all of
+        // its fields are `Pod`. Its size is asserted to be the sum of the sizes
+        // of its fields, so it does not contain any padding.
+        unsafe impl #bytemuck::Pod for #ty_name
+        where
+            #(#tys: #bytemuck::Pod,)*
+        {}
+
+        const _: () = {
+            ::core::assert!(
+                ::core::mem::size_of::<#ty_name>()
+                    == 0 #(+ ::core::mem::size_of::<#tys>())*,
+                "`#[bytemuck]` structs must not contain any padding",
+            );
+        };
     })
 }
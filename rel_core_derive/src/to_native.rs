@@ -0,0 +1,195 @@
+use ::macroix::{visit_fields, AttrValue};
+use ::proc_macro2::{Span, TokenStream};
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{
+    parse2,
+    parse_quote,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Index,
+    Path,
+    PathArguments,
+    Type,
+    TypePath,
+};
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let mut rel_core = None;
+    let mut native = None;
+    for attr in input.attrs.iter() {
+        if attr.path.is_ident("rel_core") {
+            rel_core =
+                Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+        } else if attr.path.is_ident("native") {
+            native =
+                Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+        }
+    }
+    let rel_core = rel_core.unwrap_or_else(|| parse_quote! { ::rel_core });
+    let native_ty = native.ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "`ToNative` requires a `#[native = \"...\"]` attribute naming the \
+            native type",
+        )
+    })?;
+
+    // The native type may have generic arguments, which aren't allowed in
+    // expression position without a turbofish. They can always be inferred
+    // from the return type, so we strip them off when constructing the value.
+    let mut native = native_ty.clone();
+    if let Some(last) = native.segments.last_mut() {
+        last.arguments = PathArguments::None;
+    }
+
+    // Only fields whose types are bare type parameters get `ToNative` bounds.
+    // A bound like `RelString<A>: ToNative` would shadow the `ToNative` impl
+    // for `RelString`, which prevents its `Native` type from being normalized
+    // to the type of the corresponding native field.
+    let type_params = input
+        .generics
+        .type_params()
+        .map(|p| p.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        let is_type_param = match ty {
+            Type::Path(TypePath { qself: None, path }) => path
+                .get_ident()
+                .is_some_and(|ident| type_params.contains(ident)),
+            _ => false,
+        };
+        if is_type_param {
+            where_clause
+                .predicates
+                .push(parse_quote! { #ty: #rel_core::ToNative });
+        }
+    });
+
+    let (to_native, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let to_native_variant = to_native_fields(
+                    quote! { #native::#ident },
+                    &v.fields,
+                    &rel_core,
+                    true,
+                );
+                quote! {
+                    #raw_variants::#ident(this_ptr) => {
+                        #to_native_variant
+                    }
+                }
+            });
+
+            (
+                quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    match #raw_variant_fn(this_raw) {
+                        #(#match_arms)*
+                    }
+                },
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => (
+            to_native_fields(
+                quote! { #native },
+                &data_struct.fields,
+                &rel_core,
+                false,
+            ),
+            None,
+        ),
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`ToNative` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let ty_name = &input.ident;
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            impl #impl_generics #rel_core::ToNative for #ty_name #ty_generics
+            #where_clause
+            {
+                type Native = #native_ty;
+
+                #[allow(unused_variables)]
+                fn to_native(
+                    this: #rel_core::export::situ::Ref<'_, Self>,
+                ) -> Self::Native {
+                    let this_ptr = #rel_core::export::situ::Ref::as_ptr(this);
+                    #to_native
+                }
+            }
+        };
+    })
+}
+
+fn to_native_field(rel_core: &Path, member: TokenStream) -> TokenStream {
+    quote! {
+        #rel_core::ToNative::to_native(
+            // SAFETY: `this_ptr` points to a valid value of `Self`, so its
+            // fields are also valid for reads.
+            unsafe {
+                #rel_core::export::situ::Ref::new_unchecked(
+                    ::core::ptr::addr_of!((*this_ptr).#member),
+                )
+            },
+        )
+    }
+}
+
+fn to_native_fields(
+    constructor: TokenStream,
+    fields: &Fields,
+    rel_core: &Path,
+    skip_discriminant: bool,
+) -> TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let to_native_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                let to_native = to_native_field(rel_core, quote! { #ident });
+                quote! { #ident: #to_native }
+            });
+            quote! {
+                #constructor {
+                    #(#to_native_fields,)*
+                }
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let to_native_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    to_native_field(rel_core, quote! { #i })
+                });
+            quote! {
+                #constructor(#(#to_native_fields,)*)
+            }
+        }
+        Fields::Unit => constructor,
+    }
+}
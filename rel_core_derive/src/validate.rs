@@ -0,0 +1,173 @@
+use ::macroix::{visit_fields, AttrValue};
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{
+    parse2,
+    parse_quote,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Index,
+    Path,
+};
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let mut rel_core = None;
+    for attr in input.attrs.iter() {
+        if attr.path.is_ident("rel_core") {
+            rel_core =
+                Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+        }
+    }
+    let rel_core = rel_core.unwrap_or_else(|| parse_quote! { ::rel_core });
+
+    let (validate, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_discriminant = &raw_enum.idents.discriminant;
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let variant_idents = data_enum.variants.iter().map(|v| &v.ident);
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let validate_variant =
+                    validate_fields(&v.fields, &rel_core, true);
+                quote! {
+                    #raw_variants::#ident(this_ptr) => {
+                        #validate_variant
+                    }
+                }
+            });
+
+            (
+                quote! {
+                    // SAFETY: The caller has guaranteed that `this_ptr` is
+                    // valid for reads. The discriminant of a `Portable` enum is
+                    // always a single byte located at the start of the enum.
+                    let discriminant = unsafe { this_ptr.cast::<u8>().read() };
+                    #[allow(clippy::as_conversions)]
+                    let is_valid = false #(
+                        || discriminant == #raw_discriminant::#variant_idents
+                            as u8
+                    )*;
+                    if !is_valid {
+                        return ::core::result::Result::Err(
+                            #rel_core::ValidateError::InvalidDiscriminant(
+                                discriminant,
+                            ),
+                        );
+                    }
+
+                    #[allow(clippy::as_conversions)]
+                    let this_raw = #raw_enum_fn(this_ptr as *mut Self);
+                    match #raw_variant_fn(this_raw) {
+                        #(#match_arms)*
+                    }
+                },
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => {
+            (validate_fields(&data_struct.fields, &rel_core, false), None)
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`Validate` cannot be derived for unions",
+            ))
+        }
+    };
+
+    // These bounds are added after the raw enum is generated so that they only
+    // apply to the `Validate` impl and don't leak into the raw enum items.
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #rel_core::Validate });
+    });
+    if let Data::Enum(_) = input.data {
+        // `Portable` enums always have a single-byte discriminant, which lets
+        // us check it before interpreting the rest of the value.
+        where_clause
+            .predicates
+            .push(parse_quote! { Self: #rel_core::Portable });
+    }
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+    let ty_name = &input.ident;
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            // SAFETY: `validate` checks the discriminant of enums and validates
+            // all of the fields of the value.
+            #[allow(non_snake_case)]
+            unsafe impl #impl_generics #rel_core::Validate
+                for #ty_name #ty_generics
+            #where_clause
+            {
+                #[allow(unused_variables)]
+                unsafe fn validate<__V: #rel_core::Validator + ?Sized>(
+                    this_ptr: *const Self,
+                    validator: &mut __V,
+                ) -> ::core::result::Result<(), #rel_core::ValidateError> {
+                    #validate
+                    ::core::result::Result::Ok(())
+                }
+            }
+        };
+    })
+}
+
+fn validate_field(rel_core: &Path, member: TokenStream) -> TokenStream {
+    quote! {
+        // SAFETY: The caller has guaranteed that `this_ptr` is non-null,
+        // properly aligned, and valid for reads, so its fields are as well.
+        unsafe {
+            #rel_core::Validate::validate(
+                ::core::ptr::addr_of!((*this_ptr).#member),
+                validator,
+            )?;
+        }
+    }
+}
+
+fn validate_fields(
+    fields: &Fields,
+    rel_core: &Path,
+    skip_discriminant: bool,
+) -> TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let validate_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                validate_field(rel_core, quote! { #ident })
+            });
+            quote! {
+                #(#validate_fields)*
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let validate_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    validate_field(rel_core, quote! { #i })
+                });
+            quote! {
+                #(#validate_fields)*
+            }
+        }
+        Fields::Unit => quote! {},
+    }
+}
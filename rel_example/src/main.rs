@@ -10,10 +10,10 @@ fn rel_box() {
     use rel_alloc::{EmplaceIn, RelBox};
     use rel_core::I32;
 
-    let mut backing = Align16(MaybeUninit::<[u8; 128]>::zeroed());
+    let mut backing = Align16::new(MaybeUninit::<[u8; 128]>::zeroed());
 
     let size = StaticToken::acquire(|mut token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc =
             SlabAllocator::<_>::try_new_in(bytes, GhostRef::leak(&mut token))
                 .unwrap();
@@ -33,7 +33,7 @@ fn rel_box() {
     backing_2.slot().zero();
     unsafe {
         ::core::ptr::copy_nonoverlapping(
-            backing.0.as_mut_ptr().cast::<u8>(),
+            backing.value.as_mut_ptr().cast::<u8>(),
             backing_2.as_mut_ptr().cast::<u8>(),
             size,
         );
@@ -54,13 +54,13 @@ fn rel_box() {
 }
 
 fn rel_vec() {
-    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_alloc::prelude::*;
     use ::rel_core::I32;
 
-    let mut backing = Align16(MaybeUninit::<[u8; 512]>::zeroed());
+    let mut backing = Align16::new(MaybeUninit::<[u8; 512]>::zeroed());
 
     StaticToken::acquire(|token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc = SlabAllocator::<_>::try_new_in(bytes, token).unwrap();
 
         let mut vec = vec::New(alloc)
@@ -76,7 +76,7 @@ fn rel_vec() {
     });
 
     StaticToken::acquire(|token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc = SlabAllocator::<_>::try_from_bytes(bytes, token).unwrap();
 
         let mut vec = unsafe {
@@ -97,10 +97,10 @@ fn rel_vec_rel_box() {
     use ::rel_alloc::{vec, EmplaceIn, RelBox, RelVec};
     use ::rel_core::I32;
 
-    let mut backing = Align16(MaybeUninit::<[u8; 1024]>::zeroed());
+    let mut backing = Align16::new(MaybeUninit::<[u8; 1024]>::zeroed());
 
     StaticToken::acquire(|token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc = SlabAllocator::<_>::try_new_in(bytes, token).unwrap();
 
         let mut vec = vec::New(alloc).emplace_in::<RelVec<
@@ -119,7 +119,7 @@ fn rel_vec_rel_box() {
     });
 
     StaticToken::acquire(|token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc = SlabAllocator::<_>::try_from_bytes(bytes, token).unwrap();
 
         let mut vec = unsafe {
@@ -144,10 +144,10 @@ fn rel_vec_rel_box() {
 fn rel_string() {
     use ::rel_alloc::{string, EmplaceIn, RelString};
 
-    let mut backing = Align16(MaybeUninit::<[u8; 1024]>::zeroed());
+    let mut backing = Align16::new(MaybeUninit::<[u8; 1024]>::zeroed());
 
     StaticToken::acquire(|token| {
-        let bytes = Slot::new(&mut backing.0).unsize();
+        let bytes = Slot::new(&mut backing.value).unsize();
         let alloc = SlabAllocator::<_>::try_new_in(bytes, token).unwrap();
 
         let s = string::Clone(alloc, "Hello world!")
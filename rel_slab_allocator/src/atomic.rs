@@ -0,0 +1,794 @@
+use ::core::{
+    alloc::Layout,
+    marker::PhantomPinned,
+    mem::forget,
+    ptr::{addr_of, slice_from_raw_parts_mut, NonNull},
+    sync::atomic::{AtomicU64, Ordering},
+};
+use ::heresy::alloc::{AllocError, Allocator};
+use ::mischief::{In, RegionalAllocator, Singleton, Slot, Unique};
+use ::munge::munge;
+use ::ptr_meta::Pointee;
+use ::rel_alloc::alloc::RelAllocator;
+use ::rel_core::{
+    validate_ptr,
+    Basis,
+    DefaultBasis,
+    Emplace,
+    EmplaceExt,
+    Move,
+    MoveExt,
+    Portable,
+    RelRef,
+    Validate,
+    ValidateError,
+    Validator,
+};
+use ::situ::{
+    alloc::{RawAllocator, RawRegionalAllocator},
+    DropRaw,
+    OwnedVal,
+    Pinned,
+    Ref,
+};
+
+use crate::{
+    try_cast_slot_from_bytes,
+    validator::SlabValidator,
+    RootTag,
+    SlabError,
+    SlabRegion,
+    TagMismatch,
+};
+
+/// A 64-bit atomic integer which is always stored in little-endian order.
+#[repr(transparent)]
+struct AtomicU64Le(AtomicU64);
+
+// SAFETY: `AtomicU64` has the same size and bit validity as `u64` and an
+// alignment of 8 on all targets that support it. Its value is always stored in
+// little-endian order.
+unsafe impl Portable for AtomicU64Le {}
+
+impl AtomicU64Le {
+    fn new(value: u64) -> Self {
+        Self(AtomicU64::new(value.to_le()))
+    }
+
+    fn load(&self) -> u64 {
+        u64::from_le(self.0.load(Ordering::Acquire))
+    }
+
+    fn store(&self, value: u64) {
+        self.0.store(value.to_le(), Ordering::Release);
+    }
+
+    fn compare_exchange(&self, current: u64, new: u64) -> Result<u64, u64> {
+        self.0
+            .compare_exchange(
+                current.to_le(),
+                new.to_le(),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .map(u64::from_le)
+            .map_err(u64::from_le)
+    }
+}
+
+fn to_u64(value: usize) -> u64 {
+    u64::try_from(value).unwrap()
+}
+
+fn to_usize(value: u64) -> Option<usize> {
+    usize::try_from(value).ok()
+}
+
+/// Loads a header value as a `usize`.
+///
+/// Every header value is checked to fit in a `usize` by `try_new_in` or
+/// `try_from_bytes`, and values are only ever updated from `usize`s after that.
+/// So this only saturates for the sentinel root stored while depositing.
+fn load_usize(value: &AtomicU64Le) -> usize {
+    to_usize(value.load()).unwrap_or(usize::MAX)
+}
+
+/// The root offset stored while a root object is being deposited.
+///
+/// This is never a valid root offset, since it is past the end of every slab.
+const DEPOSITING: u64 = u64::MAX;
+
+/// The slab control for an `AtomicSlabAllocator`.
+///
+/// This has the same fields as `SlabControl`, but they are stored as 64-bit
+/// atomics so that it can be shared between threads. Because of this, slabs
+/// created with an `AtomicSlabAllocator` must be loaded with an
+/// `AtomicSlabAllocator` as well.
+#[derive(Portable, Unique)]
+#[repr(C, align(8))]
+struct AtomicSlabControl<U> {
+    root: AtomicU64Le,
+    len: AtomicU64Le,
+    cap: AtomicU64Le,
+    tag: AtomicU64Le,
+    #[unique]
+    unique: U,
+    _pinned: PhantomPinned,
+}
+
+impl<U> AtomicSlabControl<U> {
+    const LAYOUT: Layout = Layout::new::<Self>();
+
+    fn root(&self) -> usize {
+        match self.root.load() {
+            DEPOSITING => 0,
+            _ => load_usize(&self.root),
+        }
+    }
+
+    fn cap(&self) -> usize {
+        load_usize(&self.cap)
+    }
+
+    fn len(&self) -> usize {
+        load_usize(&self.len)
+    }
+
+    fn tag(&self) -> u64 {
+        self.tag.load()
+    }
+
+    fn allocate(
+        this: Ref<'_, Self>,
+        layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.align() > Self::LAYOUT.align() {
+            return Err(AllocError);
+        }
+
+        // Other threads may allocate between loading the length and updating
+        // it, so we retry until we update it from the length we read.
+        let mut len = this.len();
+        loop {
+            let start = (len + layout.align() - 1) & !(layout.align() - 1);
+            let end = start
+                .checked_add(layout.size())
+                .filter(|&end| end <= this.cap())
+                .ok_or(AllocError)?;
+            match this.len.compare_exchange(to_u64(len), to_u64(end)) {
+                Ok(_) => {
+                    let address =
+                        unsafe { this.as_ptr().cast::<u8>().add(start) };
+                    let slice_ptr =
+                        slice_from_raw_parts_mut(address, layout.size());
+                    return Ok(unsafe { NonNull::new_unchecked(slice_ptr) });
+                }
+                Err(current) => len = to_usize(current).ok_or(AllocError)?,
+            }
+        }
+    }
+
+    unsafe fn grow_in_place(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let start = ptr.as_ptr() as usize - this.as_ptr() as usize;
+        if new_layout.align() > Self::LAYOUT.align()
+            || start & (new_layout.align() - 1) != 0
+            || this.cap() - start < new_layout.size()
+        {
+            return Err(AllocError);
+        }
+
+        // This only succeeds if the allocation is still the last one in the
+        // slab, even if another thread allocated concurrently.
+        this.len
+            .compare_exchange(
+                to_u64(start + old_layout.size()),
+                to_u64(start + new_layout.size()),
+            )
+            .map_err(|_| AllocError)?;
+        let slice_ptr =
+            slice_from_raw_parts_mut(ptr.as_ptr(), new_layout.size());
+        Ok(unsafe { NonNull::new_unchecked(slice_ptr) })
+    }
+
+    unsafe fn deallocate(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    ) {
+        let start = ptr.as_ptr() as usize - this.as_ptr() as usize;
+        let _ = this
+            .len
+            .compare_exchange(to_u64(start + layout.size()), to_u64(start));
+    }
+
+    fn try_new_in(
+        bytes: Slot<'_, [u8]>,
+        unique: U,
+    ) -> Result<Ref<'_, Self>, SlabError> {
+        let max_cap = u64::try_from(bytes.len())
+            .map_err(|_| SlabError::CapacityOverflow)?;
+        let mut out = try_cast_slot_from_bytes::<Self>(bytes)?;
+
+        munge!(
+            let AtomicSlabControl {
+                root: mut out_root,
+                len: mut out_len,
+                cap: mut out_cap,
+                tag: mut out_tag,
+                unique: mut out_unique,
+                ..
+            } = out.as_mut()
+        );
+
+        out_root.write(AtomicU64Le::new(0));
+        out_len.write(AtomicU64Le::new(to_u64(Self::LAYOUT.size())));
+        out_cap.write(AtomicU64Le::new(max_cap));
+        out_tag.write(AtomicU64Le::new(0));
+        out_unique.write(unique);
+
+        Ok(unsafe { Ref::new_unchecked(out.as_ptr()) })
+    }
+
+    fn try_from_bytes(
+        bytes: Slot<'_, [u8]>,
+        unique: U,
+    ) -> Result<Ref<'_, Self>, SlabError> {
+        let max_cap = bytes.len();
+        let slot = try_cast_slot_from_bytes::<Self>(bytes)?;
+
+        forget(unique);
+        let result = unsafe { Ref::new_unchecked(slot.as_ptr()) };
+
+        let root = to_usize(result.root.load());
+        let len = to_usize(result.len.load());
+        let cap = to_usize(result.cap.load());
+        let (root, len, cap) = match (root, len, cap) {
+            (Some(root), Some(len), Some(cap)) => (root, len, cap),
+            _ => {
+                return Err(SlabError::InconsistentHeader {
+                    len: len.unwrap_or(usize::MAX),
+                    cap: cap.unwrap_or(usize::MAX),
+                    max_cap,
+                })
+            }
+        };
+        if len < Self::LAYOUT.size() || len > cap || cap > max_cap {
+            Err(SlabError::InconsistentHeader { len, cap, max_cap })
+        } else if root != 0 && (root < Self::LAYOUT.size() || root >= len) {
+            Err(SlabError::InvalidRoot { root, len })
+        } else {
+            Ok(result)
+        }
+    }
+
+    fn shrink_to_fit(&self) -> usize {
+        let len = self.len.load();
+        self.cap.store(len);
+        load_usize(&self.cap)
+    }
+
+    unsafe fn deposit<T>(this: Ref<'_, Self>, val: *mut T, tag: u64) -> bool {
+        let base = this.as_ptr() as usize;
+        let target = val as usize;
+        // The root is claimed with a sentinel first, so the tag is always
+        // stored before the root is published. A thread which acquires the
+        // root offset is then guaranteed to see the matching tag.
+        if target >= base && this.root.compare_exchange(0, DEPOSITING).is_ok() {
+            this.tag.store(tag);
+            this.root.store(to_u64(target - base));
+            true
+        } else {
+            false
+        }
+    }
+
+    unsafe fn withdraw<T>(this: Ref<'_, Self>) -> Option<(*mut T, u64)> {
+        // The root offset must only be loaded once, since it may be published
+        // by another thread at any time.
+        let root = this.root();
+        if root != 0 {
+            // SAFETY: Published root offsets always point into the slab, since
+            // they were either deposited from a value in the slab or checked
+            // by `try_from_bytes`.
+            let ptr = unsafe { this.as_ptr().cast::<u8>().add(root) };
+            Some((ptr.cast::<T>(), this.tag()))
+        } else {
+            None
+        }
+    }
+}
+
+unsafe impl<U: Unique> Pinned<SlabRegion<U>> for AtomicSlabControl<U> {}
+
+/// A slab allocator which may be shared between threads.
+///
+/// This works like `SlabAllocator`, but the length of the slab is updated
+/// atomically so that multiple threads can allocate from the same slab at
+/// once. Relative pointers are relative to their own locations, so values
+/// allocated by different threads can point to each other freely.
+///
+/// The slab header of an `AtomicSlabAllocator` is not compatible with the
+/// header of a `SlabAllocator`. Slabs must be loaded with the same kind of
+/// allocator that created them.
+#[derive(Singleton)]
+pub struct AtomicSlabAllocator<'a, U> {
+    inner: Ref<'a, AtomicSlabControl<U>>,
+}
+
+// SAFETY: `AtomicSlabControl` only contains atomics and the unique value, so
+// sharing a reference to it between threads is safe when `U` is `Sync`.
+unsafe impl<U: Sync> Send for AtomicSlabAllocator<'_, U> {}
+
+// SAFETY: See the `Send` impl above.
+unsafe impl<U: Sync> Sync for AtomicSlabAllocator<'_, U> {}
+
+impl<'a, U> AtomicSlabAllocator<'a, U> {
+    /// Creates a new, empty slab in `bytes`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is too small or not aligned enough to
+    /// contain the slab header.
+    pub fn try_new_in(
+        bytes: Slot<'a, [u8]>,
+        unique: U,
+    ) -> Result<Self, SlabError> {
+        Ok(Self {
+            inner: AtomicSlabControl::try_new_in(bytes, unique)?,
+        })
+    }
+
+    /// Loads a slab that was created by an `AtomicSlabAllocator` from `bytes`.
+    ///
+    /// Only the slab header is checked. The root object must still be checked
+    /// with [`withdraw`](Self::withdraw) before it is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is too small or not aligned enough to
+    /// contain the slab header, if the length and capacity in the header don't
+    /// fit in a `usize` or are inconsistent with `bytes`, or if the root offset
+    /// does not point into the used portion of the slab.
+    pub fn try_from_bytes(
+        bytes: Slot<'a, [u8]>,
+        unique: U,
+    ) -> Result<Self, SlabError> {
+        Ok(Self {
+            inner: AtomicSlabControl::try_from_bytes(bytes, unique)?,
+        })
+    }
+
+    /// Returns the number of bytes of the slab that are in use.
+    ///
+    /// This follows the same rules as [`SlabAllocator::used`], including
+    /// rewinding when the trailing allocation is freed. Other threads may
+    /// allocate or deallocate at any time, so the result is only a snapshot
+    /// and may already be stale when it's returned.
+    ///
+    /// [`SlabAllocator::used`]: crate::SlabAllocator::used
+    pub fn used(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns the total size of the slab in bytes.
+    pub fn capacity(&self) -> usize {
+        self.inner.cap()
+    }
+
+    /// Returns the number of bytes between the end of the used portion of the
+    /// slab and its capacity.
+    ///
+    /// Like [`used`](Self::used), this is only a snapshot when other threads
+    /// are allocating concurrently.
+    pub fn remaining(&self) -> usize {
+        self.inner.cap() - self.inner.len()
+    }
+
+    /// Shrinks the capacity of the slab to its used length and returns it.
+    ///
+    /// Allocations made concurrently with shrinking may extend past the new
+    /// capacity, so this should only be called after every other thread has
+    /// finished allocating.
+    pub fn shrink_to_fit(&self) -> usize {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Deposits a root object with no type tag.
+    ///
+    /// Only one root object can be deposited in a slab. If multiple threads
+    /// deposit at the same time, exactly one of them succeeds, and the root
+    /// object and its tag are published together.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if a root object was already deposited.
+    pub fn deposit<T>(
+        &self,
+        val: OwnedVal<T, Self>,
+    ) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable,
+        Self: Singleton,
+    {
+        self.deposit_with_tag(val, 0)
+    }
+
+    /// Deposits a root object along with the type tag of `T`.
+    ///
+    /// The root object can later be withdrawn with `withdraw_tagged`, which
+    /// checks that the tag matches.
+    pub fn deposit_tagged<T>(
+        &self,
+        val: OwnedVal<T, Self>,
+    ) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable + RootTag,
+        Self: Singleton,
+    {
+        self.deposit_with_tag(val, T::TAG)
+    }
+
+    fn deposit_with_tag<T>(
+        &self,
+        mut val: OwnedVal<T, Self>,
+        tag: u64,
+    ) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable,
+        Self: Singleton,
+    {
+        let was_stored = unsafe {
+            AtomicSlabControl::deposit(self.inner, val.as_mut().as_ptr(), tag)
+        };
+        if was_stored {
+            forget(val);
+            None
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Withdraws a previously-deposited root object.
+    ///
+    /// # Safety
+    ///
+    /// The previously-deposited root object must be compatible with type `T`.
+    pub unsafe fn withdraw_unchecked<T>(&self) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable,
+    {
+        let result = unsafe { AtomicSlabControl::withdraw(self.inner) };
+        result.map(|(ptr, _)| unsafe { OwnedVal::from_raw_in(ptr, *self) })
+    }
+
+    /// Withdraws a previously-deposited root object after checking that it was
+    /// deposited with the type tag of `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every root object deposited with the tag `T::TAG` must be compatible
+    /// with type `T`. The tag is only checked, not the root object, so this
+    /// must not be relied on for slabs from untrusted sources. See
+    /// [`RootTag`] for why the tag can't stand in for validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root object was deposited with a different tag.
+    pub unsafe fn withdraw_tagged<T>(
+        &self,
+    ) -> Result<Option<OwnedVal<T, Self>>, TagMismatch>
+    where
+        T: DropRaw + Portable + RootTag,
+    {
+        match unsafe { AtomicSlabControl::withdraw(self.inner) } {
+            Some((_, tag)) if tag != T::TAG => Err(TagMismatch {
+                expected: T::TAG,
+                found: tag,
+            }),
+            result => Ok(result.map(|(ptr, _)| {
+                // SAFETY: The root object was deposited with the tag
+                // `T::TAG`, so the caller has guaranteed that it is compatible
+                // with `T`.
+                unsafe { OwnedVal::from_raw_in(ptr, *self) }
+            })),
+        }
+    }
+
+    /// Validates and withdraws a previously-deposited root object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previously-deposited root object is not a valid
+    /// value of type `T`.
+    pub fn withdraw<T>(
+        &self,
+    ) -> Result<Option<OwnedVal<T, Self>>, ValidateError>
+    where
+        T: DropRaw + Portable + Validate,
+    {
+        let withdrawn = unsafe { AtomicSlabControl::withdraw::<T>(self.inner) };
+        if let Some((ptr, _)) = withdrawn {
+            let mut validator = SlabValidator::from_parts(
+                self.inner.as_ptr().cast(),
+                AtomicSlabControl::<U>::LAYOUT.size(),
+                self.inner.len(),
+            );
+            validate_ptr(ptr, &mut validator)?;
+        }
+        Ok(withdrawn.map(|(ptr, _)| {
+            // SAFETY: The root object was validated as a `T`.
+            unsafe { OwnedVal::from_raw_in(ptr, *self) }
+        }))
+    }
+}
+
+impl<U> Clone for AtomicSlabAllocator<'_, U> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner }
+    }
+}
+
+impl<U> Copy for AtomicSlabAllocator<'_, U> {}
+
+unsafe impl<U> Allocator for AtomicSlabAllocator<'_, U> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        AtomicSlabControl::allocate(self.inner, layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { AtomicSlabControl::deallocate(self.inner, ptr, layout) }
+    }
+
+    unsafe fn grow_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe {
+            AtomicSlabControl::grow_in_place(
+                self.inner, ptr, old_layout, new_layout,
+            )
+        }
+    }
+}
+
+unsafe impl<'a, U, B> Emplace<RelAtomicSlabAllocator<'a, U, B>, SlabRegion<U>>
+    for AtomicSlabAllocator<'a, U>
+where
+    U: Unique,
+    B: Basis,
+{
+    fn emplaced_meta(
+        &self,
+    ) -> <RelAtomicSlabAllocator<'a, U, B> as Pointee>::Metadata {
+    }
+
+    unsafe fn emplace_unsized_unchecked(
+        self,
+        out: In<Slot<'_, RelAtomicSlabAllocator<'a, U, B>>, SlabRegion<U>>,
+    ) {
+        munge!(let RelAtomicSlabAllocator { inner: out_inner } = out);
+        In::new(self.inner).emplace(out_inner);
+    }
+}
+
+unsafe impl<'a, U: Unique> RegionalAllocator for AtomicSlabAllocator<'a, U> {
+    type Region = SlabRegion<U>;
+}
+
+unsafe impl<'a, U, B> RelAllocator<RelAtomicSlabAllocator<'a, U, B>>
+    for AtomicSlabAllocator<'a, U>
+where
+    U: Unique,
+    B: Basis,
+{
+}
+
+/// The relative counterpart to `AtomicSlabAllocator`.
+#[derive(DropRaw, Portable)]
+#[repr(C)]
+pub struct RelAtomicSlabAllocator<'a, U: Unique, B: Basis = DefaultBasis> {
+    inner: RelRef<'a, AtomicSlabControl<U>, SlabRegion<U>, B>,
+}
+
+unsafe impl<U, B> RawAllocator for RelAtomicSlabAllocator<'_, U, B>
+where
+    U: Unique,
+    B: Basis,
+{
+    fn raw_allocate(
+        this: Ref<'_, Self>,
+        layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        munge!(let RelAtomicSlabAllocator { inner } = this);
+        AtomicSlabControl::allocate(RelRef::deref(inner), layout)
+    }
+
+    unsafe fn raw_deallocate(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    ) {
+        munge!(let RelAtomicSlabAllocator { inner } = this);
+        unsafe {
+            AtomicSlabControl::deallocate(RelRef::deref(inner), ptr, layout)
+        }
+    }
+
+    unsafe fn raw_grow_in_place(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        munge!(let RelAtomicSlabAllocator { inner } = this);
+        unsafe {
+            AtomicSlabControl::grow_in_place(
+                RelRef::deref(inner),
+                ptr,
+                old_layout,
+                new_layout,
+            )
+        }
+    }
+}
+
+unsafe impl<'a, U, B> RawRegionalAllocator for RelAtomicSlabAllocator<'a, U, B>
+where
+    U: Unique,
+    B: Basis,
+{
+    type Region = SlabRegion<U>;
+}
+
+unsafe impl<'a, U, B> Move<SlabRegion<U>> for RelAtomicSlabAllocator<'a, U, B>
+where
+    U: Unique,
+    B: Basis,
+{
+    unsafe fn move_unsized_unchecked(
+        this: In<situ::Val<'_, Self>, SlabRegion<U>>,
+        out: In<Slot<'_, Self>, SlabRegion<U>>,
+    ) {
+        munge!(let RelAtomicSlabAllocator { inner } = this);
+        munge!(let RelAtomicSlabAllocator { inner: out_inner } = out);
+
+        MoveExt::r#move(inner, out_inner);
+    }
+}
+
+// SAFETY: `validate` checks that the reference points to the slab control,
+// which is always located at the start of the slab being validated.
+unsafe impl<'a, U, B> Validate for RelAtomicSlabAllocator<'a, U, B>
+where
+    U: Unique,
+    B: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        let inner = unsafe { Ref::new_unchecked(addr_of!((*ptr).inner)) };
+        match RelRef::as_ptr_wrapping(inner) {
+            Some(target) if target.cast::<u8>() == validator.base() => Ok(()),
+            Some(target) => Err(ValidateError::OutOfBounds {
+                address: target.cast::<u8>() as usize,
+                size: AtomicSlabControl::<U>::LAYOUT.size(),
+            }),
+            None => Err(ValidateError::NullPointer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::forget;
+    use ::mischief::{GhostRef, StaticToken};
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+    use ::rel_util::Align16;
+
+    use crate::{AtomicSlabAllocator, RelAtomicSlabAllocator, RootTag};
+
+    impl RootTag for U32 {
+        const TAG: u64 = 1;
+    }
+
+    #[test]
+    fn allocate_from_threads() {
+        type Values<'a, U> = RelVec<U32, RelAtomicSlabAllocator<'a, U>>;
+
+        const THREADS: u32 = 4;
+        const VALUES: u32 = 32;
+
+        let mut bytes = Align16::frame(4096);
+        StaticToken::acquire(|mut token| {
+            let alloc = AtomicSlabAllocator::try_new_in(
+                bytes.slot().as_bytes(),
+                GhostRef::leak(&mut token),
+            )
+            .unwrap();
+            let initial = alloc.used();
+
+            let sums = ::std::thread::scope(|s| {
+                let handles = (0..THREADS)
+                    .map(|t| {
+                        s.spawn(move || {
+                            let mut values = vec::WithCapacity(alloc, 8)
+                                .emplace_in::<Values<'_, _>>(alloc);
+                            RelVec::extend(
+                                values.as_mut(),
+                                (0..VALUES).map(|i| t * VALUES + i),
+                            );
+                            // Freeing the trailing allocation rewinds `used`,
+                            // so growth can only be observed while `values` is
+                            // live.
+                            assert!(alloc.used() > initial);
+                            RelVec::iter(values.as_ref())
+                                .map(|x| x.to_ne())
+                                .sum::<u32>()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                handles
+                    .into_iter()
+                    .map(|h| h.join().unwrap())
+                    .collect::<Vec<_>>()
+            });
+
+            let total = THREADS * VALUES;
+            assert_eq!(sums.iter().sum::<u32>(), total * (total - 1) / 2);
+            assert!(alloc.used() >= initial);
+            assert!(alloc.used() <= alloc.capacity());
+        });
+    }
+
+    #[test]
+    fn deposit_publishes_tag() {
+        const THREADS: u32 = 4;
+
+        let mut bytes = Align16::frame(1024);
+        StaticToken::acquire(|mut token| {
+            let alloc = AtomicSlabAllocator::try_new_in(
+                bytes.slot().as_bytes(),
+                GhostRef::leak(&mut token),
+            )
+            .unwrap();
+
+            let (deposited, root) = ::std::thread::scope(|s| {
+                let reader = s.spawn(move || loop {
+                    // The root object is never visible before its tag.
+                    let root = unsafe { alloc.withdraw_tagged::<U32>() };
+                    if let Some(root) = root.unwrap() {
+                        let value = root.to_ne();
+                        forget(root);
+                        break value;
+                    }
+                });
+                let writers = (0..THREADS)
+                    .map(|t| {
+                        s.spawn(move || {
+                            let value = t.emplace_in::<U32>(alloc);
+                            alloc.deposit_tagged(value).is_none()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let deposited = writers
+                    .into_iter()
+                    .map(|h| h.join().unwrap())
+                    .filter(|&was_stored| was_stored)
+                    .count();
+                (deposited, reader.join().unwrap())
+            });
+
+            assert_eq!(deposited, 1);
+            assert!(root < THREADS);
+        });
+    }
+}
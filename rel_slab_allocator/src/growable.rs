@@ -0,0 +1,209 @@
+use ::core::{
+    marker::PhantomData,
+    ptr::{slice_from_raw_parts_mut, NonNull},
+};
+use ::mischief::Slot;
+use ::rel_core::{Basis, DefaultBasis};
+use ::situ::Ref;
+
+use crate::{SlabAllocator, SlabControl, SlabError};
+
+pub(crate) const WORD_SIZE: usize = 8;
+
+#[derive(Clone, Copy)]
+#[repr(C, align(8))]
+pub(crate) struct Word(pub(crate) [u8; WORD_SIZE]);
+
+/// A slab allocator which owns a growable backing buffer.
+///
+/// Growing the slab reallocates the backing buffer, which moves it to a new
+/// location in memory. Relative pointers stored inside of the slab are relative
+/// to their own locations, so they remain valid after the slab grows. However,
+/// any raw pointers into the old buffer are left dangling. `Ref`s and `Mut`s
+/// into the slab are tied to the lifetime of the `SlabAllocator` returned from
+/// `allocator`, so the borrow checker prevents growing while they are held.
+///
+/// The slab does not grow on its own when it is exhausted, and allocations
+/// which don't fit fail just like they do in a fixed-size slab. Every value in
+/// the slab is accessed through a pointer into the backing buffer: the
+/// `SlabAllocator` from `allocator`, and every `OwnedVal`, `Ref`, and `Mut`
+/// created with it. Growing from inside of an allocation would leave all of
+/// them dangling. Instead, the slab can only grow with `reserve`, which
+/// requires that none of them are still held. To keep building a structure
+/// after the slab fills up, deposit its root object, `reserve` more space, and
+/// then withdraw the root object again.
+pub struct GrowableSlabAllocator<U, B: Basis = DefaultBasis> {
+    words: NonNull<[Word]>,
+    _phantom: PhantomData<SlabControl<U, B>>,
+}
+
+impl<U, B: Basis> Drop for GrowableSlabAllocator<U, B> {
+    fn drop(&mut self) {
+        // SAFETY: `words` was created with `Box::into_raw` and is only freed
+        // here.
+        drop(unsafe { Box::from_raw(self.words.as_ptr()) });
+    }
+}
+
+impl<U, B: Basis> GrowableSlabAllocator<U, B> {
+    /// Returns a new `GrowableSlabAllocator` with a backing buffer of at least
+    /// `capacity` bytes, including the slab header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `capacity` is too small to contain the slab header,
+    /// or too large to be represented with `B`.
+    pub fn with_capacity(
+        capacity: usize,
+        unique: U,
+    ) -> Result<Self, SlabError> {
+        let words = Self::words_for(capacity)?;
+        let vec = vec![Word([0; WORD_SIZE]); words];
+        let raw = Box::into_raw(vec.into_boxed_slice());
+        let result = Self {
+            // SAFETY: `Box::into_raw` never returns a null pointer.
+            words: unsafe { NonNull::new_unchecked(raw) },
+            _phantom: PhantomData,
+        };
+
+        SlabControl::<U, B>::try_new_in(result.bytes(), unique)?;
+
+        Ok(result)
+    }
+
+    /// Returns a `SlabAllocator` which allocates in this slab.
+    ///
+    /// The allocator borrows the `GrowableSlabAllocator`, so the slab can't
+    /// grow while it or any values allocated with it are in use.
+    pub fn allocator(&self) -> SlabAllocator<'_, U, B> {
+        SlabAllocator {
+            inner: self.control(),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be allocated
+    /// in the slab.
+    ///
+    /// If the slab does not have enough remaining capacity, then its backing
+    /// buffer will be reallocated. Any raw pointers into the slab will be
+    /// invalidated.
+    pub fn reserve(&mut self, additional: usize) -> Result<(), SlabError> {
+        let control = self.control();
+        let min_cap = control
+            .len()
+            .checked_add(additional)
+            .ok_or(SlabError::CapacityOverflow)?;
+        if min_cap <= control.cap() {
+            return Ok(());
+        }
+
+        let new_words =
+            Self::words_for(min_cap.max(control.cap().saturating_mul(2)))?;
+        let new_cap = new_words * WORD_SIZE;
+        B::from_native_usize(new_cap)
+            .map_err(|_| SlabError::CapacityOverflow)?;
+
+        // SAFETY: `words` was created with `Box::into_raw`, and is replaced
+        // with the reallocated buffer before it is used again.
+        let mut vec = unsafe { Box::from_raw(self.words.as_ptr()) }.into_vec();
+        vec.resize(new_words, Word([0; WORD_SIZE]));
+        let raw = Box::into_raw(vec.into_boxed_slice());
+        // SAFETY: `Box::into_raw` never returns a null pointer.
+        self.words = unsafe { NonNull::new_unchecked(raw) };
+
+        self.control()
+            .cap
+            .set(B::from_native_usize(new_cap).unwrap());
+
+        Ok(())
+    }
+
+    fn words_for(capacity: usize) -> Result<usize, SlabError> {
+        let words = capacity
+            .checked_add(WORD_SIZE - 1)
+            .ok_or(SlabError::CapacityOverflow)?
+            / WORD_SIZE;
+        if words.checked_mul(WORD_SIZE).is_some() {
+            Ok(words)
+        } else {
+            Err(SlabError::CapacityOverflow)
+        }
+    }
+
+    fn bytes(&self) -> Slot<'_, [u8]> {
+        let len = self.words.len() * WORD_SIZE;
+        let ptr = slice_from_raw_parts_mut(self.words.as_ptr().cast(), len);
+        // SAFETY: `words` is a live allocation of `len` bytes which is owned
+        // by `self`, and the returned slot borrows `self`.
+        unsafe { Slot::new_unchecked(ptr) }
+    }
+
+    fn control(&self) -> Ref<'_, SlabControl<U, B>> {
+        // SAFETY: The backing buffer is aligned to `WORD_SIZE` and always
+        // starts with the slab header written by `with_capacity`. The returned
+        // `Ref` borrows `self`, so the buffer can't be reallocated while it is
+        // held.
+        unsafe { Ref::new_unchecked(self.words.as_ptr().cast()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::mischief::{StaticToken, Unique};
+    use ::rel_alloc::{vec, EmplaceIn, RelVec};
+    use ::rel_core::U32;
+
+    use crate::{GrowableSlabAllocator, RelSlabAllocator};
+
+    type Values<'a, U> = RelVec<U32, RelSlabAllocator<'a, U>>;
+
+    fn check_values<U: Unique>(
+        slab: &GrowableSlabAllocator<U>,
+        count: u32,
+    ) -> u32 {
+        let alloc = slab.allocator();
+        let values = alloc.withdraw::<Values<'_, U>>().unwrap().unwrap();
+        assert!(RelVec::iter(values.as_ref())
+            .map(|value| value.to_ne())
+            .eq(0..count));
+        ::core::mem::forget(values);
+        count
+    }
+
+    fn fill_and_grow<U: Unique>(mut slab: GrowableSlabAllocator<U>) {
+        let alloc = slab.allocator();
+        let mut values = vec::New(alloc).emplace_in::<Values<'_, U>>(alloc);
+        let mut count = 0;
+        while RelVec::try_reserve(values.as_mut(), 1).is_ok() {
+            RelVec::push(values.as_mut(), count);
+            count += 1;
+        }
+        assert!(alloc.deposit(values).is_none());
+        check_values(&slab, count);
+
+        let used = slab.allocator().used();
+        slab.reserve(1024).unwrap();
+        assert!(slab.allocator().capacity() >= used + 1024);
+
+        // The root object is still intact after the backing buffer moved, and
+        // more values can be emplaced in the new capacity.
+        check_values(&slab, count);
+        let alloc = slab.allocator();
+        let mut values = alloc.withdraw::<Values<'_, U>>().unwrap().unwrap();
+        for i in count..count + 64 {
+            RelVec::push(values.as_mut(), i);
+        }
+        // The root object is still deposited, so it must not be dropped.
+        ::core::mem::forget(values);
+        check_values(&slab, count + 64);
+    }
+
+    #[test]
+    fn reserve() {
+        StaticToken::acquire(|token| {
+            let slab =
+                GrowableSlabAllocator::with_capacity(128, token).unwrap();
+            fill_and_grow(slab);
+        });
+    }
+}
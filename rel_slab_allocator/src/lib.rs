@@ -1,18 +1,28 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+#[cfg(feature = "alloc")]
+mod growable;
+mod validator;
+
 use ::core::{
     alloc::Layout,
     cell::Cell,
+    fmt,
     marker::{PhantomData, PhantomPinned},
     mem::forget,
-    ptr::{slice_from_raw_parts_mut, NonNull},
+    ptr::{addr_of, slice_from_raw_parts_mut, NonNull},
 };
+#[cfg(feature = "alloc")]
+use ::core::ptr::copy_nonoverlapping;
 use ::heresy::alloc::{AllocError, Allocator};
 use ::mischief::{In, Region, RegionalAllocator, Singleton, Slot, Unique};
 use ::munge::munge;
 use ::ptr_meta::Pointee;
 use ::rel_alloc::alloc::RelAllocator;
 use ::rel_core::{
+    validate_ptr,
     Basis,
     DefaultBasis,
     Emplace,
@@ -21,17 +31,164 @@ use ::rel_core::{
     MoveExt,
     Portable,
     RelRef,
+    Validate,
+    ValidateError,
+    Validator,
+    U64,
 };
 use ::situ::{
     alloc::{RawAllocator, RawRegionalAllocator},
+    fmt::DebugRaw,
     DropRaw,
     OwnedVal,
     Pinned,
     Ref,
 };
+#[cfg(feature = "alloc")]
+use ::situ::CloneRaw;
+
+#[cfg(target_has_atomic = "64")]
+pub use self::atomic::{AtomicSlabAllocator, RelAtomicSlabAllocator};
+#[cfg(feature = "alloc")]
+pub use self::growable::GrowableSlabAllocator;
+#[cfg(feature = "alloc")]
+use self::growable::{Word, WORD_SIZE};
+use self::validator::SlabValidator;
+
+/// An error that occurred while creating a slab allocator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlabError {
+    /// The bytes were too small to contain a slab header.
+    TooSmall {
+        /// The length of the bytes.
+        len: usize,
+        /// The minimum length required.
+        min: usize,
+    },
+    /// The bytes were not sufficiently aligned for a slab header.
+    Misaligned {
+        /// The address of the start of the bytes.
+        address: usize,
+        /// The required alignment.
+        align: usize,
+    },
+    /// The length and capacity in the slab header were inconsistent with each
+    /// other or with the bytes they were loaded from.
+    InconsistentHeader {
+        /// The length read from the header.
+        len: usize,
+        /// The capacity read from the header.
+        cap: usize,
+        /// The length of the bytes the header was loaded from.
+        max_cap: usize,
+    },
+    /// The root offset in the slab header did not point into the used portion
+    /// of the slab.
+    InvalidRoot {
+        /// The root offset read from the header.
+        root: usize,
+        /// The length read from the header.
+        len: usize,
+    },
+    /// The requested capacity was too large to be represented.
+    CapacityOverflow,
+}
+
+impl fmt::Display for SlabError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooSmall { len, min } => write!(
+                f,
+                "slab of {len} bytes is too small, it must be at least {min} \
+                bytes",
+            ),
+            Self::Misaligned { address, align } => write!(
+                f,
+                "slab at address {address:#x} is not aligned to {align} bytes",
+            ),
+            Self::InconsistentHeader { len, cap, max_cap } => write!(
+                f,
+                "inconsistent slab header with length {len} and capacity \
+                {cap} in {max_cap} bytes",
+            ),
+            Self::InvalidRoot { root, len } => write!(
+                f,
+                "slab root offset {root} is outside of the used length {len}",
+            ),
+            Self::CapacityOverflow => f.write_str("slab capacity overflowed"),
+        }
+    }
+}
+
+impl ::std::error::Error for SlabError {}
 
-#[derive(Debug)]
-pub struct SlabError;
+/// A type which can be deposited and withdrawn with a type tag.
+///
+/// The tag is stored in the slab header alongside the root object and checked
+/// when it is withdrawn. This catches mistakes like loading a slab with the
+/// wrong schema, but does not check the bytes of the root object itself.
+///
+/// Tags are chosen by hand rather than derived from the type. Slabs outlive
+/// the program that wrote them, so a tag has to mean the same thing to every
+/// build that reads it, and neither `TypeId` nor `type_name` is stable across
+/// compilers or compilations. For the same reason, a matching tag only says
+/// what the depositor claimed to write: it is not a proof that the bytes are
+/// a valid `T`, which is why `withdraw_tagged` is unsafe. Use `withdraw` to
+/// load slabs from untrusted sources.
+pub trait RootTag {
+    /// The tag for this type.
+    ///
+    /// This should be nonzero, since roots deposited without a tag have a tag
+    /// of zero. It should also be unique among the root types that may be
+    /// deposited in the same slab, and should be changed whenever the layout
+    /// of the type changes.
+    const TAG: u64;
+}
+
+/// The type tag of a withdrawn root object did not match the expected tag.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TagMismatch {
+    /// The tag that was expected.
+    pub expected: u64,
+    /// The tag that the root object was deposited with.
+    pub found: u64,
+}
+
+impl fmt::Display for TagMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected root type tag {:#018x} but found {:#018x}",
+            self.expected, self.found,
+        )
+    }
+}
+
+impl ::std::error::Error for TagMismatch {}
+
+/// Casts a slot of bytes to a slot of a slab control type `T`, checking that
+/// the bytes are large enough and sufficiently aligned to contain it.
+fn try_cast_slot_from_bytes<T>(
+    slot: Slot<'_, [u8]>,
+) -> Result<Slot<'_, T>, SlabError> {
+    let layout = Layout::new::<T>();
+    let len = slot.len();
+    let address = slot.as_ptr() as *mut u8 as usize;
+    if len < layout.size() {
+        Err(SlabError::TooSmall {
+            len,
+            min: layout.size(),
+        })
+    } else if address & (layout.align() - 1) != 0 {
+        Err(SlabError::Misaligned {
+            address,
+            align: layout.align(),
+        })
+    } else {
+        let slot = unsafe { slot.cast::<T>() };
+        Ok(slot)
+    }
+}
 
 #[derive(Portable, Unique)]
 #[repr(C, align(8))]
@@ -39,6 +196,7 @@ struct SlabControl<U, B: Basis = DefaultBasis> {
     root: Cell<B::Usize>,
     len: Cell<B::Usize>,
     cap: Cell<B::Usize>,
+    tag: Cell<U64>,
     #[unique]
     unique: U,
     _pinned: PhantomPinned,
@@ -59,6 +217,10 @@ impl<U, B: Basis> SlabControl<U, B> {
         B::to_native_usize(self.len.get()).unwrap()
     }
 
+    fn tag(&self) -> u64 {
+        self.tag.get().to_ne()
+    }
+
     fn allocate(
         this: Ref<'_, Self>,
         layout: Layout,
@@ -82,18 +244,53 @@ impl<U, B: Basis> SlabControl<U, B> {
         }
     }
 
+    unsafe fn grow_in_place(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        let start = ptr.as_ptr() as usize - this.as_ptr() as usize;
+        if new_layout.align() > Self::LAYOUT.align()
+            || start & (new_layout.align() - 1) != 0
+            || start + old_layout.size() != this.len()
+            || this.cap() - start < new_layout.size()
+        {
+            Err(AllocError)
+        } else {
+            this.len
+                .set(B::from_native_usize(start + new_layout.size()).unwrap());
+            let slice_ptr =
+                slice_from_raw_parts_mut(ptr.as_ptr(), new_layout.size());
+            Ok(unsafe { NonNull::new_unchecked(slice_ptr) })
+        }
+    }
+
+    unsafe fn deallocate(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        layout: Layout,
+    ) {
+        let start = ptr.as_ptr() as usize - this.as_ptr() as usize;
+        if start + layout.size() == this.len() {
+            this.len.set(B::from_native_usize(start).unwrap());
+        }
+    }
+
     fn try_new_in(
         bytes: Slot<'_, [u8]>,
         unique: U,
     ) -> Result<Ref<'_, Self>, SlabError> {
-        let max_cap = bytes.len();
-        let mut out = Self::try_cast_slot_from_bytes(bytes)?;
+        let max_cap = B::from_native_usize(bytes.len())
+            .map_err(|_| SlabError::CapacityOverflow)?;
+        let mut out = try_cast_slot_from_bytes::<Self>(bytes)?;
 
         munge!(
             let SlabControl {
                 root: mut out_root,
                 len: mut out_len,
                 cap: mut out_cap,
+                tag: mut out_tag,
                 unique: mut out_unique,
                 ..
             } = out.as_mut()
@@ -103,7 +300,8 @@ impl<U, B: Basis> SlabControl<U, B> {
         out_len.write(Cell::new(
             B::from_native_usize(Self::LAYOUT.size()).unwrap(),
         ));
-        out_cap.write(Cell::new(B::from_native_usize(max_cap).unwrap()));
+        out_cap.write(Cell::new(max_cap));
+        out_tag.write(Cell::new(U64::from_ne(0)));
         out_unique.write(unique);
 
         Ok(unsafe { Ref::new_unchecked(out.as_ptr()) })
@@ -114,50 +312,53 @@ impl<U, B: Basis> SlabControl<U, B> {
         unique: U,
     ) -> Result<Ref<'_, Self>, SlabError> {
         let max_cap = bytes.len();
-        let slot = Self::try_cast_slot_from_bytes(bytes)?;
+        let slot = try_cast_slot_from_bytes::<Self>(bytes)?;
 
         forget(unique);
         let result = unsafe { Ref::new_unchecked(slot.as_ptr()) };
 
-        if result.len() > result.cap() || result.cap() > max_cap {
-            Err(SlabError)
+        let root = result.root();
+        let len = result.len();
+        let cap = result.cap();
+        if len < Self::LAYOUT.size() || len > cap || cap > max_cap {
+            Err(SlabError::InconsistentHeader { len, cap, max_cap })
+        } else if root != 0 && (root < Self::LAYOUT.size() || root >= len) {
+            Err(SlabError::InvalidRoot { root, len })
         } else {
             Ok(result)
         }
     }
 
-    fn try_cast_slot_from_bytes(
-        slot: Slot<'_, [u8]>,
-    ) -> Result<Slot<'_, Self>, SlabError> {
-        let len = slot.len();
-        if len < Self::LAYOUT.size()
-            || slot.as_ptr() as *mut u8 as usize & (Self::LAYOUT.align() - 1)
-                != 0
-        {
-            Err(SlabError)
-        } else {
-            let slot = unsafe { slot.cast::<Self>() };
-            Ok(slot)
-        }
-    }
-
     fn shrink_to_fit(&self) -> usize {
         let len = self.len.get();
         self.cap.set(len);
         Self::LAYOUT.size() + B::to_native_usize(len).unwrap()
     }
 
-    unsafe fn deposit<T>(this: Ref<'_, Self>, val: *mut T) -> bool {
+    unsafe fn deposit<T>(this: Ref<'_, Self>, val: *mut T, tag: u64) -> bool {
         let base = this.as_ptr() as usize;
         let target = val as usize;
         if this.root() == 0 && target >= base {
             this.root.set(B::from_native_usize(target - base).unwrap());
+            this.tag.set(U64::from_ne(tag));
             true
         } else {
             false
         }
     }
 
+    fn fmt_header(
+        &self,
+        name: &str,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct(name)
+            .field("len", &self.len())
+            .field("cap", &self.cap())
+            .field("root", &self.root())
+            .finish()
+    }
+
     unsafe fn withdraw<T>(this: Ref<'_, Self>) -> Option<*mut T> {
         if this.root() != 0 {
             unsafe {
@@ -202,20 +403,77 @@ impl<'a, U, B: Basis> SlabAllocator<'a, U, B> {
         })
     }
 
+    /// Returns the number of bytes of the slab that are in use.
+    ///
+    /// This is the offset just past the end of the most recent allocation, and
+    /// includes the slab's control header. When the allocation that ends at
+    /// this offset is deallocated, `used` rewinds to the start of it. Freeing
+    /// any other allocation leaves `used` unchanged, and its space is not
+    /// reclaimed even once the allocations after it are freed. So `used` can
+    /// go down as well as up, and may count bytes that are no longer live.
+    pub fn used(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns the total size of the slab in bytes.
+    ///
+    /// This is the length of the buffer the slab was created in until
+    /// [`shrink_to_fit`](Self::shrink_to_fit) is called.
+    pub fn capacity(&self) -> usize {
+        self.inner.cap()
+    }
+
+    /// Returns the number of bytes between the end of the used portion of the
+    /// slab and its capacity.
+    ///
+    /// An allocation of this size may still fail if it needs padding to be
+    /// aligned.
+    pub fn remaining(&self) -> usize {
+        self.inner.cap() - self.inner.len()
+    }
+
     pub fn shrink_to_fit(&self) -> usize {
         self.inner.shrink_to_fit()
     }
 
     pub fn deposit<T>(
+        &self,
+        val: OwnedVal<T, Self>,
+    ) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable,
+        Self: Singleton,
+    {
+        self.deposit_with_tag(val, 0)
+    }
+
+    /// Deposits a root object along with the type tag of `T`.
+    ///
+    /// The root object can later be withdrawn with `withdraw_tagged`, which
+    /// checks that the tag matches.
+    pub fn deposit_tagged<T>(
+        &self,
+        val: OwnedVal<T, Self>,
+    ) -> Option<OwnedVal<T, Self>>
+    where
+        T: DropRaw + Portable + RootTag,
+        Self: Singleton,
+    {
+        self.deposit_with_tag(val, T::TAG)
+    }
+
+    fn deposit_with_tag<T>(
         &self,
         mut val: OwnedVal<T, Self>,
+        tag: u64,
     ) -> Option<OwnedVal<T, Self>>
     where
         T: DropRaw + Portable,
         Self: Singleton,
     {
-        let was_stored =
-            unsafe { SlabControl::deposit(self.inner, val.as_mut().as_ptr()) };
+        let was_stored = unsafe {
+            SlabControl::deposit(self.inner, val.as_mut().as_ptr(), tag)
+        };
         if was_stored {
             forget(val);
             None
@@ -236,6 +494,154 @@ impl<'a, U, B: Basis> SlabAllocator<'a, U, B> {
         let result = unsafe { SlabControl::withdraw(self.inner) };
         result.map(|ptr| unsafe { OwnedVal::from_raw_in(ptr, *self) })
     }
+
+    /// Withdraws a previously-deposited root object after checking that it was
+    /// deposited with the type tag of `T`.
+    ///
+    /// # Safety
+    ///
+    /// Every root object deposited with the tag `T::TAG` must be compatible
+    /// with type `T`. The tag is only checked, not the root object, so this
+    /// must not be relied on for slabs from untrusted sources. See
+    /// [`RootTag`] for why the tag can't stand in for validation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root object was deposited with a different tag.
+    pub unsafe fn withdraw_tagged<T>(
+        &self,
+    ) -> Result<Option<OwnedVal<T, Self>>, TagMismatch>
+    where
+        T: DropRaw + Portable + RootTag,
+    {
+        if self.inner.root() != 0 && self.inner.tag() != T::TAG {
+            return Err(TagMismatch {
+                expected: T::TAG,
+                found: self.inner.tag(),
+            });
+        }
+        Ok(unsafe { self.withdraw_unchecked() })
+    }
+
+    /// Compacts the slab by moving everything reachable from the root object
+    /// to the front of it.
+    ///
+    /// The root object is deep-cloned with `CloneRaw` into a scratch buffer
+    /// with the same layout as the slab, and then copied back over the used
+    /// portion of the slab. Relative pointers between the cloned values and to
+    /// the slab header keep the same offsets, so they are still valid after
+    /// the copy. `used` is then lowered to the end of the compacted values.
+    /// This reclaims the holes left by freed allocations and the space of any
+    /// values which are not reachable from the root object. If no root object
+    /// has been deposited, the whole slab is freed.
+    ///
+    /// The old values are overwritten without being dropped. Values in a slab
+    /// only own memory in that slab, which the compaction reclaims anyway.
+    ///
+    /// # Safety
+    ///
+    /// - The previously-deposited root object must be compatible with type
+    ///   `T`.
+    /// - Cloning the root object must clone everything it points to into the
+    ///   given allocator. Values which point to other values in the slab
+    ///   without owning them would be left pointing into the scratch buffer.
+    /// - No values in the slab may be accessed after it is compacted, except
+    ///   through a newly-withdrawn root object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the compacted values do not fit in the capacity of the slab.
+    #[cfg(feature = "alloc")]
+    pub unsafe fn compact<T>(&self)
+    where
+        T: CloneRaw<Self> + DropRaw + Portable,
+        U: Unique,
+    {
+        let control = self.inner;
+        let header = SlabControl::<U, B>::LAYOUT.size();
+        let root = match unsafe { SlabControl::withdraw::<T>(control) } {
+            Some(root) => root,
+            None => {
+                control.len.set(B::from_native_usize(header).unwrap());
+                return;
+            }
+        };
+
+        let words = control.cap().div_ceil(WORD_SIZE);
+        let mut scratch = vec![Word([0; WORD_SIZE]); words].into_boxed_slice();
+        let scratch_ptr = scratch.as_mut_ptr().cast::<u8>();
+        let slab_ptr = control.as_ptr().cast::<u8>();
+
+        // SAFETY: The scratch buffer is at least as large as the slab, which
+        // always contains a whole header. The scratch buffer is a new
+        // allocation, so it doesn't overlap the slab.
+        unsafe {
+            copy_nonoverlapping(slab_ptr, scratch_ptr, header);
+        }
+        // SAFETY: The scratch buffer is aligned to `WORD_SIZE`, which is the
+        // alignment of the header, and we just copied a valid header into it.
+        // The scratch buffer outlives every use of the scratch allocator.
+        let scratch_alloc: Self = Self {
+            inner: unsafe { Ref::new_unchecked(scratch_ptr.cast()) },
+        };
+        let scratch_control = scratch_alloc.inner;
+        scratch_control.root.set(B::from_native_usize(0).unwrap());
+        scratch_control.len.set(B::from_native_usize(header).unwrap());
+
+        let out = scratch_alloc
+            .allocate(Layout::new::<T>())
+            .unwrap()
+            .as_ptr()
+            .cast::<T>();
+        // SAFETY: The caller has guaranteed that the root object is compatible
+        // with `T`, and `out` was just allocated in the scratch slab with the
+        // layout of `T`. The scratch slab is laid out exactly like the slab,
+        // so its allocations will be in the slab region once copied back.
+        unsafe {
+            T::clone_raw(
+                Ref::new_unchecked(root),
+                scratch_alloc,
+                In::new_unchecked(Slot::new_unchecked(out)),
+            );
+        }
+
+        let len = scratch_control.len();
+        let root = out as usize - scratch_ptr as usize;
+        // SAFETY: `len` is at most the capacity of the scratch slab, which is
+        // the same as the capacity of the slab. The two buffers don't overlap.
+        unsafe {
+            copy_nonoverlapping(
+                scratch_ptr.add(header),
+                slab_ptr.add(header),
+                len - header,
+            );
+        }
+        control.len.set(B::from_native_usize(len).unwrap());
+        control.root.set(B::from_native_usize(root).unwrap());
+    }
+
+    /// Validates and withdraws a previously-deposited root object.
+    ///
+    /// The root object and everything reachable from it are checked before
+    /// it is returned, so this is safe to call on slabs loaded from untrusted
+    /// sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the previously-deposited root object is not a valid
+    /// value of type `T`.
+    pub fn withdraw<T>(
+        &self,
+    ) -> Result<Option<OwnedVal<T, Self>>, ValidateError>
+    where
+        T: DropRaw + Portable + Validate,
+    {
+        if let Some(ptr) = unsafe { SlabControl::withdraw::<T>(self.inner) } {
+            let mut validator = SlabValidator::new(self.inner);
+            validate_ptr(ptr, &mut validator)?;
+        }
+        Ok(unsafe { self.withdraw_unchecked() })
+    }
 }
 
 impl<U, B: Basis> Clone for SlabAllocator<'_, U, B> {
@@ -246,12 +652,31 @@ impl<U, B: Basis> Clone for SlabAllocator<'_, U, B> {
 
 impl<U, B: Basis> Copy for SlabAllocator<'_, U, B> {}
 
+impl<U, B: Basis> fmt::Debug for SlabAllocator<'_, U, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt_header("SlabAllocator", f)
+    }
+}
+
 unsafe impl<U, B: Basis> Allocator for SlabAllocator<'_, U, B> {
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
         SlabControl::allocate(self.inner, layout)
     }
 
-    unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        unsafe { SlabControl::deallocate(self.inner, ptr, layout) }
+    }
+
+    unsafe fn grow_in_place(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        unsafe {
+            SlabControl::grow_in_place(self.inner, ptr, old_layout, new_layout)
+        }
+    }
 }
 
 unsafe impl<'a, U, B1, B2>
@@ -317,10 +742,44 @@ where
     }
 
     unsafe fn raw_deallocate(
-        _this: Ref<'_, Self>,
-        _ptr: NonNull<u8>,
-        _layout: Layout,
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        layout: Layout,
     ) {
+        munge!(let RelSlabAllocator { inner } = this);
+        unsafe { SlabControl::deallocate(RelRef::deref(inner), ptr, layout) }
+    }
+
+    unsafe fn raw_grow_in_place(
+        this: Ref<'_, Self>,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        munge!(let RelSlabAllocator { inner } = this);
+        unsafe {
+            SlabControl::grow_in_place(
+                RelRef::deref(inner),
+                ptr,
+                old_layout,
+                new_layout,
+            )
+        }
+    }
+}
+
+impl<U, B1, B2> DebugRaw for RelSlabAllocator<'_, U, B1, B2>
+where
+    U: Unique,
+    B1: Basis,
+    B2: Basis,
+{
+    fn fmt_raw(
+        this: Ref<'_, Self>,
+        f: &mut fmt::Formatter<'_>,
+    ) -> Result<(), fmt::Error> {
+        munge!(let RelSlabAllocator { inner } = this);
+        RelRef::deref(inner).fmt_header("RelSlabAllocator", f)
     }
 }
 
@@ -351,3 +810,375 @@ where
         MoveExt::r#move(inner, out_inner);
     }
 }
+
+// SAFETY: `validate` checks that the reference points to the slab control,
+// which is always located at the start of the slab being validated.
+unsafe impl<'a, U, B1, B2> Validate for RelSlabAllocator<'a, U, B1, B2>
+where
+    U: Unique,
+    B1: Basis,
+    B2: Basis,
+{
+    unsafe fn validate<V: Validator + ?Sized>(
+        ptr: *const Self,
+        validator: &mut V,
+    ) -> Result<(), ValidateError> {
+        let inner = unsafe { Ref::new_unchecked(addr_of!((*ptr).inner)) };
+        match RelRef::as_ptr_wrapping(inner) {
+            Some(target) if target.cast::<u8>() == validator.base() => Ok(()),
+            Some(target) => Err(ValidateError::OutOfBounds {
+                address: target.cast::<u8>() as usize,
+                size: SlabControl::<U, B1>::LAYOUT.size(),
+            }),
+            None => Err(ValidateError::NullPointer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::forget;
+    use ::mischief::{In, Region, Slot, StaticToken};
+    use ::ptr_meta::Pointee;
+    use ::rel_alloc::{string, EmplaceIn, RelString};
+    use ::rel_core::{
+        rel_tuple::RelTuple3,
+        Bool,
+        Char,
+        DefaultBasis,
+        Emplace,
+        Portable,
+        ValidateError,
+        I32,
+        U32,
+    };
+    use ::rel_util::{read_slab, write_slab, Align16};
+    use ::situ::DropRaw;
+
+    use crate::{
+        RelSlabAllocator,
+        RootTag,
+        SlabAllocator,
+        SlabError,
+        TagMismatch,
+    };
+
+    /// Calls `f` with a slab allocator backed by a fresh zeroed buffer of
+    /// `size` bytes.
+    pub fn with_slab<R>(
+        size: usize,
+        f: impl for<'a, 'id> FnOnce(SlabAllocator<'a, StaticToken<'id>>) -> R,
+    ) -> R {
+        let mut bytes = Align16::frame(size);
+        bytes.slot().zero();
+        StaticToken::acquire(|token| {
+            f(SlabAllocator::try_new_in(bytes.slot().as_bytes(), token)
+                .unwrap())
+        })
+    }
+
+    #[test]
+    fn slab_errors() {
+        StaticToken::acquire(|token| {
+            let mut bytes = Align16::frame(4);
+            let result =
+                SlabAllocator::<_>::try_new_in(bytes.slot().as_bytes(), token);
+            assert!(matches!(
+                result,
+                Err(SlabError::TooSmall { len: 16, .. }),
+            ));
+        });
+
+        StaticToken::acquire(|token| {
+            let mut bytes = Align16::frame(256);
+            bytes.slot().zero();
+            let result = SlabAllocator::<_>::try_from_bytes(
+                bytes.slot().as_bytes(),
+                token,
+            );
+            assert!(matches!(
+                result,
+                Err(SlabError::InconsistentHeader {
+                    len: 0,
+                    cap: 0,
+                    max_cap: 256,
+                }),
+            ));
+        });
+    }
+
+    #[test]
+    fn slab_error_messages() {
+        let messages = [
+            (
+                SlabError::TooSmall { len: 4, min: 16 },
+                "slab of 4 bytes is too small, it must be at least 16 bytes",
+            ),
+            (
+                SlabError::Misaligned {
+                    address: 0x1004,
+                    align: 8,
+                },
+                "slab at address 0x1004 is not aligned to 8 bytes",
+            ),
+            (
+                SlabError::InconsistentHeader {
+                    len: 64,
+                    cap: 32,
+                    max_cap: 256,
+                },
+                "inconsistent slab header with length 64 and capacity 32 in \
+                256 bytes",
+            ),
+            (
+                SlabError::InvalidRoot { root: 128, len: 64 },
+                "slab root offset 128 is outside of the used length 64",
+            ),
+            (SlabError::CapacityOverflow, "slab capacity overflowed"),
+        ];
+        for (error, message) in messages {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn debug() {
+        with_slab(256, |alloc| {
+            assert_eq!(
+                format!("{:?}", alloc),
+                format!(
+                    "SlabAllocator {{ len: {}, cap: 256, root: 0 }}",
+                    alloc.used(),
+                ),
+            );
+
+            let rel_alloc = alloc.emplace_in::<RelSlabAllocator<_>>(alloc);
+            assert_eq!(
+                format!("{:?}", rel_alloc),
+                format!(
+                    "RelSlabAllocator {{ len: {}, cap: 256, root: 0 }}",
+                    alloc.used(),
+                ),
+            );
+        });
+    }
+
+    #[test]
+    fn withdraw_tagged() {
+        #[derive(DropRaw, Portable)]
+        #[repr(transparent)]
+        struct Version(U32);
+
+        impl RootTag for Version {
+            const TAG: u64 = 1;
+        }
+
+        // SAFETY:
+        // - `emplaced_meta` returns `()`, the only valid metadata for `Sized`
+        //   types.
+        // - `emplace_unsized_unchecked` initializes its `out` parameter by
+        //   writing a `Version` to it.
+        unsafe impl<R: Region> Emplace<Version, R> for u32 {
+            fn emplaced_meta(&self) -> <Version as Pointee>::Metadata {}
+
+            unsafe fn emplace_unsized_unchecked(
+                self,
+                out: In<Slot<'_, Version>, R>,
+            ) {
+                In::into_inner(out).write(Version(U32::from_ne(self)));
+            }
+        }
+
+        #[derive(DropRaw, Portable)]
+        #[repr(transparent)]
+        struct Count(U32);
+
+        impl RootTag for Count {
+            const TAG: u64 = 2;
+        }
+
+        with_slab(256, |alloc| {
+            let version = 3.emplace_in::<Version>(alloc);
+            assert!(alloc.deposit_tagged(version).is_none());
+
+            assert_eq!(
+                unsafe { alloc.withdraw_tagged::<Count>() }.err(),
+                Some(TagMismatch {
+                    expected: 2,
+                    found: 1,
+                }),
+            );
+            let version = unsafe { alloc.withdraw_tagged::<Version>() }
+                .unwrap()
+                .unwrap();
+            assert_eq!(version.0.to_ne(), 3);
+        });
+    }
+
+    #[test]
+    fn validate_withdraw() {
+        type Root<'a, 'id> = RelTuple3<
+            Bool,
+            Char,
+            RelString<RelSlabAllocator<'a, StaticToken<'id>>>,
+        >;
+
+        with_slab(256, |alloc| {
+            let root = (true, 'x', string::Clone(alloc, "hello"))
+                .emplace_in::<Root<'_, '_>>(alloc);
+            assert!(alloc.deposit(root).is_none());
+
+            let mut root = alloc.withdraw::<Root<'_, '_>>().unwrap().unwrap();
+            let ptr = root.as_mut().as_ptr();
+            forget(root);
+
+            // SAFETY: `RelTuple3` is `repr(C)`, so its `Bool` is the first
+            // byte.
+            unsafe { ptr.cast::<u8>().write(2) };
+            assert_eq!(
+                alloc.withdraw::<Root<'_, '_>>().err(),
+                Some(ValidateError::InvalidBool(2)),
+            );
+            // SAFETY: `RelTuple3` is `repr(C)`, so its `Char` immediately
+            // follows its `Bool` at an offset of 4 bytes.
+            unsafe {
+                ptr.cast::<u8>().write(1);
+                ptr.cast::<U32>().add(1).write(U32::from_ne(0xd800));
+            }
+            assert_eq!(
+                alloc.withdraw::<Root<'_, '_>>().err(),
+                Some(ValidateError::InvalidChar(0xd800)),
+            );
+            // SAFETY: `ptr` still points to the `Char` of the root object.
+            unsafe { ptr.cast::<U32>().add(1).write(U32::from_ne(0x78)) };
+            assert!(alloc.withdraw::<Root<'_, '_>>().is_ok());
+        });
+    }
+
+    #[test]
+    fn write_read_slab() {
+        let mut bytes = Align16::frame(256);
+        bytes.slot().zero();
+        let used = StaticToken::acquire(|token| {
+            let alloc =
+                SlabAllocator::<_>::try_new_in(bytes.slot().as_bytes(), token)
+                    .unwrap();
+            let int = 42.emplace_in::<I32>(alloc);
+            assert!(alloc.deposit(int).is_none());
+            alloc.shrink_to_fit()
+        });
+
+        // SAFETY: `bytes` was zeroed, so all of its bytes are initialized.
+        let slab = unsafe { bytes.slot().as_bytes().assume_init_ref() };
+        let mut written = Vec::new();
+        write_slab::<DefaultBasis, _>(slab, used, &mut written).unwrap();
+
+        let mut corrupted = written.clone();
+        corrupted[0] ^= 0xff;
+        assert!(
+            read_slab::<DefaultBasis, _>(&mut corrupted.as_slice()).is_err()
+        );
+
+        let mut frame =
+            read_slab::<DefaultBasis, _>(&mut written.as_slice()).unwrap();
+        StaticToken::acquire(|token| {
+            let alloc = SlabAllocator::<_>::try_from_bytes(
+                frame.slot().as_bytes(),
+                token,
+            )
+            .unwrap();
+            let int = unsafe { alloc.withdraw_unchecked::<I32>().unwrap() };
+            assert_eq!(int.to_ne(), 42);
+        });
+    }
+
+    #[test]
+    fn deallocate_rewinds_trailing() {
+        with_slab(256, |alloc| {
+            let a = 1u32.emplace_in::<U32>(alloc);
+            let after_a = alloc.used();
+            let b = 2u32.emplace_in::<U32>(alloc);
+            let after_b = alloc.used();
+            assert!(after_b > after_a);
+
+            // Freeing an allocation which isn't the most recent one doesn't
+            // reclaim its space.
+            drop(a);
+            assert_eq!(alloc.used(), after_b);
+
+            // Freeing the most recent allocation rewinds to its start.
+            drop(b);
+            assert_eq!(alloc.used(), after_a);
+        });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compact() {
+        use ::mischief::Unique;
+        use ::rel_alloc::{vec, RelVec};
+        use ::situ::ops::IndexMutRaw;
+
+        type Inner<'a, U> = RelVec<U32, RelSlabAllocator<'a, U>>;
+        type Root<'a, U> = RelVec<Inner<'a, U>, RelSlabAllocator<'a, U>>;
+
+        fn check<U: Unique>(alloc: SlabAllocator<'_, U>) {
+            let root = alloc.withdraw::<Root<'_, U>>().unwrap().unwrap();
+            assert_eq!(root.len(), 2);
+            for (j, inner) in RelVec::iter_enumerated(root.as_ref()) {
+                let j = u32::try_from(j).unwrap();
+                assert!(RelVec::iter(inner)
+                    .map(|value| value.to_ne())
+                    .eq((0..32).map(|i| i * 2 + j)));
+            }
+            forget(root);
+        }
+
+        with_slab(4096, |alloc| {
+            let mut root = vec::New(alloc).emplace_in::<Root<'_, _>>(alloc);
+            RelVec::push(root.as_mut(), vec::New(alloc));
+            RelVec::push(root.as_mut(), vec::New(alloc));
+
+            // Growing the inner vecs in turn frees their old buffers from the
+            // middle of the slab.
+            for i in 0..32 {
+                for j in 0..2 {
+                    let inner = IndexMutRaw::index_mut_raw(root.as_mut(), j);
+                    RelVec::push(inner, i * 2 + u32::try_from(j).unwrap());
+                }
+            }
+            assert!(alloc.deposit(root).is_none());
+            check(alloc);
+
+            let before = alloc.used();
+            unsafe {
+                alloc.compact::<Root<'_, _>>();
+            }
+            assert!(alloc.used() < before);
+            check(alloc);
+
+            // Compacting again doesn't find anything else to reclaim.
+            let compacted = alloc.used();
+            unsafe {
+                alloc.compact::<Root<'_, _>>();
+            }
+            assert_eq!(alloc.used(), compacted);
+            check(alloc);
+        });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compact_without_root() {
+        with_slab(256, |alloc| {
+            let empty = alloc.used();
+            forget(1u32.emplace_in::<U32>(alloc));
+            assert!(alloc.used() > empty);
+
+            unsafe {
+                alloc.compact::<U32>();
+            }
+            assert_eq!(alloc.used(), empty);
+        });
+    }
+}
@@ -0,0 +1,91 @@
+use ::core::alloc::Layout;
+use ::rel_core::{Basis, ValidateError, Validator};
+use ::situ::Ref;
+use ::std::collections::BTreeMap;
+
+use crate::SlabControl;
+
+/// A validator for the used portion of a slab.
+///
+/// Claims are tracked as offsets from the start of the slab. The slab control
+/// is claimed up front so that no value can overlap it.
+pub struct SlabValidator {
+    base: *const u8,
+    len: usize,
+    claims: BTreeMap<usize, usize>,
+}
+
+impl SlabValidator {
+    pub fn new<U, B: Basis>(control: Ref<'_, SlabControl<U, B>>) -> Self {
+        Self::from_parts(
+            control.as_ptr().cast(),
+            SlabControl::<U, B>::LAYOUT.size(),
+            control.len(),
+        )
+    }
+
+    /// Creates a validator for a slab starting at `base` with a slab control
+    /// of `control_size` bytes and `len` used bytes.
+    pub fn from_parts(
+        base: *const u8,
+        control_size: usize,
+        len: usize,
+    ) -> Self {
+        let mut claims = BTreeMap::new();
+        claims.insert(0, control_size);
+
+        Self { base, len, claims }
+    }
+}
+
+unsafe impl Validator for SlabValidator {
+    fn base(&self) -> *const u8 {
+        self.base
+    }
+
+    fn claim(
+        &mut self,
+        ptr: *const u8,
+        layout: Layout,
+    ) -> Result<(), ValidateError> {
+        let address = ptr as usize;
+        let size = layout.size();
+
+        if address & (layout.align() - 1) != 0 {
+            return Err(ValidateError::Misaligned {
+                address,
+                align: layout.align(),
+            });
+        }
+
+        // Zero-sized values don't occupy any memory, so they can't be out of
+        // bounds or overlap with anything.
+        if size == 0 {
+            return if address == 0 {
+                Err(ValidateError::NullPointer)
+            } else {
+                Ok(())
+            };
+        }
+
+        let start = address.wrapping_sub(self.base as usize);
+        if address < self.base as usize
+            || start > self.len
+            || size > self.len - start
+        {
+            return Err(ValidateError::OutOfBounds { address, size });
+        }
+        let end = start + size;
+
+        // Claims never overlap each other, so the claim which starts closest
+        // before `end` is the only one which could overlap this one.
+        if let Some((_, &prev_end)) = self.claims.range(..end).next_back() {
+            if prev_end > start {
+                return Err(ValidateError::Overlapping { address, size });
+            }
+        }
+        self.claims.insert(start, end);
+
+        Ok(())
+    }
+}
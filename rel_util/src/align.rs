@@ -0,0 +1,274 @@
+use ::core::{
+    alloc::Layout,
+    mem::{align_of, size_of},
+};
+use ::heresy::alloc::AllocError;
+use ::mischief::{Frame, Metadata};
+use ::ptr_meta::Pointee;
+
+/// An alignment of `N` bytes.
+///
+/// Only the alignments which implement [`SupportedAlignment`] can be used with
+/// [`AlignTo`]. These are the powers of two from 1 to 4096.
+pub struct Alignment<const N: usize>;
+
+/// An [`Alignment`] which can be used with [`AlignTo`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SupportedAlignment: sealed::Sealed {
+    /// A zero-sized type with this alignment.
+    type Archetype: Clone + Copy + ::core::fmt::Debug;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+mod archetypes {
+    use super::{sealed::Sealed, Alignment, SupportedAlignment};
+
+    macro_rules! impl_alignment {
+        ($($name:ident = $n:literal),* $(,)?) => {
+            $(
+                #[derive(Clone, Copy, Debug)]
+                #[repr(align($n))]
+                pub struct $name;
+
+                impl Sealed for Alignment<$n> {}
+
+                impl SupportedAlignment for Alignment<$n> {
+                    type Archetype = $name;
+                }
+            )*
+        };
+    }
+
+    impl_alignment! {
+        Archetype1 = 1,
+        Archetype2 = 2,
+        Archetype4 = 4,
+        Archetype8 = 8,
+        Archetype16 = 16,
+        Archetype32 = 32,
+        Archetype64 = 64,
+        Archetype128 = 128,
+        Archetype256 = 256,
+        Archetype512 = 512,
+        Archetype1024 = 1024,
+        Archetype2048 = 2048,
+        Archetype4096 = 4096,
+    }
+}
+
+/// A type that aligns its contents to `N`-byte boundaries.
+///
+/// `N` must be a power of two from 1 to 4096.
+#[derive(Debug)]
+#[repr(C)]
+pub struct AlignTo<const N: usize, T: ?Sized>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    _align: [<Alignment<N> as SupportedAlignment>::Archetype; 0],
+    /// The aligned value.
+    pub value: T,
+}
+
+/// A type that aligns its contents to 16-byte boundaries.
+pub type Align16<T> = AlignTo<16, T>;
+
+impl<const N: usize, T: ?Sized> AlignTo<N, T>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    /// The alignment of this type in bytes.
+    pub const ALIGN: usize = {
+        assert!(N.is_power_of_two(), "`N` must be a power of two");
+        N
+    };
+}
+
+impl<const N: usize, T> AlignTo<N, T>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    /// Returns a new `AlignTo` containing the given value.
+    pub const fn new(value: T) -> Self {
+        Self { _align: [], value }
+    }
+}
+
+impl<const N: usize> AlignTo<N, [u8]>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    /// Returns the size of the frame that would be allocated for the given
+    /// requested size.
+    ///
+    /// This is `size` rounded up to the next multiple of `N`. Returns `None` if
+    /// the rounded size overflows or is too large to fit in a valid layout.
+    pub fn rounded_size(size: usize) -> Option<usize> {
+        let mask = Self::ALIGN - 1;
+        let rounded = size.checked_add(mask)? & !mask;
+        Layout::from_size_align(rounded, Self::ALIGN).ok()?;
+        Some(rounded)
+    }
+
+    /// Returns a new [`Frame`] of at least the given size.
+    ///
+    /// The actual size of the frame is [`rounded_size(size)`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame size overflows or the frame could not be allocated.
+    /// See [`try_frame`] for a non-panicking version.
+    ///
+    /// [`rounded_size(size)`]: Self::rounded_size
+    /// [`try_frame`]: Self::try_frame
+    pub fn frame(size: usize) -> Frame<Self> {
+        Self::try_frame(size).expect("failed to allocate an `AlignTo` frame")
+    }
+
+    /// Returns a new [`Frame`] of at least the given size, or `None` if the
+    /// frame size overflows or the frame could not be allocated.
+    ///
+    /// The actual size of the frame is [`rounded_size(size)`].
+    ///
+    /// [`rounded_size(size)`]: Self::rounded_size
+    pub fn try_frame(size: usize) -> Option<Frame<Self>> {
+        // SAFETY: A length of zero is always valid for `AlignTo<N, [u8]>`.
+        let mut frame = unsafe { Frame::new_unsized(0) };
+        Self::grow_frame(&mut frame, size).ok()?;
+        Some(frame)
+    }
+
+    /// Grows the given [`Frame`] to at least the given size.
+    ///
+    /// This allows a frame to start small and expand as its required size is
+    /// discovered. The existing contents of the frame are preserved, but it
+    /// may be moved to a new location in memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is less than the current size of the frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the frame could not be grown. When this happens, the
+    /// frame is left unchanged.
+    pub fn grow_frame(
+        frame: &mut Frame<Self>,
+        size: usize,
+    ) -> Result<(), AllocError> {
+        let len = ::ptr_meta::metadata(frame.as_ptr());
+        assert!(
+            size >= len,
+            "`size` must be greater than or equal to the current size",
+        );
+        let metadata = Self::rounded_size(size).ok_or(AllocError)?;
+        // SAFETY:
+        // - `metadata` is a multiple of `N` which fits in a valid layout.
+        // - `metadata` is at least `size`, which is at least the current size
+        //   of the frame.
+        unsafe { frame.grow_unsized(metadata) }
+    }
+}
+
+impl<const N: usize, T> Pointee for AlignTo<N, [T]>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    type Metadata = <[T] as Pointee>::Metadata;
+}
+
+// SAFETY: `pointee_layout` returns the layout for a `T` slice of length `self`
+// aligned to `N` bytes, or to the alignment of `T` if it is greater.
+unsafe impl<const N: usize, T> Metadata<AlignTo<N, [T]>> for usize
+where
+    Alignment<N>: SupportedAlignment,
+{
+    unsafe fn pointee_layout(self) -> Layout {
+        // SAFETY:
+        // - The caller has guaranteed that `self` is a valid length for
+        //   `AlignTo<N, [T]>`, so the size of its elements does not overflow.
+        // - `AlignTo<N, [T]>` has the greater of the alignments of `N` and
+        //   `T`. `N` is a power of two because `Alignment<N>` implements
+        //   `SupportedAlignment`, and the alignment of `T` is always a power
+        //   of two, so the greater of them is as well.
+        unsafe {
+            Layout::from_size_align_unchecked(
+                size_of::<T>() * self,
+                AlignTo::<N, [T]>::ALIGN.max(align_of::<T>()),
+            )
+        }
+    }
+}
+
+impl<const N: usize> Pointee for AlignTo<N, str>
+where
+    Alignment<N>: SupportedAlignment,
+{
+    type Metadata = <str as Pointee>::Metadata;
+}
+
+// SAFETY: `pointee_layout` returns the layout for a `str` of length `self`
+// aligned to `N` bytes.
+unsafe impl<const N: usize> Metadata<AlignTo<N, str>> for usize
+where
+    Alignment<N>: SupportedAlignment,
+{
+    unsafe fn pointee_layout(self) -> Layout {
+        // SAFETY: The caller has guaranteed that `self` is a valid length for
+        // `AlignTo<N, str>`, which always has an alignment of `N`. `N` is a
+        // power of two because `Alignment<N>` implements `SupportedAlignment`.
+        unsafe {
+            Layout::from_size_align_unchecked(self, AlignTo::<N, str>::ALIGN)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::core::mem::align_of_val;
+    use ::mischief::{layout_of_val_raw, Frame};
+
+    use crate::{Align16, AlignTo};
+
+    #[test]
+    fn align16_try_frame() {
+        assert_eq!(Align16::rounded_size(0), Some(0));
+        assert_eq!(Align16::rounded_size(17), Some(32));
+        assert_eq!(Align16::rounded_size(usize::MAX), None);
+
+        let frame = Align16::try_frame(17).unwrap();
+        assert_eq!(::ptr_meta::metadata(frame.as_ptr()), 32);
+        assert!(Align16::try_frame(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn align_to_frame() {
+        assert_eq!(AlignTo::<64, [u8]>::rounded_size(65), Some(128));
+
+        let frame = AlignTo::<64, [u8]>::frame(65);
+        assert_eq!(::ptr_meta::metadata(frame.as_ptr()), 128);
+        assert_eq!(frame.as_ptr().cast::<u8>().align_offset(64), 0);
+
+        let value = AlignTo::<32, _>::new(1u8);
+        assert_eq!(align_of_val(&value), 32);
+    }
+
+    #[test]
+    fn align16_unsized_frames() {
+        // SAFETY: `5` is a valid length for `Align16<str>`.
+        let frame = unsafe { Frame::<Align16<str>>::new_unsized(5) };
+        let layout = layout_of_val_raw(frame.as_ptr());
+        assert_eq!((layout.size(), layout.align()), (5, 16));
+        assert_eq!(frame.as_ptr().cast::<u8>().align_offset(16), 0);
+
+        // SAFETY: `3` is a valid length for `Align16<[u32]>`.
+        let frame = unsafe { Frame::<Align16<[u32]>>::new_unsized(3) };
+        let layout = layout_of_val_raw(frame.as_ptr());
+        assert_eq!((layout.size(), layout.align()), (12, 16));
+        assert_eq!(frame.as_ptr().cast::<u8>().align_offset(16), 0);
+    }
+}
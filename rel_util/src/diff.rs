@@ -0,0 +1,115 @@
+use ::core::fmt;
+use ::situ::{
+    diff::{DiffPath, DiffRaw, PathSegment},
+    Ref,
+};
+use ::std::vec::Vec;
+
+/// The path from a value to the first field which differs from another value.
+///
+/// This is returned by [`diff`]. It displays like a Rust field access, for
+/// example `.items[3].name`. An empty path means that the values differ in
+/// their entirety.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FieldPath {
+    segments: Vec<PathSegment>,
+}
+
+impl FieldPath {
+    /// Returns the segments of the path, from the outermost to the innermost.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+// Segments are pushed from the innermost outward, and then reversed by `diff`.
+impl DiffPath for FieldPath {
+    fn push_segment(&mut self, segment: PathSegment) {
+        self.segments.push(segment);
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in self.segments.iter() {
+            fmt::Display::fmt(segment, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two values by their logical contents and returns the path to the
+/// first field which differs, or `None` if they are the same.
+///
+/// Relative pointers are compared by the values they point to rather than by
+/// their offsets, so values from two separately-built buffers can be diffed
+/// even if their layouts differ.
+pub fn diff<T: DiffRaw + ?Sized>(
+    a: Ref<'_, T>,
+    b: Ref<'_, T>,
+) -> Option<FieldPath> {
+    let mut path = FieldPath::default();
+    if DiffRaw::diff_raw(a, b, &mut path) {
+        path.segments.reverse();
+        Some(path)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::core::mem::MaybeUninit;
+    use ::mischief::{In, Region, Slot};
+    use ::rel_core::{option::RelOption, EmplaceExt, U32};
+    use ::situ::{diff::DiffRaw, DropRaw, Ref};
+    use ::std::string::ToString;
+
+    use crate::diff;
+
+    struct Local;
+
+    // SAFETY: `Local` is only used to emplace `RelOption`s, which don't
+    // contain any relative pointers.
+    unsafe impl Region for Local {}
+
+    #[derive(DiffRaw, DropRaw)]
+    #[repr(C)]
+    struct Point {
+        x: U32,
+        y: RelOption<U32>,
+    }
+
+    fn point(x: u32, y: Option<u32>) -> Point {
+        let mut out = MaybeUninit::uninit();
+        let slot = Slot::new(&mut out);
+        // SAFETY: `out` is located in `Local`.
+        y.emplace(unsafe { In::<_, Local>::new_unchecked(slot) });
+        Point {
+            x: U32::from_ne(x),
+            // SAFETY: `out` was just initialized, and a `RelOption<U32>` can be
+            // moved because it doesn't contain any relative pointers.
+            y: unsafe { out.assume_init() },
+        }
+    }
+
+    #[test]
+    fn field_path() {
+        let a = point(1, Some(2));
+        // SAFETY: The `Point`s are valid and never mutated.
+        let diff = |a: &Point, b: &Point| unsafe {
+            diff(Ref::new_unchecked(a), Ref::new_unchecked(b))
+        };
+
+        // The values are in different places, but compare the same.
+        assert!(diff(&a, &point(1, Some(2))).is_none());
+
+        let path = diff(&a, &point(1, Some(3))).unwrap();
+        assert_eq!(path.to_string(), ".y::Some.0");
+        let path = diff(&a, &point(1, None)).unwrap();
+        assert_eq!(path.to_string(), ".y");
+        let path = diff(&a, &point(2, Some(2))).unwrap();
+        assert_eq!(path.to_string(), ".x");
+        assert_eq!(path.segments().len(), 1);
+    }
+}
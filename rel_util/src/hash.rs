@@ -0,0 +1,142 @@
+use ::core::hash::Hasher;
+use ::situ::{hash::HashRaw, Ref};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher which produces the same hashes on every platform.
+///
+/// Unlike the default implementations of `Hasher`, `FnvHasher` writes integers
+/// in little-endian byte order, and writes `usize`s and `isize`s as 64-bit
+/// integers. Hashes of the same values are therefore the same regardless of the
+/// target's endianness or pointer width.
+///
+/// FNV is fast for small inputs but is not resistant to collision attacks, so
+/// it shouldn't be used to hash untrusted data in a `HashMap`.
+#[derive(Clone, Copy, Debug)]
+pub struct FnvHasher {
+    state: u64,
+}
+
+impl FnvHasher {
+    /// Returns a new `FnvHasher`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Default for FnvHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_write_le {
+    ($($fn:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[inline]
+            fn $fn(&mut self, i: $ty) {
+                self.write(&i.to_le_bytes());
+            }
+        )*
+    };
+}
+
+impl Hasher for FnvHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= u64::from(byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    impl_write_le! {
+        write_u16: u16,
+        write_u32: u32,
+        write_u64: u64,
+        write_u128: u128,
+        write_i16: i16,
+        write_i32: i32,
+        write_i64: i64,
+        write_i128: i128,
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(u64::try_from(i).unwrap());
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i64::try_from(i).unwrap());
+    }
+}
+
+/// Returns a hash of the logical contents of a value.
+///
+/// The value is hashed with its `HashRaw` implementation, so relative pointers
+/// are hashed by the values they point to and padding bytes are never read.
+/// Two equal values have the same content hash even if they were written to
+/// different buffers at different offsets.
+///
+/// Values are hashed with [`FnvHasher`]. Because the portable primitives have
+/// the same representation on every platform and `FnvHasher` doesn't depend on
+/// the target's endianness or pointer width, content hashes are stable across
+/// platforms and runs. They may change between versions of `rel` if a type's
+/// `HashRaw` implementation changes, so they shouldn't be persisted across
+/// upgrades.
+#[inline]
+pub fn content_hash<T: HashRaw + ?Sized>(this: Ref<'_, T>) -> u64 {
+    content_hash_with(this, FnvHasher::new())
+}
+
+/// Returns a hash of the logical contents of a value using the given `Hasher`.
+///
+/// This is like [`content_hash`], but allows a different hasher to be used.
+/// Content hashes are only stable across platforms if the hasher is.
+#[inline]
+pub fn content_hash_with<T: HashRaw + ?Sized, H: Hasher>(
+    this: Ref<'_, T>,
+    mut hasher: H,
+) -> u64 {
+    HashRaw::hash_raw(this, &mut hasher);
+    hasher.finish()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use ::rel_core::U32;
+    use ::situ::Ref;
+
+    use crate::content_hash;
+
+    #[test]
+    fn stable_content_hash() {
+        let (one, other_one, two) =
+            (U32::from_ne(1), U32::from_ne(1), U32::from_ne(2));
+        // SAFETY: The values are valid and never mutated.
+        let (one, other_one, two) = unsafe {
+            (
+                Ref::new_unchecked(&one),
+                Ref::new_unchecked(&other_one),
+                Ref::new_unchecked(&two),
+            )
+        };
+
+        // Content hashes are the same on every platform.
+        assert_eq!(content_hash(one), 0xad2a_ca77_4798_5764);
+        // Equal values hash the same even though they're in different places.
+        assert_eq!(content_hash(one), content_hash(other_one));
+        assert_ne!(content_hash(one), content_hash(two));
+    }
+}
@@ -0,0 +1,287 @@
+use ::core::mem::size_of;
+use ::mischief::Frame;
+#[cfg(feature = "mmap")]
+use ::mischief::Slot;
+use ::rel_core::{Basis, U16};
+use ::std::{
+    fs::File,
+    io::{self, Read, Write},
+    path::Path,
+    vec::Vec,
+};
+
+use crate::Align16;
+
+const SLAB_MAGIC: [u8; 4] = *b"rel\0";
+const SLAB_VERSION: u16 = 2;
+
+/// The size of the header written by [`write_slab`].
+///
+/// This is a multiple of 16 bytes, so slab bytes which follow the header keep
+/// their alignment.
+pub const SLAB_HEADER_SIZE: usize = 16;
+
+fn basis_tag<B: Basis>() -> u8 {
+    u8::try_from(size_of::<B::Usize>()).unwrap()
+}
+
+fn endian_tag() -> u8 {
+    let one = U16::from_ne(1);
+    // SAFETY: `U16` is two bytes in size, so its first byte is valid for
+    // reads. The first byte is `1` if portable primitives are little-endian
+    // and `0` if they are big-endian.
+    unsafe { ::core::ptr::addr_of!(one).cast::<u8>().read() }
+}
+
+fn invalid_data(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn file_len(file: &File) -> io::Result<usize> {
+    usize::try_from(file.metadata()?.len())
+        .map_err(|_| invalid_data("file is too large"))
+}
+
+/// Reads the contents of a file into a new 16-byte aligned [`Frame`].
+///
+/// The frame is zero-padded to the next multiple of 16 bytes, and every byte of
+/// it is initialized.
+///
+/// # Errors
+///
+/// Returns `Err` if the file could not be opened or read.
+pub fn read_aligned<P: AsRef<Path>>(
+    path: P,
+) -> io::Result<Frame<Align16<[u8]>>> {
+    let mut file = File::open(path)?;
+    let len = file_len(&file)?;
+    read_frame(&mut file, len)
+}
+
+/// Writes the used bytes of a slab to a writer, preceded by a header.
+///
+/// The header contains a magic number, a format version, tags for the basis `B`
+/// and the endianness of the portable primitives, and the number of bytes that
+/// follow. The written slab can be read back with [`read_slab`].
+///
+/// `used` is the number of bytes of `bytes` which are used by the slab, for
+/// example as returned from `SlabAllocator::shrink_to_fit`.
+///
+/// # Errors
+///
+/// Returns `Err` if `used` is greater than the length of `bytes`, or if writing
+/// fails.
+pub fn write_slab<B: Basis, W: Write>(
+    bytes: &[u8],
+    used: usize,
+    w: &mut W,
+) -> io::Result<()> {
+    let used_bytes = bytes.get(..used).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "used length is greater than the slab length",
+        )
+    })?;
+    let len = u64::try_from(used).map_err(|_| {
+        io::Error::new(io::ErrorKind::InvalidInput, "slab is too large")
+    })?;
+
+    let mut header = [0; SLAB_HEADER_SIZE];
+    header[0..4].copy_from_slice(&SLAB_MAGIC);
+    header[4..6].copy_from_slice(&SLAB_VERSION.to_le_bytes());
+    header[6] = basis_tag::<B>();
+    header[7] = endian_tag();
+    header[8..16].copy_from_slice(&len.to_le_bytes());
+
+    w.write_all(&header)?;
+    w.write_all(used_bytes)
+}
+
+/// Reads a slab written by [`write_slab`] into a new 16-byte aligned
+/// [`Frame`].
+///
+/// The header is validated before the slab bytes are read. The length in the
+/// header is not trusted: the bytes are read until it is reached or the reader
+/// runs out, so a corrupted length can't cause a huge allocation up front. The
+/// returned frame can be passed to `SlabAllocator::try_from_bytes`.
+///
+/// # Errors
+///
+/// Returns `Err` if reading fails, if the header is invalid or does not match
+/// the basis `B` and the endianness of the portable primitives, or if the
+/// reader runs out of bytes before the length in the header.
+pub fn read_slab<B: Basis, R: Read>(
+    r: &mut R,
+) -> io::Result<Frame<Align16<[u8]>>> {
+    let mut header = [0; SLAB_HEADER_SIZE];
+    r.read_exact(&mut header)?;
+
+    if header[0..4] != SLAB_MAGIC {
+        return Err(invalid_data("invalid slab magic number"));
+    }
+    if header[4..6] != SLAB_VERSION.to_le_bytes() {
+        return Err(invalid_data("unsupported slab version"));
+    }
+    if header[6] != basis_tag::<B>() {
+        return Err(invalid_data("slab basis does not match"));
+    }
+    if header[7] != endian_tag() {
+        return Err(invalid_data("slab endianness does not match"));
+    }
+    let mut len_bytes = [0; 8];
+    len_bytes.copy_from_slice(&header[8..16]);
+    let len_u64 = u64::from_le_bytes(len_bytes);
+    let len = usize::try_from(len_u64)
+        .map_err(|_| invalid_data("slab is too large"))?;
+
+    let mut data = Vec::new();
+    r.take(len_u64).read_to_end(&mut data)?;
+    if data.len() != len {
+        return Err(invalid_data("slab is truncated"));
+    }
+
+    let mut frame = Align16::frame(len);
+    let mut bytes = frame.slot().as_bytes();
+    bytes.zero();
+    // SAFETY: Every byte of `bytes` was just initialized to zero.
+    let bytes = unsafe { bytes.assume_init_mut() };
+    bytes[..len].copy_from_slice(&data);
+
+    Ok(frame)
+}
+
+fn read_frame<R: Read>(
+    r: &mut R,
+    len: usize,
+) -> io::Result<Frame<Align16<[u8]>>> {
+    let mut frame = Align16::frame(len);
+    let mut bytes = frame.slot().as_bytes();
+    bytes.zero();
+    // SAFETY: Every byte of `bytes` was just initialized to zero.
+    let bytes = unsafe { bytes.assume_init_mut() };
+    r.read_exact(&mut bytes[..len])?;
+
+    Ok(frame)
+}
+
+/// A memory-mapped file which is aligned to at least 16 bytes.
+///
+/// The file is mapped copy-on-write, so it is loaded without copying and writes
+/// to the mapping are never written back to the file.
+#[cfg(feature = "mmap")]
+pub struct AlignedMap {
+    map: ::memmap2::MmapMut,
+}
+
+#[cfg(feature = "mmap")]
+impl AlignedMap {
+    /// Returns the length of the mapped file in bytes.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns whether the mapped file is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns a [`Slot`] of the mapped bytes.
+    ///
+    /// The slot is guaranteed to be aligned to at least 16 bytes.
+    pub fn slot(&mut self) -> Slot<'_, [u8]> {
+        let ptr = ::core::ptr::slice_from_raw_parts_mut(
+            self.map.as_mut_ptr(),
+            self.map.len(),
+        );
+        // SAFETY:
+        // - `ptr` points to the mapped bytes, so it is non-null and valid for
+        //   reads and writes. Pointers to `u8` are always properly aligned.
+        // - `ptr` does not alias any other accessible references because
+        //   `self` is mutably borrowed.
+        unsafe { Slot::new_unchecked(ptr) }
+    }
+}
+
+/// Maps a file into memory without copying it.
+///
+/// The mapping is aligned to the page size of the system, which is always at
+/// least 16 bytes.
+///
+/// # Safety
+///
+/// The file must not be modified, truncated, or deleted by this or any other
+/// process while it is mapped. Doing so may cause the mapped bytes to change or
+/// become inaccessible.
+///
+/// # Errors
+///
+/// Returns `Err` if the file could not be opened or mapped.
+#[cfg(feature = "mmap")]
+pub unsafe fn map_aligned<P: AsRef<Path>>(path: P) -> io::Result<AlignedMap> {
+    let file = File::open(path)?;
+    // SAFETY: The caller has guaranteed that the file will not be modified
+    // while it is mapped.
+    let map = unsafe { ::memmap2::MmapOptions::new().map_copy(&file)? };
+    debug_assert_eq!(map.as_ptr().align_offset(16), 0);
+    Ok(AlignedMap { map })
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rel_core::DefaultBasis;
+    use ::std::{format, io::ErrorKind, time::SystemTime, vec::Vec};
+
+    use crate::{read_aligned, read_slab, write_slab};
+
+    #[test]
+    fn read_aligned_pads() {
+        let nanos = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let name = format!(
+            "rel_test_read_aligned_{}_{}.bin",
+            ::std::process::id(),
+            nanos,
+        );
+        let path = ::std::env::temp_dir().join(name);
+        ::std::fs::write(&path, b"hello world").unwrap();
+
+        let mut frame = read_aligned(&path).unwrap();
+        ::std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(frame.as_ptr().cast::<u8>().align_offset(16), 0);
+        let bytes = frame.slot().as_bytes();
+        assert_eq!(bytes.len(), 16);
+        // SAFETY: `read_aligned` initializes every byte of the frame.
+        let bytes = unsafe { bytes.assume_init_ref() };
+        assert_eq!(&bytes[..11], b"hello world");
+        assert!(bytes[11..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn read_slab_truncated() {
+        let mut written = Vec::new();
+        write_slab::<DefaultBasis, _>(&[1; 32], 32, &mut written).unwrap();
+
+        let mut truncated = &written[..written.len() - 1];
+        let error = read_slab::<DefaultBasis, _>(&mut truncated).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        // A corrupted length is checked against the bytes which are actually
+        // read instead of being allocated up front.
+        let mut corrupted = written.clone();
+        corrupted[8..16].copy_from_slice(&(1u64 << 40).to_le_bytes());
+        let error = read_slab::<DefaultBasis, _>(&mut corrupted.as_slice())
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+
+        let mut frame =
+            read_slab::<DefaultBasis, _>(&mut written.as_slice()).unwrap();
+        let bytes = frame.slot().as_bytes();
+        // SAFETY: `read_slab` initializes every byte of the frame.
+        let bytes = unsafe { bytes.assume_init_ref() };
+        assert!(bytes.iter().all(|&b| b == 1));
+    }
+}
@@ -0,0 +1,37 @@
+use ::mischief::{In, RegionalAllocator, Slot};
+pub use ::situ_derive::CloneRaw;
+
+use crate::Ref;
+
+/// A type that can be cloned through a raw reference.
+///
+/// This is the raw counterpart to `Clone`. Any data owned by the value is
+/// cloned by allocating it in `alloc`, so the clone is entirely located in
+/// `A::Region` regardless of where the original value is located.
+///
+/// Types that implement `Copy` are cloned by copying them.
+///
+/// # Safety
+///
+/// `clone_raw` must initialize its `out` parameter.
+pub unsafe trait CloneRaw<A: RegionalAllocator>: Sized {
+    /// Clones the value pointed to by `this` into `out`, allocating any owned
+    /// data in `alloc`.
+    fn clone_raw(
+        this: Ref<'_, Self>,
+        alloc: A,
+        out: In<Slot<'_, Self>, A::Region>,
+    );
+}
+
+// SAFETY: `clone_raw` initializes `out` by writing a copy of `this` to it.
+unsafe impl<T: Copy, A: RegionalAllocator> CloneRaw<A> for T {
+    #[inline]
+    fn clone_raw(
+        this: Ref<'_, Self>,
+        _: A,
+        out: In<Slot<'_, Self>, A::Region>,
+    ) {
+        In::into_inner(out).write(*this);
+    }
+}
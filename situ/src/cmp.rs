@@ -0,0 +1,120 @@
+//! Utilities for comparing and ordering raw values.
+
+use ::core::cmp::Ordering;
+
+pub use ::situ_derive::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw};
+
+use crate::Ref;
+
+/// Equality comparisons for raw values.
+///
+/// This is the raw counterpart to `PartialEq`. Like `HashRaw`, values are
+/// compared by their logical contents rather than their addresses.
+pub trait PartialEqRaw {
+    /// Returns whether `this` and `other` are equal.
+    fn eq_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> bool;
+}
+
+impl<T: PartialEq + ?Sized> PartialEqRaw for T {
+    #[inline]
+    fn eq_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> bool {
+        PartialEq::eq(&*this, &*other)
+    }
+}
+
+/// Equality comparisons for raw values which are equivalence relations.
+///
+/// This is the raw counterpart to `Eq`.
+pub trait EqRaw: PartialEqRaw {}
+
+impl<T: Eq + ?Sized> EqRaw for T {}
+
+/// Partial orderings for raw values.
+///
+/// This is the raw counterpart to `PartialOrd`.
+pub trait PartialOrdRaw: PartialEqRaw {
+    /// Returns an ordering between `this` and `other` if one exists.
+    fn partial_cmp_raw(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+    ) -> Option<Ordering>;
+}
+
+impl<T: PartialOrd + ?Sized> PartialOrdRaw for T {
+    #[inline]
+    fn partial_cmp_raw(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+    ) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&*this, &*other)
+    }
+}
+
+/// Total orderings for raw values.
+///
+/// This is the raw counterpart to `Ord`.
+pub trait OrdRaw: EqRaw + PartialOrdRaw {
+    /// Returns the ordering between `this` and `other`.
+    fn cmp_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> Ordering;
+}
+
+impl<T: Ord + ?Sized> OrdRaw for T {
+    #[inline]
+    fn cmp_raw(this: Ref<'_, Self>, other: Ref<'_, Self>) -> Ordering {
+        Ord::cmp(&*this, &*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::std::vec::Vec;
+
+    use crate::{
+        cmp::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw},
+        Ref,
+    };
+
+    #[test]
+    fn derive_cmp_raw() {
+        #[derive(EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw)]
+        #[situ = "crate"]
+        struct Version {
+            major: u32,
+            minor: u32,
+        }
+
+        #[derive(EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw)]
+        #[situ = "crate"]
+        #[allow(dead_code)]
+        #[repr(u8)]
+        enum Release {
+            Stable(Version),
+            Beta { version: Version, build: u32 },
+        }
+
+        let version = |major, minor| Version { major, minor };
+        let releases = [
+            Release::Stable(version(1, 0)),
+            Release::Stable(version(1, 2)),
+            Release::Beta {
+                version: version(1, 0),
+                build: 3,
+            },
+            Release::Beta {
+                version: version(1, 0),
+                build: 4,
+            },
+        ];
+        let releases = releases
+            .iter()
+            // SAFETY: Each release is valid and never mutated.
+            .map(|r| unsafe { Ref::new_unchecked(r) })
+            .collect::<Vec<_>>();
+        for (i, a) in releases.iter().enumerate() {
+            for (j, b) in releases.iter().enumerate() {
+                assert_eq!(a == b, i == j);
+                assert_eq!(a.cmp(b), i.cmp(&j));
+            }
+        }
+    }
+}
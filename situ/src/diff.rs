@@ -0,0 +1,72 @@
+//! Utilities for finding the differences between raw values.
+
+use ::core::fmt;
+
+pub use ::situ_derive::DiffRaw;
+
+use crate::Ref;
+
+/// A single step on the path from a value to one of its fields.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PathSegment {
+    /// A field of a struct or enum variant. Tuple fields are named by their
+    /// index.
+    Field(&'static str),
+    /// An element of a sequence.
+    Index(usize),
+    /// The variant of an enum.
+    Variant(&'static str),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Field(name) => write!(f, ".{name}"),
+            Self::Index(index) => write!(f, "[{index}]"),
+            Self::Variant(name) => write!(f, "::{name}"),
+        }
+    }
+}
+
+/// A path which a difference between two values can be reported to.
+pub trait DiffPath {
+    /// Adds a segment to the path.
+    ///
+    /// Segments are pushed from the innermost outward, as the difference is
+    /// reported up through each of the values containing it.
+    fn push_segment(&mut self, segment: PathSegment);
+}
+
+/// A raw type whose values can be compared field by field.
+///
+/// Like `PartialEqRaw`, values are compared by their logical contents rather
+/// than their addresses, so two values in different buffers can be compared
+/// even if their relative pointers differ. Unlike `PartialEqRaw`, `DiffRaw`
+/// also reports which field the first difference was found in.
+///
+/// Types that implement `PartialEq` (including the portable primitives)
+/// implement `DiffRaw` by comparing their referenced values. They don't report
+/// any fields.
+pub trait DiffRaw {
+    /// Returns whether `this` and `other` differ.
+    ///
+    /// If they do, the path to the first field which differs is pushed to
+    /// `path`. Values which differ in their entirety (like enums with different
+    /// variants) don't push any segments.
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        path: &mut P,
+    ) -> bool;
+}
+
+impl<T: PartialEq + ?Sized> DiffRaw for T {
+    #[inline]
+    fn diff_raw<P: DiffPath + ?Sized>(
+        this: Ref<'_, Self>,
+        other: Ref<'_, Self>,
+        _: &mut P,
+    ) -> bool {
+        PartialEq::ne(&*this, &*other)
+    }
+}
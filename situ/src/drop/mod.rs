@@ -24,3 +24,57 @@ pub trait DropRaw {
     ///   accessed again.
     unsafe fn drop_raw(this: Mut<'_, Self>);
 }
+
+#[cfg(test)]
+mod tests {
+    use ::core::{cell::Cell, mem::ManuallyDrop};
+
+    use crate::{DropRaw, Mut};
+
+    #[test]
+    fn skip_drop() {
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl DropRaw for Counted<'_> {
+            unsafe fn drop_raw(this: Mut<'_, Self>) {
+                this.0.set(this.0.get() + 1);
+            }
+        }
+
+        #[derive(DropRaw)]
+        #[situ = "crate"]
+        #[situ(finalize = "Self::finalize")]
+        struct Resource<'a> {
+            counted: Counted<'a>,
+            #[situ(skip_drop)]
+            handle: ManuallyDrop<Counted<'a>>,
+            #[situ(skip_drop)]
+            finalized: &'a Cell<bool>,
+        }
+
+        impl Resource<'_> {
+            unsafe fn finalize(this: Mut<'_, Self>) {
+                // None of the fields have been dropped yet.
+                assert_eq!(this.counted.0.get(), 0);
+                assert_eq!(this.handle.0.get(), 0);
+                this.finalized.set(true);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let finalized = Cell::new(false);
+        let mut resource = Resource {
+            counted: Counted(&drops),
+            handle: ManuallyDrop::new(Counted(&drops)),
+            finalized: &finalized,
+        };
+        // SAFETY: `resource` is a valid `Resource` which is never used again
+        // after it is dropped.
+        unsafe {
+            DropRaw::drop_raw(Mut::new_unchecked(&mut resource));
+        }
+
+        assert!(finalized.get());
+        assert_eq!(drops.get(), 1);
+    }
+}
@@ -0,0 +1,3 @@
+//! Public re-exports of dependencies.
+
+pub use ::mischief;
@@ -2,6 +2,8 @@
 
 use ::core::fmt::{Debug, Display, Error, Formatter};
 
+pub use ::situ_derive::{DebugRaw, DisplayRaw};
+
 use crate::Ref;
 
 /// `?` formatting for raw references.
@@ -35,3 +37,67 @@ impl<T: Display + ?Sized> DisplayRaw for T {
         Display::fmt(&*this, f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ::std::format;
+
+    use crate::{
+        fmt::{DebugRaw, DisplayRaw},
+        Ref,
+    };
+
+    #[test]
+    fn derive_debug_raw() {
+        #[derive(DebugRaw, DisplayRaw)]
+        #[situ = "crate"]
+        struct Meters(i32);
+
+        #[derive(DebugRaw)]
+        #[situ = "crate"]
+        struct Point {
+            x: Meters,
+            y: Meters,
+        }
+
+        #[derive(DebugRaw)]
+        #[situ = "crate"]
+        #[allow(dead_code)]
+        #[repr(u8)]
+        enum Shape {
+            Empty,
+            Dot(Point),
+            Line { start: Point, end: Point },
+        }
+
+        let meters = Meters(3);
+        // SAFETY: `meters` is a valid `Meters` which is never mutated.
+        let meters = unsafe { Ref::new_unchecked(&meters) };
+        assert_eq!(format!("{meters}"), "3");
+        assert_eq!(format!("{meters:?}"), "Meters(3)");
+
+        let point = |x, y| Point {
+            x: Meters(x),
+            y: Meters(y),
+        };
+        let shapes = [
+            Shape::Empty,
+            Shape::Dot(point(1, 2)),
+            Shape::Line {
+                start: point(0, 0),
+                end: point(-1, 4),
+            },
+        ];
+        let expected = [
+            "Empty",
+            "Dot(Point { x: Meters(1), y: Meters(2) })",
+            "Line { start: Point { x: Meters(0), y: Meters(0) }, end: Point { \
+             x: Meters(-1), y: Meters(4) } }",
+        ];
+        for (shape, expected) in shapes.iter().zip(expected.iter()) {
+            // SAFETY: `shape` is a valid `Shape` which is never mutated.
+            let shape = unsafe { Ref::new_unchecked(shape) };
+            assert_eq!(format!("{shape:?}"), *expected);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Utilities for hashing raw values.
+
+use ::core::hash::{Hash, Hasher};
+
+pub use ::situ_derive::HashRaw;
+
+use crate::Ref;
+
+/// A hashable raw type.
+///
+/// Types implementing `HashRaw` hash their logical contents rather than their
+/// location in memory. Two equal values must hash identically, even if they
+/// are located at different addresses. This is what allows `Ref` and `Val` to
+/// implement `Hash` by forwarding to `HashRaw`.
+///
+/// Types that implement `Hash` (including the portable primitives) implement
+/// `HashRaw` by hashing their referenced value.
+pub trait HashRaw {
+    /// Feeds this value into the given `Hasher`.
+    fn hash_raw<H: Hasher>(this: Ref<'_, Self>, state: &mut H);
+}
+
+impl<T: Hash + ?Sized> HashRaw for T {
+    fn hash_raw<H: Hasher>(this: Ref<'_, Self>, state: &mut H) {
+        Hash::hash(&*this, state)
+    }
+}
@@ -11,15 +11,33 @@
 )]
 #![no_std]
 
+#[cfg(test)]
+extern crate std;
+
 pub mod alloc;
+mod clone;
+pub mod cmp;
+pub mod diff;
 mod drop;
+pub mod export;
 pub mod fmt;
+pub mod hash;
 mod r#mut;
 pub mod ops;
 mod owned_val;
 mod pinned;
 mod r#ref;
+#[cfg(feature = "serde")]
+pub mod ser;
 pub mod str;
 mod val;
 
-pub use self::{drop::*, owned_val::*, pinned::*, r#mut::*, r#ref::*, val::*};
+pub use self::{
+    clone::*,
+    drop::*,
+    owned_val::*,
+    pinned::*,
+    r#mut::*,
+    r#ref::*,
+    val::*,
+};
@@ -2,11 +2,15 @@ use ::core::{
     fmt,
     marker::PhantomData,
     ops::{Deref, DerefMut},
-    ptr::NonNull,
+    ptr::{slice_from_raw_parts_mut, NonNull},
 };
 use ::mischief::{Pointer, Region, RestructurablePointer, Unique, Within};
 use ::munge::{Destructure, Restructure};
+#[cfg(feature = "serde")]
+use ::serde::{Serialize, Serializer};
 
+#[cfg(feature = "serde")]
+use crate::ser::SerializeRaw;
 use crate::{
     fmt::{DebugRaw, DisplayRaw},
     DropRaw,
@@ -30,6 +34,7 @@ impl<'a, T: ?Sized> Mut<'a, T> {
     ///   writes.
     /// - `ptr` must not alias any other accessible references for `'a`.
     /// - The value pointed to by `ptr` must be initialized and immovable.
+    #[inline]
     pub unsafe fn new_unchecked(ptr: *mut T) -> Self {
         Self {
             // SAFETY: The caller has guaranteed that `ptr` is non-null.
@@ -40,6 +45,11 @@ impl<'a, T: ?Sized> Mut<'a, T> {
 
     /// Constructs a new `Mut` by mapping the interior pointer.
     ///
+    /// This is most often used to project a `Mut` to one of the fields of the
+    /// referenced value. The returned `Mut` has the same lifetime as `self`,
+    /// and `self` is consumed so that the two cannot alias. To project to
+    /// fields safely, use `munge` instead.
+    ///
     /// # Safety
     ///
     /// - The pointer returned by `f` must be non-null, properly aligned, and
@@ -47,7 +57,11 @@ impl<'a, T: ?Sized> Mut<'a, T> {
     /// - The pointer returned by `f` must not alias any other accessible
     ///   references for `'a`.
     /// - The value pointed to by the pointer returned by `f` must be
-    ///   initialized and immutable.
+    ///   initialized and immovable.
+    ///
+    /// A pointer to a field of the value pointed to by `self` satisfies all of
+    /// these requirements.
+    #[inline]
     pub unsafe fn map_unchecked<F, U>(self, f: F) -> Mut<'a, U>
     where
         F: FnOnce(*mut T) -> *mut U,
@@ -58,22 +72,62 @@ impl<'a, T: ?Sized> Mut<'a, T> {
     }
 
     /// Returns a pointer to the referenced value.
+    #[inline]
     pub fn as_ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
 
+    /// Returns the address of the referenced value.
+    ///
+    /// Any pointer metadata is discarded.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Mut::addr(&x)` instead of `x.addr()`. This is so that there is
+    /// no conflict with a method on the inner type.
+    // TODO strict_provenance: Use `<*mut T>::addr`.
+    #[inline]
+    #[allow(clippy::as_conversions)]
+    pub fn addr(this: &Self) -> usize {
+        this.as_ptr().cast::<()>() as usize
+    }
+
+    /// Returns `true` if the two `Mut`s point to the same address.
+    ///
+    /// Any pointer metadata is ignored.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Mut::ptr_eq(&a, &b)` instead of `a.ptr_eq(&b)`. This is so
+    /// that there is no conflict with a method on the inner type.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.as_ptr().cast::<()>() == other.as_ptr().cast::<()>()
+    }
+
     /// Returns a `NonNull` to the referenced value.
+    #[inline]
     pub fn as_non_null(&self) -> NonNull<T> {
         self.ptr
     }
 
     /// Returns a `Ref` of the referenced value.
+    #[inline]
     pub fn as_ref(&self) -> Ref<'_, T> {
         // SAFETY: The requirements for `Ref` are a subset of those for `Mut`.
         unsafe { Ref::new_unchecked(self.as_ptr()) }
     }
 
-    /// Returns a reborrowed `Mut` of the referenced value.
+    /// Reborrows the `Mut` for a shorter lifetime.
+    ///
+    /// This is useful for passing a `Mut` to a function which consumes it while
+    /// still using it afterward. The returned `Mut` borrows `self`, so `self`
+    /// can't be used again until it is dropped.
+    ///
+    /// Like `&'a mut T`, `Mut<'a, T>` is covariant in `'a` and invariant in
+    /// `T`. The reborrowed lifetime is always the lifetime of the borrow of
+    /// `self`, which can't be longer than `'a`. So reborrowing can only ever
+    /// shorten the lifetime of a `Mut`, never extend it (for example to
+    /// `'static`).
+    #[inline]
     pub fn as_mut(&mut self) -> Mut<'_, T> {
         // SAFETY: The reborrowed `Mut` lives shorter than `self` and satisfies
         // all of the same requirements.
@@ -89,6 +143,7 @@ impl<'a, T: ?Sized> Mut<'a, T> {
     ///   accessed in an illegal state. Because `Val` may drop the value, care
     ///   must be taken to forget the `Val` or replace the value after dropping
     ///   it.
+    #[inline]
     pub unsafe fn take(self) -> Val<'a, T>
     where
         T: DropRaw,
@@ -100,10 +155,66 @@ impl<'a, T: ?Sized> Mut<'a, T> {
     }
 }
 
+impl<'a, T> Mut<'a, [T]> {
+    /// Returns a `Mut` of the first element of the slice and a `Mut` of the
+    /// rest of the elements, or `None` if the slice is empty.
+    pub fn split_first(self) -> Option<(Mut<'a, T>, Mut<'a, [T]>)> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let ptr = self.as_ptr().cast::<T>();
+        // SAFETY: The slice is not empty, so `ptr.add(1)` is in bounds of the
+        // slice or one past its end.
+        let rest = unsafe { ptr.add(1) };
+        // SAFETY:
+        // - Both pointers point to elements of the original slice, so they are
+        //   non-null, properly aligned, and valid for reads and writes. The
+        //   elements are initialized and immovable.
+        // - The first element and the rest of the slice are disjoint, so the
+        //   returned `Mut`s do not alias each other. They also do not alias
+        //   any other accessible references for `'a` because `self` is
+        //   consumed.
+        unsafe {
+            Some((
+                Mut::new_unchecked(ptr),
+                Mut::new_unchecked(slice_from_raw_parts_mut(rest, len - 1)),
+            ))
+        }
+    }
+
+    /// Returns a `Mut` of the last element of the slice and a `Mut` of the
+    /// rest of the elements, or `None` if the slice is empty.
+    pub fn split_last(self) -> Option<(Mut<'a, T>, Mut<'a, [T]>)> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let ptr = self.as_ptr().cast::<T>();
+        // SAFETY: The slice is not empty, so `len - 1` is in bounds of it.
+        let last = unsafe { ptr.add(len - 1) };
+        // SAFETY:
+        // - Both pointers point to elements of the original slice, so they are
+        //   non-null, properly aligned, and valid for reads and writes. The
+        //   elements are initialized and immovable.
+        // - The last element and the rest of the slice are disjoint, so the
+        //   returned `Mut`s do not alias each other. They also do not alias
+        //   any other accessible references for `'a` because `self` is
+        //   consumed.
+        unsafe {
+            Some((
+                Mut::new_unchecked(last),
+                Mut::new_unchecked(slice_from_raw_parts_mut(ptr, len - 1)),
+            ))
+        }
+    }
+}
+
 // SAFETY: `Mut` returns the same value from `target`, `deref`, and `deref_mut`.
 unsafe impl<T: ?Sized> Pointer for Mut<'_, T> {
     type Target = T;
 
+    #[inline]
     fn target(&self) -> *mut Self::Target {
         self.ptr.as_ptr()
     }
@@ -116,6 +227,7 @@ unsafe impl<T: Pinned<R> + ?Sized, R: Region> Within<R> for Mut<'_, T> {}
 impl<T: ?Sized> Deref for Mut<'_, T> {
     type Target = T;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         // SAFETY:
         // - `self.ptr` is always properly aligned and dereferenceable.
@@ -131,6 +243,7 @@ impl<T: ?Sized> Deref for Mut<'_, T> {
 // Note that `T` must be `Unpin` to avoid violating the immovability invariant
 // of `Mut`.
 impl<T: Unpin + ?Sized> DerefMut for Mut<'_, T> {
+    #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
         // SAFETY:
         // - `self.ptr` is always properly aligned and dereferenceable.
@@ -144,17 +257,30 @@ impl<T: Unpin + ?Sized> DerefMut for Mut<'_, T> {
 }
 
 impl<T: DebugRaw + ?Sized> fmt::Debug for Mut<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DebugRaw::fmt_raw(self.as_ref(), f)
     }
 }
 
 impl<T: DisplayRaw + ?Sized> fmt::Display for Mut<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DisplayRaw::fmt_raw(self.as_ref(), f)
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: SerializeRaw + ?Sized> Serialize for Mut<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SerializeRaw::serialize_raw(self.as_ref(), serializer)
+    }
+}
+
 // SAFETY: `Destructure::underlying` for `Mut` returns the same pointer as
 // `Pointer::target`.
 unsafe impl<T: ?Sized> RestructurablePointer for Mut<'_, T> {}
@@ -168,6 +294,7 @@ unsafe impl<'a, T: ?Sized> Destructure for Mut<'a, T> {
     type Underlying = T;
     type Destructuring = ::munge::Ref;
 
+    #[inline]
     fn underlying(&mut self) -> *mut Self::Underlying {
         self.as_ptr()
     }
@@ -178,6 +305,7 @@ unsafe impl<'a, T: ?Sized> Destructure for Mut<'a, T> {
 unsafe impl<'a, T, U: 'a> Restructure<U> for Mut<'a, T> {
     type Restructured = Mut<'a, U>;
 
+    #[inline]
     unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
         // SAFETY:
         // - A pointer to a subfield of a `Mut` is also non-null, properly
@@ -195,3 +323,54 @@ unsafe impl<'a, T, U: 'a> Restructure<U> for Mut<'a, T> {
 // SAFETY: Because the borrowed `T` is unique and mutable references are
 // exclusive, there can only ever be one `Mut` to each unique `T` at any time.
 unsafe impl<T: Unique + ?Sized> Unique for Mut<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use ::core::ptr::addr_of_mut;
+
+    use crate::Mut;
+
+    #[test]
+    fn map_unchecked() {
+        struct Outer {
+            inner: Inner,
+        }
+
+        struct Inner {
+            value: u32,
+        }
+
+        let mut outer = Outer {
+            inner: Inner { value: 1 },
+        };
+        // SAFETY: `outer` is a valid `Outer` which is not otherwise accessed
+        // while the `Mut` is live, and each mapped pointer points to one of its
+        // fields.
+        let mut value = unsafe {
+            Mut::new_unchecked(&mut outer)
+                .map_unchecked(|p| addr_of_mut!((*p).inner))
+                .map_unchecked(|p| addr_of_mut!((*p).value))
+        };
+        *value = 2;
+        assert_eq!(outer.inner.value, 2);
+    }
+
+    #[test]
+    fn reborrow() {
+        fn increment(mut value: Mut<'_, u32>) {
+            *value += 1;
+        }
+
+        let mut value = 0u32;
+        // SAFETY: `value` is a local which is not otherwise accessed while
+        // `mutable` is alive.
+        let mut mutable = unsafe { Mut::new_unchecked(&mut value) };
+        for _ in 0..4 {
+            increment(mutable.as_mut());
+        }
+        assert_eq!(*mutable, 4);
+
+        increment(mutable);
+        assert_eq!(value, 5);
+    }
+}
@@ -183,3 +183,67 @@ impl<T> IndexMutRaw<usize> for [T] {
         unsafe { Mut::new_unchecked(ptr) }
     }
 }
+
+macro_rules! define_assign_raw {
+    (
+        $(
+            $name:ident:
+            $assign:ident::$assign_fn:ident => $fn:ident, $op:literal;
+        )*
+    ) => {
+        $(
+            #[doc = concat!(
+                "A variant of `",
+                stringify!($assign),
+                "` that works with raw references.",
+            )]
+            ///
+            /// Types that implement the corresponding `ops` trait and are
+            /// `Unpin` implement this trait by mutating their referenced value.
+            pub trait $name<Rhs = Self> {
+                #[doc = concat!("Performs the `", $op, "` operation.")]
+                fn $fn(this: Mut<'_, Self>, rhs: Rhs);
+            }
+
+            impl<T, Rhs> $name<Rhs> for T
+            where
+                T: ::core::ops::$assign<Rhs> + Unpin,
+            {
+                #[inline]
+                fn $fn(mut this: Mut<'_, Self>, rhs: Rhs) {
+                    ::core::ops::$assign::$assign_fn(&mut *this, rhs);
+                }
+            }
+        )*
+    };
+}
+
+define_assign_raw! {
+    AddAssignRaw: AddAssign::add_assign => add_assign_raw, "+=";
+    SubAssignRaw: SubAssign::sub_assign => sub_assign_raw, "-=";
+    MulAssignRaw: MulAssign::mul_assign => mul_assign_raw, "*=";
+    DivAssignRaw: DivAssign::div_assign => div_assign_raw, "/=";
+    RemAssignRaw: RemAssign::rem_assign => rem_assign_raw, "%=";
+    BitAndAssignRaw: BitAndAssign::bitand_assign => bitand_assign_raw, "&=";
+    BitOrAssignRaw: BitOrAssign::bitor_assign => bitor_assign_raw, "|=";
+    BitXorAssignRaw: BitXorAssign::bitxor_assign => bitxor_assign_raw, "^=";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ops::{AddAssignRaw, BitXorAssignRaw},
+        Mut,
+    };
+
+    #[test]
+    fn assign_raw() {
+        let mut value = 1u64;
+        // SAFETY: `value` is not otherwise accessed while the `Mut` is live.
+        let mut this = unsafe { Mut::new_unchecked(&mut value) };
+        u64::add_assign_raw(this.as_mut(), 2);
+        assert_eq!(*this, 3);
+        u64::bitxor_assign_raw(this.as_mut(), 0b110);
+        assert_eq!(*this, 0b101);
+    }
+}
@@ -10,14 +10,16 @@ use ::core::{
 use ::heresy::alloc::Allocator;
 #[cfg(feature = "alloc")]
 use ::heresy::alloc::Global;
-use ::mischief::{Frame, Metadata, Pointer, RegionalAllocator, Within};
+use ::mischief::{Frame, In, Metadata, Pointer, RegionalAllocator, Within};
 use ::ptr_meta::Pointee;
 
 use crate::{
     fmt::{DebugRaw, DisplayRaw},
+    CloneRaw,
     DropRaw,
     Mut,
     Ref,
+    Val,
 };
 
 /// A pointer type for memory allocation.
@@ -97,6 +99,32 @@ impl<T: DropRaw + ?Sized, A: Allocator> OwnedVal<T, A> {
         unsafe { Self::from_raw_in(ptr, alloc) }
     }
 
+    /// Constructs an owned `Val` from a `Val` located in the region of the
+    /// given allocator.
+    ///
+    /// The returned `OwnedVal` takes over ownership of both the value and its
+    /// backing memory. When it is dropped, the value will be dropped with
+    /// `DropRaw` and the memory will be freed with `alloc`.
+    ///
+    /// # Safety
+    ///
+    /// - The memory backing `val` must be a block currently allocated by
+    ///   `alloc`.
+    /// - The layout used to allocate that block must exactly match the return
+    ///   value of `Layout::for_value`.
+    pub unsafe fn from_val_in(val: In<Val<'_, T>, A::Region>, alloc: A) -> Self
+    where
+        A: RegionalAllocator,
+    {
+        let ptr = Val::leak(In::into_inner(val)).as_ptr();
+        // SAFETY:
+        // - The caller has guaranteed that `ptr` points to a memory block
+        //   allocated by `alloc` with a layout matching `Layout::for_value`.
+        // - `Val`s always point to initialized values, and leaking it ensures
+        //   that the value will not be dropped.
+        unsafe { Self::from_raw_in(ptr, alloc) }
+    }
+
     /// Consumes the `OwnedVal`, returning a wrapped raw pointer and the
     /// allocator.
     ///
@@ -121,6 +149,62 @@ impl<T: DropRaw + ?Sized, A: Allocator> OwnedVal<T, A> {
         (b.ptr.as_ptr(), alloc)
     }
 
+    /// Consumes and leaks the `OwnedVal`, returning a mutable reference,
+    /// `Mut<'a, T>`.
+    ///
+    /// The value is never dropped and its memory is never freed through the
+    /// `OwnedVal`. Unless the allocator reclaims all of its memory at once
+    /// (like a slab allocator does when its slab is reset or dropped), this
+    /// leaks the allocation. This is mainly useful for values that should live
+    /// for as long as the allocator itself, like the root of an arena.
+    ///
+    /// The allocator is leaked along with the value, so that it can't free the
+    /// value's memory while the returned `Mut` is alive. The leaked value can
+    /// be owned again by passing the `Mut` and an equivalent allocator to
+    /// [`OwnedVal::from_leaked`].
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `OwnedVal::leak(v)` instead of `v.leak()`. This is so that there
+    /// is no conflict with a method on the inner type.
+    pub fn leak<'a>(v: Self) -> Mut<'a, T>
+    where
+        A: 'a,
+    {
+        let v = ManuallyDrop::new(v);
+        // SAFETY:
+        // - `v.ptr` is always non-null, properly aligned, and valid for reads
+        //   and writes.
+        // - `v.ptr` always points to an initialized `T` and does not alias any
+        //   other references because `v` owned it.
+        // - The memory backing `v.ptr` is allocated by `v.alloc`, which lives
+        //   for at least `'a` and is never dropped. Because `v` is never
+        //   dropped, the value will also never be dropped or deallocated.
+        unsafe { Mut::new_unchecked(v.ptr.as_ptr()) }
+    }
+
+    /// Constructs an owned `Val` from a `Mut` that was leaked from an
+    /// `OwnedVal` with [`OwnedVal::leak`].
+    ///
+    /// After calling this function, the value is owned by the resulting
+    /// `OwnedVal` again. Its destructor will call the `DropRaw` destructor of
+    /// `T` and free the memory with `alloc`.
+    ///
+    /// # Safety
+    ///
+    /// - `leaked` must have been returned from `OwnedVal::leak` on an
+    ///   `OwnedVal` with an allocator that is equivalent to `alloc`. That is,
+    ///   the memory backing `leaked` must be currently allocated by `alloc`.
+    /// - The value pointed to by `leaked` must not have been dropped.
+    pub unsafe fn from_leaked(leaked: Mut<'_, T>, alloc: A) -> Self {
+        // SAFETY:
+        // - The caller has guaranteed that `leaked` points to a memory block
+        //   currently allocated by `alloc`. Because it was allocated for an
+        //   `OwnedVal`, its layout matches `Layout::for_value`.
+        // - `Mut`s always point to initialized values, and the caller has
+        //   guaranteed that the value has not been dropped.
+        unsafe { Self::from_raw_in(leaked.as_ptr(), alloc) }
+    }
+
     /// Returns a `Ref` of the owned value.
     pub fn as_ref(&self) -> Ref<'_, T> {
         // SAFETY: `self.ptr` is always non-null, properly aligned, and valid
@@ -140,6 +224,29 @@ impl<T: DropRaw + ?Sized, A: Allocator> OwnedVal<T, A> {
         // initialized.
         unsafe { Mut::new_unchecked(self.ptr.as_ptr()) }
     }
+
+    /// Projects the `OwnedVal` to a part of its value, like one of its fields.
+    ///
+    /// The returned `MappedOwnedVal` only provides access to the part returned
+    /// by `f`, but still owns the entire value and its backing memory. A value
+    /// and its memory can only be freed together, so the entire value is kept
+    /// alive until the `MappedOwnedVal` is dropped. Then it is dropped with
+    /// `DropRaw` and its memory is freed, exactly as if the `OwnedVal` had been
+    /// dropped. The `OwnedVal` can be recovered with
+    /// [`MappedOwnedVal::into_owner`].
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `OwnedVal::map(v, f)` instead of `v.map(f)`. This is so that there
+    /// is no conflict with a method on the inner type.
+    pub fn map<U, F>(v: Self, f: F) -> MappedOwnedVal<T, U, A>
+    where
+        U: ?Sized,
+        F: for<'a> FnOnce(Mut<'a, T>) -> Mut<'a, U>,
+    {
+        let mut owner = v;
+        let ptr = f(owner.as_mut()).as_non_null();
+        MappedOwnedVal { owner, ptr }
+    }
 }
 
 impl<T: DropRaw, A: Allocator> OwnedVal<T, A> {
@@ -152,6 +259,19 @@ impl<T: DropRaw, A: Allocator> OwnedVal<T, A> {
     }
 }
 
+impl<T, A> Clone for OwnedVal<T, A>
+where
+    T: CloneRaw<A> + DropRaw,
+    A: Clone + RegionalAllocator,
+{
+    fn clone(&self) -> Self {
+        let mut frame = In::new(Frame::new_in(self.alloc.clone()));
+        T::clone_raw(self.as_ref(), self.alloc.clone(), frame.slot());
+        // SAFETY: `clone_raw` initialized `frame`.
+        unsafe { Self::assume_init(In::into_inner(frame)) }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<T: DropRaw> OwnedVal<T, Global> {
     /// Allocates memory in the `Global` allocator and then places `x` into it.
@@ -231,3 +351,221 @@ where
         DisplayRaw::fmt_raw(self.as_ref(), f)
     }
 }
+
+/// An `OwnedVal` which has been projected to a part of its value.
+///
+/// This is created by [`OwnedVal::map`]. It owns the entire value of the
+/// original `OwnedVal`, but only provides access to the projected part.
+pub struct MappedOwnedVal<T: DropRaw + ?Sized, U: ?Sized, A: Allocator> {
+    owner: OwnedVal<T, A>,
+    ptr: NonNull<U>,
+}
+
+impl<T, U, A> MappedOwnedVal<T, U, A>
+where
+    T: DropRaw + ?Sized,
+    U: ?Sized,
+    A: Allocator,
+{
+    /// Returns a `Ref` of the projected value.
+    pub fn as_ref(&self) -> Ref<'_, U> {
+        // SAFETY: `self.ptr` was returned from a `Mut` of the owned value (or
+        // is valid for `'static`), so it is non-null, properly aligned, and
+        // valid for reads for as long as the owned value is. Because `self` is
+        // borrowed, the value pointed to by `self.ptr` may only be aliased by
+        // shared references, and it must be initialized.
+        unsafe { Ref::new_unchecked(self.ptr.as_ptr()) }
+    }
+
+    /// Returns a `Mut` of the projected value.
+    pub fn as_mut(&mut self) -> Mut<'_, U> {
+        // SAFETY: `self.ptr` was returned from a `Mut` of the owned value (or
+        // is valid for `'static`), so it is non-null, properly aligned, and
+        // valid for reads and writes for as long as the owned value is. Because
+        // `self` is mutably borrowed, the value pointed to by `self.ptr` may
+        // not be aliased, and it must be initialized and immovable.
+        unsafe { Mut::new_unchecked(self.ptr.as_ptr()) }
+    }
+
+    /// Further projects the `MappedOwnedVal` to a part of its projected value.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `MappedOwnedVal::map(v, f)` instead of `v.map(f)`. This is so
+    /// that there is no conflict with a method on the inner type.
+    pub fn map<V, F>(v: Self, f: F) -> MappedOwnedVal<T, V, A>
+    where
+        V: ?Sized,
+        F: for<'a> FnOnce(Mut<'a, U>) -> Mut<'a, V>,
+    {
+        let mut v = v;
+        let ptr = f(v.as_mut()).as_non_null();
+        MappedOwnedVal {
+            owner: v.owner,
+            ptr,
+        }
+    }
+
+    /// Returns the `OwnedVal` that owns the projected value.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `MappedOwnedVal::into_owner(v)` instead of `v.into_owner()`. This
+    /// is so that there is no conflict with a method on the inner type.
+    pub fn into_owner(v: Self) -> OwnedVal<T, A> {
+        v.owner
+    }
+}
+
+impl<T, U, A> Deref for MappedOwnedVal<T, U, A>
+where
+    T: DropRaw + ?Sized,
+    U: ?Sized,
+    A: Allocator,
+{
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // - `self.ptr` is always properly aligned and dereferenceable.
+        // - `self.ptr` always points to an initialized value of `U`.
+        // - Because `MappedOwnedVal<T, U, A>` is borrowed for `'_`, the
+        //   returned reference is also valid for `'_` and has shared read-only
+        //   aliasing.
+        unsafe { &*self.ptr.as_ptr() }
+    }
+}
+
+// Note that `U` must be `Unpin` to avoid violating the immovability invariant
+// of the owned value.
+impl<T, U, A> DerefMut for MappedOwnedVal<T, U, A>
+where
+    T: DropRaw + ?Sized,
+    U: Unpin + ?Sized,
+    A: Allocator,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // - `self.ptr` is always properly aligned and dereferenceable.
+        // - `self.ptr` always points to an initialized value of `U`.
+        // - Because `MappedOwnedVal<T, U, A>` is mutably borrowed for `'_`, the
+        //   returned reference is also valid for `'_` and has unique
+        //   read-write aliasing.
+        unsafe { &mut *self.ptr.as_ptr() }
+    }
+}
+
+impl<T, U, A> fmt::Debug for MappedOwnedVal<T, U, A>
+where
+    T: DropRaw + ?Sized,
+    U: DebugRaw + ?Sized,
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        DebugRaw::fmt_raw(self.as_ref(), f)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use ::core::{alloc::Layout, cell::Cell, ptr::NonNull};
+
+    use ::heresy::alloc::{AllocError, Allocator, Global};
+    use ::mischief::{Frame, In, Region, RegionalAllocator};
+
+    use crate::{
+        ops::IndexMutRaw,
+        DropRaw,
+        MappedOwnedVal,
+        Mut,
+        OwnedVal,
+        Val,
+    };
+
+    #[test]
+    fn leak() {
+        let values = OwnedVal::new([1u8, 2, 3]);
+        let mut leaked = OwnedVal::leak(values);
+        leaked[2] = 4;
+
+        // SAFETY: `leaked` was leaked from an `OwnedVal` allocated by `Global`
+        // and hasn't been dropped.
+        let values = unsafe { OwnedVal::from_leaked(leaked, Global) };
+        assert_eq!(*values, [1, 2, 4]);
+    }
+
+    #[test]
+    fn from_val_in() {
+        struct Counted<'a>(u8, &'a Cell<usize>);
+
+        impl DropRaw for Counted<'_> {
+            unsafe fn drop_raw(this: Mut<'_, Self>) {
+                this.1.set(this.1.get() + 1);
+            }
+        }
+
+        struct Single;
+
+        // SAFETY: `Single` only locates the one value allocated by `InSingle`
+        // in this test.
+        unsafe impl Region for Single {}
+
+        #[derive(Clone, Copy)]
+        struct InSingle;
+
+        // SAFETY: `InSingle` forwards to `Global`.
+        unsafe impl Allocator for InSingle {
+            fn allocate(
+                &self,
+                layout: Layout,
+            ) -> Result<NonNull<[u8]>, AllocError> {
+                Global.allocate(layout)
+            }
+
+            unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+                // SAFETY: The caller has guaranteed that `ptr` was allocated
+                // by `InSingle`, and so by `Global`, with `layout`.
+                unsafe { Global.deallocate(ptr, layout) }
+            }
+        }
+
+        // SAFETY: `InSingle` only allocates once in this test, and that
+        // allocation is located by `Single`.
+        unsafe impl RegionalAllocator for InSingle {
+            type Region = Single;
+        }
+
+        let drops = Cell::new(0);
+        let mut frame = Frame::new_in(InSingle);
+        frame.slot().write(Counted(42, &drops));
+        let (ptr, alloc) = Frame::into_raw_with_allocator(frame);
+        // SAFETY:
+        // - `ptr` was just allocated and initialized, and is owned by nothing
+        //   else once the frame is consumed.
+        // - `ptr` was allocated by `InSingle`, so it is located in `Single`.
+        let val = unsafe { In::new_unchecked(Val::new_unchecked(ptr)) };
+        // SAFETY: `ptr` was allocated by `alloc` with the layout of `Counted`.
+        let owned = unsafe { OwnedVal::from_val_in(val, alloc) };
+
+        assert_eq!(owned.0, 42);
+        assert_eq!(drops.get(), 0);
+        drop(owned);
+        assert_eq!(drops.get(), 1);
+    }
+
+    #[test]
+    fn map() {
+        let values = OwnedVal::new([[1u8, 2], [3, 4], [5, 6]]);
+        let pair = OwnedVal::map(values, |v| IndexMutRaw::index_mut_raw(v, 2));
+        assert_eq!(*pair, [5, 6]);
+
+        let mut last =
+            MappedOwnedVal::map(pair, |p| IndexMutRaw::index_mut_raw(p, 1));
+        assert_eq!(*last, 6);
+        *last = 7;
+
+        // The whole array is still owned and can be recovered.
+        let values = MappedOwnedVal::into_owner(last);
+        assert_eq!(*values, [[1, 2], [3, 4], [5, 7]]);
+    }
+}
@@ -1,4 +1,5 @@
 use ::mischief::Region;
+pub use ::situ_derive::Pinned;
 
 /// A type with values that are always located in a particular region.
 ///
@@ -10,3 +11,41 @@ use ::mischief::Region;
 ///
 /// Values of this type must only ever be located in `R`.
 pub unsafe trait Pinned<R: Region> {}
+
+#[cfg(test)]
+mod tests {
+    use ::core::marker::PhantomData;
+    use ::mischief::Region;
+
+    use crate::Pinned;
+
+    #[test]
+    fn derive_pinned() {
+        struct Anchor<R>(PhantomData<R>);
+
+        // SAFETY: `Anchor`s are never constructed.
+        unsafe impl<R: Region> Pinned<R> for Anchor<R> {}
+
+        #[derive(Pinned)]
+        #[situ = "crate"]
+        #[allow(dead_code)]
+        struct Node<R> {
+            #[pinned]
+            anchor: Anchor<R>,
+            value: u32,
+        }
+
+        #[derive(Pinned)]
+        #[situ = "crate"]
+        #[allow(dead_code)]
+        struct Pair<R>(Anchor<R>, Anchor<R>);
+
+        fn assert_pinned<T: Pinned<R>, R: Region>() {}
+
+        #[allow(dead_code)]
+        fn check<R: Region>() {
+            assert_pinned::<Node<R>, R>();
+            assert_pinned::<Pair<R>, R>();
+        }
+    }
+}
@@ -1,4 +1,11 @@
-use ::core::{fmt, marker::PhantomData, ops::Deref, ptr::NonNull};
+use ::core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::Deref,
+    ptr::NonNull,
+};
 use ::mischief::{
     Pointer,
     Region,
@@ -8,9 +15,15 @@ use ::mischief::{
     Within,
 };
 use ::munge::{Destructure, Restructure};
+#[cfg(feature = "serde")]
+use ::serde::{Serialize, Serializer};
 
+#[cfg(feature = "serde")]
+use crate::ser::SerializeRaw;
 use crate::{
+    cmp::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw},
     fmt::{DebugRaw, DisplayRaw},
+    hash::HashRaw,
     Pinned,
 };
 
@@ -24,6 +37,7 @@ pub struct Ref<'a, T: ?Sized> {
 }
 
 impl<'a, T: ?Sized> Clone for Ref<'a, T> {
+    #[inline]
     fn clone(&self) -> Self {
         // SAFETY: `self.as_ptr()` is the internal pointer of this `Ref`, and
         // upholds all of the safety requirements of `Ref::new_unchecked`.
@@ -41,6 +55,7 @@ impl<'a, T: ?Sized> Ref<'a, T> {
     /// - `ptr` must be non-null, properly aligned, and valid for reads.
     /// - `ptr` must not alias any other mutable references for `'a`.
     /// - The value pointed to by `ptr` must be initialized.
+    #[inline]
     pub unsafe fn new_unchecked(ptr: *const T) -> Self {
         Self {
             // SAFETY: The caller has guaranteed that `ptr` is non-null.
@@ -49,16 +64,72 @@ impl<'a, T: ?Sized> Ref<'a, T> {
         }
     }
 
+    /// Constructs a new `Ref` by mapping the interior pointer.
+    ///
+    /// This is most often used to project a `Ref` to one of the fields of the
+    /// referenced value. The returned `Ref` has the same lifetime as `self`.
+    /// To project to fields safely, use `munge` instead.
+    ///
+    /// # Safety
+    ///
+    /// - The pointer returned by `f` must be non-null, properly aligned, and
+    ///   valid for reads.
+    /// - The pointer returned by `f` must not alias any other mutable
+    ///   references for `'a`.
+    /// - The value pointed to by the pointer returned by `f` must be
+    ///   initialized.
+    ///
+    /// A pointer to a field of the value pointed to by `self` satisfies all of
+    /// these requirements.
+    #[inline]
+    pub unsafe fn map_unchecked<F, U>(self, f: F) -> Ref<'a, U>
+    where
+        F: FnOnce(*const T) -> *const U,
+    {
+        // SAFETY: The caller has guaranteed that pointer returned by `f` meets
+        // all of the safety requirements of `new_unchecked`.
+        unsafe { Ref::new_unchecked(f(self.as_ptr())) }
+    }
+
     /// Returns a pointer to the referenced value.
+    #[inline]
     pub fn as_ptr(self) -> *mut T {
         self.ptr.as_ptr()
     }
+
+    /// Returns the address of the referenced value.
+    ///
+    /// Any pointer metadata is discarded.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Ref::addr(x)` instead of `x.addr()`. This is so that there is no
+    /// conflict with a method on the inner type.
+    // TODO strict_provenance: Use `<*const T>::addr`.
+    #[inline]
+    #[allow(clippy::as_conversions)]
+    pub fn addr(this: Self) -> usize {
+        this.as_ptr().cast::<()>() as usize
+    }
+
+    /// Returns `true` if the two `Ref`s point to the same address.
+    ///
+    /// Any pointer metadata is ignored, so two `Ref`s to slices of different
+    /// lengths which start at the same address are considered equal.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Ref::ptr_eq(a, b)` instead of `a.ptr_eq(b)`. This is so that
+    /// there is no conflict with a method on the inner type.
+    #[inline]
+    pub fn ptr_eq(this: Self, other: Self) -> bool {
+        this.as_ptr().cast::<()>() == other.as_ptr().cast::<()>()
+    }
 }
 
 // SAFETY: `Ref` returns the same value from `target` and `deref`.
 unsafe impl<T: ?Sized> Pointer for Ref<'_, T> {
     type Target = T;
 
+    #[inline]
     fn target(&self) -> *mut Self::Target {
         self.ptr.as_ptr()
     }
@@ -67,6 +138,7 @@ unsafe impl<T: ?Sized> Pointer for Ref<'_, T> {
 impl<T: ?Sized> Deref for Ref<'_, T> {
     type Target = T;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         // SAFETY:
         // - `self.ptr` is always properly aligned and dereferenceable.
@@ -80,17 +152,62 @@ impl<T: ?Sized> Deref for Ref<'_, T> {
 }
 
 impl<T: DebugRaw + ?Sized> fmt::Debug for Ref<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DebugRaw::fmt_raw(*self, f)
     }
 }
 
 impl<T: DisplayRaw + ?Sized> fmt::Display for Ref<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DisplayRaw::fmt_raw(*self, f)
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: SerializeRaw + ?Sized> Serialize for Ref<'_, T> {
+    #[inline]
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        SerializeRaw::serialize_raw(*self, serializer)
+    }
+}
+
+// Note that this hashes the referenced value, not the address it is located at.
+impl<T: HashRaw + ?Sized> Hash for Ref<'_, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        HashRaw::hash_raw(*self, state)
+    }
+}
+
+// Note that these compare the referenced values, not their addresses.
+impl<T: PartialEqRaw + ?Sized> PartialEq for Ref<'_, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        PartialEqRaw::eq_raw(*self, *other)
+    }
+}
+
+impl<T: EqRaw + ?Sized> Eq for Ref<'_, T> {}
+
+impl<T: PartialOrdRaw + ?Sized> PartialOrd for Ref<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrdRaw::partial_cmp_raw(*self, *other)
+    }
+}
+
+impl<T: OrdRaw + ?Sized> Ord for Ref<'_, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        OrdRaw::cmp_raw(*self, *other)
+    }
+}
+
 // SAFETY: `Destructure::underlying` for `Ref` returns the same pointer as
 // `Pointer::target`.
 unsafe impl<T: ?Sized> RestructurablePointer for Ref<'_, T> {}
@@ -108,6 +225,7 @@ unsafe impl<'a, T: ?Sized> Destructure for Ref<'a, T> {
     type Underlying = T;
     type Destructuring = ::munge::Ref;
 
+    #[inline]
     fn underlying(&mut self) -> *mut Self::Underlying {
         self.as_ptr()
     }
@@ -118,6 +236,7 @@ unsafe impl<'a, T: ?Sized> Destructure for Ref<'a, T> {
 unsafe impl<'a, T, U: 'a> Restructure<U> for Ref<'a, T> {
     type Restructured = Ref<'a, U>;
 
+    #[inline]
     unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
         // SAFETY:
         // - A pointer to a subfield of a `Ref` is also non-null, properly
@@ -136,3 +255,52 @@ unsafe impl<'a, T, U: 'a> Restructure<U> for Ref<'a, T> {
 // state from the underlying `T`, all `Ref<T>` to unique `T` must be sharing the
 // same value.
 unsafe impl<T: Unique + ?Sized> Singleton for Ref<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use ::core::ptr::addr_of;
+
+    use crate::Ref;
+
+    #[test]
+    fn map_unchecked() {
+        struct Outer {
+            inner: Inner,
+        }
+
+        struct Inner {
+            value: u32,
+        }
+
+        let outer = Outer {
+            inner: Inner { value: 1 },
+        };
+        // SAFETY: `outer` is a valid `Outer` which is never mutated, and each
+        // mapped pointer points to one of its fields.
+        let value = unsafe {
+            Ref::new_unchecked(&outer)
+                .map_unchecked(|p| addr_of!((*p).inner))
+                .map_unchecked(|p| addr_of!((*p).value))
+        };
+        assert_eq!(*value, 1);
+    }
+
+    #[test]
+    fn ptr_eq() {
+        let values = [1u32, 1u32];
+        // SAFETY: The pointers are derived from references to `values`, which
+        // lives for the rest of the test and is never mutated.
+        let (a, b, c) = unsafe {
+            (
+                Ref::new_unchecked(&values[0]),
+                Ref::new_unchecked(&values[0]),
+                Ref::new_unchecked(&values[1]),
+            )
+        };
+
+        assert!(Ref::ptr_eq(a, b));
+        assert!(!Ref::ptr_eq(a, c));
+        assert_eq!(*a, *c);
+        assert_eq!(Ref::addr(c) - Ref::addr(a), 4);
+    }
+}
@@ -0,0 +1,36 @@
+//! Utilities for serializing raw values.
+
+use ::serde::{Serialize, Serializer};
+
+use crate::Ref;
+
+/// A serializable raw type.
+///
+/// Types implementing `SerializeRaw` serialize their logical contents rather
+/// than their in-memory representation. For example, a relative pointer
+/// serializes the value it points to instead of its offset. This is what
+/// allows `Ref` and `Mut` to implement `Serialize` by forwarding to
+/// `SerializeRaw`.
+///
+/// Types that implement `Serialize` (including the portable primitives)
+/// implement `SerializeRaw` by serializing their referenced value.
+pub trait SerializeRaw {
+    /// Serializes this value into the given `Serializer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the value.
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+impl<T: Serialize + ?Sized> SerializeRaw for T {
+    fn serialize_raw<S: Serializer>(
+        this: Ref<'_, Self>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&*this, serializer)
+    }
+}
@@ -1,6 +1,6 @@
 //! Utilities for the `str` primitive type.
 
-use ::core::str;
+use ::core::{iter::FusedIterator, str};
 
 use crate::{Mut, Ref};
 
@@ -55,3 +55,108 @@ pub fn from_raw_utf8_mut(
     // SAFETY: `from_utf8_mut` has checked that the byte slice is valid UTF-8.
     Ok(unsafe { from_raw_utf8_unchecked_mut(v) })
 }
+
+/// Returns the native string slice that a `Ref` points to.
+fn as_native(v: Ref<'_, str>) -> &'_ str {
+    // SAFETY: The pointer of a `Ref<'a, str>` is non-null, properly aligned,
+    // valid for reads, and points to an initialized `str` which is not mutably
+    // aliased for `'a`.
+    unsafe { &*v.as_ptr() }
+}
+
+/// Returns a `Ref` of a native string slice.
+fn from_native(s: &str) -> Ref<'_, str> {
+    // SAFETY: `s` is a shared reference, so it is non-null, properly aligned,
+    // valid for reads, not mutably aliased for its lifetime, and points to an
+    // initialized `str`.
+    unsafe { Ref::new_unchecked(s) }
+}
+
+/// Returns an iterator over the substrings of a string slice separated by a
+/// character.
+///
+/// The substrings borrow from the original string slice, so no string data is
+/// copied. Like `str::split`, consecutive separators produce empty substrings.
+pub fn split_raw(v: Ref<'_, str>, by: char) -> Split<'_> {
+    Split {
+        inner: as_native(v).split(by),
+    }
+}
+
+/// Returns a string slice with leading and trailing whitespace removed.
+///
+/// Whitespace is defined the same way as for `str::trim`.
+pub fn trim_raw(v: Ref<'_, str>) -> Ref<'_, str> {
+    from_native(as_native(v).trim())
+}
+
+/// Returns the byte index of the first occurrence of `pat` in a string slice,
+/// or `None` if it doesn't occur.
+pub fn find_raw(v: Ref<'_, str>, pat: &str) -> Option<usize> {
+    as_native(v).find(pat)
+}
+
+/// An iterator over the substrings of a string slice separated by a
+/// character.
+///
+/// This is created by [`split_raw`].
+#[derive(Clone)]
+pub struct Split<'a> {
+    inner: str::Split<'a, char>,
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = Ref<'a, str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(from_native)
+    }
+}
+
+impl DoubleEndedIterator for Split<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(from_native)
+    }
+}
+
+impl FusedIterator for Split<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::from_native;
+    use crate::str::{find_raw, split_raw, trim_raw};
+
+    #[test]
+    fn trim() {
+        let s = from_native("  name=rel,version=0.1  ");
+        assert_eq!(&*trim_raw(s), "name=rel,version=0.1");
+
+        let s = from_native(" \t\n ");
+        assert_eq!(&*trim_raw(s), "");
+    }
+
+    #[test]
+    fn split() {
+        let s = from_native("name=rel,version=0.1");
+
+        let mut fields = split_raw(s, ',');
+        assert_eq!(fields.next().as_deref(), Some("name=rel"));
+        assert_eq!(fields.next().as_deref(), Some("version=0.1"));
+        assert!(fields.next().is_none());
+
+        let mut reversed = split_raw(s, ',').rev();
+        assert_eq!(reversed.next().as_deref(), Some("version=0.1"));
+        assert_eq!(reversed.next().as_deref(), Some("name=rel"));
+        assert!(reversed.next().is_none());
+    }
+
+    #[test]
+    fn find() {
+        let s = from_native("version=0.1");
+        assert_eq!(find_raw(s, "="), Some(7));
+        assert_eq!(find_raw(s, "name"), None);
+        assert_eq!(find_raw(from_native(""), "="), None);
+    }
+}
@@ -1,9 +1,11 @@
 use ::core::{
+    cmp::Ordering,
     fmt,
+    hash::{Hash, Hasher},
     marker::PhantomData,
     mem::ManuallyDrop,
     ops::{Deref, DerefMut},
-    ptr::{copy_nonoverlapping, NonNull},
+    ptr::{self, copy_nonoverlapping, NonNull},
 };
 use ::mischief::{
     layout_of_val_raw,
@@ -19,7 +21,9 @@ use ::munge::{Destructure, Restructure};
 use ::ptr_meta::{metadata, Pointee};
 
 use crate::{
+    cmp::{EqRaw, OrdRaw, PartialEqRaw, PartialOrdRaw},
     fmt::{DebugRaw, DisplayRaw},
+    hash::HashRaw,
     DropRaw,
     Mut,
     Pinned,
@@ -33,6 +37,7 @@ pub struct Val<'a, T: DropRaw + ?Sized> {
 }
 
 impl<T: DropRaw + ?Sized> Drop for Val<'_, T> {
+    #[inline]
     fn drop(&mut self) {
         // SAFETY:
         // - `self.ptr` is always non-null, properly aligned, and valid for
@@ -52,6 +57,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     ///   writing, and dropping.
     /// - `ptr` must not alias any other accessible references for `'a`.
     /// - The value pointed to by `ptr` must be initialized and immovable.
+    #[inline]
     pub unsafe fn new_unchecked(ptr: *mut T) -> Self {
         Self {
             // SAFETY: `ptr` is non-null.
@@ -66,6 +72,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     ///
     /// The value pointed to by `slot` must be initialized, valid for dropping,
     /// and immovable.
+    #[inline]
     pub unsafe fn from_slot_unchecked(slot: Slot<'a, T>) -> Self {
         Self {
             // SAFETY: `Slot`s always have a non-null pointer.
@@ -75,10 +82,37 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     }
 
     /// Returns a pointer to the referenced value.
+    #[inline]
     pub fn as_ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
 
+    /// Returns the address of the referenced value.
+    ///
+    /// Any pointer metadata is discarded.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Val::addr(&x)` instead of `x.addr()`. This is so that there is
+    /// no conflict with a method on the inner type.
+    // TODO strict_provenance: Use `<*mut T>::addr`.
+    #[inline]
+    #[allow(clippy::as_conversions)]
+    pub fn addr(this: &Self) -> usize {
+        this.as_ptr().cast::<()>() as usize
+    }
+
+    /// Returns `true` if the two `Val`s point to the same address.
+    ///
+    /// Any pointer metadata is ignored.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Val::ptr_eq(&a, &b)` instead of `a.ptr_eq(&b)`. This is so
+    /// that there is no conflict with a method on the inner type.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.as_ptr().cast::<()>() == other.as_ptr().cast::<()>()
+    }
+
     /// Consumes the `Val` and leaks its value, returning a mutable reference
     /// `&'a mut T`.
     ///
@@ -91,6 +125,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     /// Note: this is an associated function, which means that you have to call
     /// it as `Val::leak(x)` instead of `x.leak()`. This is so that there is no
     /// conflict with a method named `leak` on the value type.
+    #[inline]
     pub fn leak(v: Self) -> Mut<'a, T> {
         let v = ManuallyDrop::new(v);
 
@@ -112,6 +147,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     /// # Safety
     ///
     /// The value owned by `self` must be a valid `U`.
+    #[inline]
     pub unsafe fn cast<U: DropRaw>(self) -> Val<'a, U>
     where
         T: Sized,
@@ -130,6 +166,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     /// Note: this is an associated function, which means that you have to call
     /// it as `Val::read(this)` instead of `this.read()`. This is so that there
     /// is no conflict with a method on the inner type.
+    #[inline]
     pub fn read(this: Self) -> T
     where
         T: Sized + Unpin,
@@ -140,6 +177,46 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
         unsafe { this.ptr.as_ptr().read() }
     }
 
+    /// Replaces the value owned by the `Val` with `value`, returning the old
+    /// value.
+    ///
+    /// The old value is moved out without being dropped, so it is only dropped
+    /// when the returned value is.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Val::replace(this, value)` instead of `this.replace(value)`. This
+    /// is so that there is no conflict with a method on the inner type.
+    #[inline]
+    pub fn replace(this: &mut Self, value: T) -> T
+    where
+        T: Sized + Unpin,
+    {
+        // SAFETY: `ptr` is always non-null, properly aligned, and valid for
+        // reads and writes. The value may be moved because it implements
+        // `Unpin`.
+        unsafe { ptr::replace(this.as_ptr(), value) }
+    }
+
+    /// Swaps the values owned by two `Val`s.
+    ///
+    /// Note: this is an associated function, which means that you have to call
+    /// it as `Val::swap(a, b)` instead of `a.swap(b)`. This is so that there is
+    /// no conflict with a method on the inner type.
+    #[inline]
+    pub fn swap(a: &mut Self, b: &mut Val<'_, T>)
+    where
+        T: Sized + Unpin,
+    {
+        // SAFETY:
+        // - The pointers of `a` and `b` are always non-null, properly aligned,
+        //   and valid for reads and writes.
+        // - `Val`s own the values they point to, so `a` and `b` cannot overlap.
+        // - The values may be moved because they implement `Unpin`.
+        unsafe {
+            ptr::swap_nonoverlapping(a.as_ptr(), b.as_ptr(), 1);
+        }
+    }
+
     /// Consumes the `Val` and moves it into the given `Slot`.
     ///
     /// Note: this is an associated function, which means that you have to call
@@ -150,6 +227,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     /// # Panics
     ///
     /// Panics if `slot` does not have the same metadata as `this`.
+    #[inline]
     pub fn read_unsized(this: Self, slot: Slot<'_, T>)
     where
         T: Pointee + Unpin,
@@ -172,6 +250,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     /// # Safety
     ///
     /// `slot` must have the same metadata as `this`.
+    #[inline]
     pub unsafe fn read_unsized_unchecked(this: Self, slot: Slot<'_, T>)
     where
         T: Pointee + Unpin,
@@ -192,6 +271,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
 
     /// Forgets the contained value, returning a `Slot` of the underlying
     /// memory.
+    #[inline]
     pub fn forget(this: Self) -> Slot<'a, T> {
         let this = ManuallyDrop::new(this);
         // SAFETY: `ptr` is a valid pointer for `'a` and the returned `Slot` is
@@ -201,6 +281,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     }
 
     /// Drops the contained value, returning a `Slot` of the underlying memory.
+    #[inline]
     pub fn drop(this: Self) -> Slot<'a, T> {
         // SAFETY: `ptr` is a valid pointer for `'a` and the returned `Slot` is
         // borrowed for `'b` and cannot be modified until the returned value is
@@ -211,12 +292,14 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
     }
 
     /// Returns a `Ref` of the referenced value.
+    #[inline]
     pub fn as_ref(&self) -> Ref<'_, T> {
         // SAFETY: The requirements for `Ref` are a subset of those for `Val`.
         unsafe { Ref::new_unchecked(self.as_ptr()) }
     }
 
     /// Returns a reborrowed `Mut` of the referenced value.
+    #[inline]
     pub fn as_mut(&mut self) -> Mut<'_, T> {
         // SAFETY: The requirements for `Ref` are a subset of those for `Val`.
         unsafe { Mut::new_unchecked(self.as_ptr()) }
@@ -227,6 +310,7 @@ impl<'a, T: DropRaw + ?Sized> Val<'a, T> {
 unsafe impl<T: DropRaw + ?Sized> Pointer for Val<'_, T> {
     type Target = T;
 
+    #[inline]
     fn target(&self) -> *mut Self::Target {
         self.ptr.as_ptr()
     }
@@ -244,6 +328,7 @@ where
 impl<T: DropRaw + ?Sized> Deref for Val<'_, T> {
     type Target = T;
 
+    #[inline]
     fn deref(&self) -> &Self::Target {
         // SAFETY:
         // - `self.ptr` is always properly aligned and dereferenceable.
@@ -272,17 +357,51 @@ impl<T: DropRaw + Unpin + ?Sized> DerefMut for Val<'_, T> {
 }
 
 impl<T: DebugRaw + DropRaw + ?Sized> fmt::Debug for Val<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DebugRaw::fmt_raw(self.as_ref(), f)
     }
 }
 
 impl<T: DisplayRaw + DropRaw + ?Sized> fmt::Display for Val<'_, T> {
+    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         DisplayRaw::fmt_raw(self.as_ref(), f)
     }
 }
 
+// Note that this hashes the owned value, not the address it is located at.
+impl<T: DropRaw + HashRaw + ?Sized> Hash for Val<'_, T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        HashRaw::hash_raw(self.as_ref(), state)
+    }
+}
+
+// Note that these compare the owned values, not their addresses.
+impl<T: DropRaw + PartialEqRaw + ?Sized> PartialEq for Val<'_, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        PartialEqRaw::eq_raw(self.as_ref(), other.as_ref())
+    }
+}
+
+impl<T: DropRaw + EqRaw + ?Sized> Eq for Val<'_, T> {}
+
+impl<T: DropRaw + PartialOrdRaw + ?Sized> PartialOrd for Val<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrdRaw::partial_cmp_raw(self.as_ref(), other.as_ref())
+    }
+}
+
+impl<T: DropRaw + OrdRaw + ?Sized> Ord for Val<'_, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        OrdRaw::cmp_raw(self.as_ref(), other.as_ref())
+    }
+}
+
 // SAFETY: `Destructure::underlying` for `Val` returns the same pointer as
 // `Pointer::target`.
 unsafe impl<T: DropRaw + ?Sized> RestructurablePointer for Val<'_, T> {}
@@ -296,6 +415,7 @@ unsafe impl<'a, T: DropRaw + ?Sized> Destructure for Val<'a, T> {
     type Underlying = T;
     type Destructuring = ::munge::Value;
 
+    #[inline]
     fn underlying(&mut self) -> *mut Self::Underlying {
         self.as_ptr()
     }
@@ -306,6 +426,7 @@ unsafe impl<'a, T: DropRaw + ?Sized> Destructure for Val<'a, T> {
 unsafe impl<'a, T: DropRaw, U: 'a + DropRaw> Restructure<U> for Val<'a, T> {
     type Restructured = Val<'a, U>;
 
+    #[inline]
     unsafe fn restructure(&self, ptr: *mut U) -> Self::Restructured {
         // SAFETY:
         // - A pointer to a subfield of a `Val` is also non-null, properly
@@ -325,3 +446,25 @@ unsafe impl<'a, T: DropRaw, U: 'a + DropRaw> Restructure<U> for Val<'a, T> {
 // SAFETY: Because the `T` value is unique and values can only have one owner,
 // there can only ever be one `Val` of each unique `T` at any time.
 unsafe impl<T: DropRaw + Unique + ?Sized> Unique for Val<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::Val;
+
+    #[test]
+    fn replace_swap() {
+        let mut a = 1u8;
+        let mut b = 2u8;
+        // SAFETY: `a` and `b` are never accessed again after the `Val`s are
+        // created.
+        let (mut a, mut b) =
+            unsafe { (Val::new_unchecked(&mut a), Val::new_unchecked(&mut b)) };
+
+        assert_eq!(Val::replace(&mut a, 3), 1);
+        assert_eq!(*a, 3);
+
+        Val::swap(&mut a, &mut b);
+        assert_eq!(*a, 2);
+        assert_eq!(*b, 3);
+    }
+}
@@ -0,0 +1,20 @@
+use ::macroix::AttrValue;
+use ::syn::{parse2, parse_quote, Attribute, Error, Meta, Path};
+
+/// Returns the path to `situ` from a `#[situ = "path"]` attribute, or
+/// `::situ` if none is present.
+///
+/// List attributes like `#[situ(...)]` are ignored so that derives which share
+/// the `situ` attribute don't reject each other's arguments.
+pub fn situ_path(attrs: &[Attribute]) -> Result<Path, Error> {
+    let mut situ = None;
+    for attr in attrs.iter() {
+        if attr.path.is_ident("situ") {
+            if let Meta::List(_) = attr.parse_meta()? {
+                continue;
+            }
+            situ = Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+        }
+    }
+    Ok(situ.unwrap_or_else(|| parse_quote! { ::situ }))
+}
@@ -0,0 +1,205 @@
+use ::macroix::visit_fields;
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{parse_quote, Data, DeriveInput, Error, Fields, Index, Path};
+
+use crate::attr::situ_path;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let mut clone_generics = input.generics.clone();
+    clone_generics.params.push(parse_quote! {
+        __A: ::core::clone::Clone
+            + #situ::export::mischief::RegionalAllocator
+    });
+    let where_clause = clone_generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #situ::CloneRaw<__A> });
+    });
+
+    let (clone, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_discriminant_fn = &raw_enum.idents.discriminant_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let clone_variant = clone_fields(&v.fields, &situ, true);
+                quote! {
+                    #raw_variants::#ident(this_ptr) => {
+                        match #raw_variant_fn(out_raw) {
+                            #raw_variants::#ident(out_ptr) => {
+                                #clone_variant
+                            },
+                            // SAFETY: `this` and `out` must be the same variant
+                            // because we copied the discriminant from `this` to
+                            // out.
+                            _ => unsafe {
+                                ::core::hint::unreachable_unchecked();
+                            },
+                        }
+                    }
+                }
+            });
+
+            (
+                Some(quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    let out_raw = #raw_enum_fn(out_ptr);
+                    let this_discriminant = #raw_discriminant_fn(this_raw);
+                    let out_discriminant = #raw_discriminant_fn(out_raw);
+                    // SAFETY: `this_discriminant` points to the initialized
+                    // discriminant of `this`, and `out_discriminant` points to
+                    // the discriminant of `out` which is valid for writes.
+                    unsafe {
+                        *out_discriminant = *this_discriminant;
+                    }
+
+                    match #raw_variant_fn(this_raw) {
+                        #(#match_arms)*
+                    }
+                }),
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => {
+            (clone_fields(&data_struct.fields, &situ, false), None)
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`CloneRaw` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, _, where_clause) = clone_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let ty_name = &input.ident;
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            // SAFETY: `clone_raw` initializes its `out` parameter by
+            // destructuring it and cloning all of the fields.
+            #[allow(non_snake_case)]
+            unsafe impl #impl_generics #situ::CloneRaw<__A>
+                for #ty_name #ty_generics
+            #where_clause
+            {
+                #[allow(unused_variables)]
+                fn clone_raw(
+                    this: #situ::Ref<'_, Self>,
+                    alloc: __A,
+                    out: #situ::export::mischief::In<
+                        #situ::export::mischief::Slot<'_, Self>,
+                        <
+                            __A as #situ::export::mischief::RegionalAllocator
+                        >::Region,
+                    >,
+                ) {
+                    let this_ptr = #situ::Ref::as_ptr(this);
+                    let out_ptr = #situ::export::mischief::Pointer::target(
+                        out.ptr(),
+                    );
+
+                    #clone
+                }
+            }
+        };
+    })
+}
+
+fn clone_field(situ: &Path) -> TokenStream {
+    quote! {
+        // SAFETY: `this_field` is a subfield of the value being cloned, and so
+        // is guaranteed to be non-null, properly aligned, valid for reads, and
+        // initialized. It may only be aliased by shared references because
+        // `this` is a `Ref`.
+        let this_field = unsafe { #situ::Ref::new_unchecked(this_field) };
+        // SAFETY:
+        // - `out_field` is a pointer to a subfield of the slot being cloned
+        //   into, and so is guaranteed to be non-null, properly aligned, and
+        //   valid for reads and writes.
+        // - `out_field` is the only pointer to the subfield we created, so it
+        //   cannot alias any other accessible references for its lifetime.
+        let out_field = unsafe {
+            #situ::export::mischief::Slot::new_unchecked(out_field)
+        };
+        // SAFETY: `out_field` is a subfield of the slot being cloned into, so
+        // it must be contained in the same region as it.
+        let out_field = unsafe {
+            #situ::export::mischief::In::new_unchecked(out_field)
+        };
+        #situ::CloneRaw::clone_raw(
+            this_field,
+            ::core::clone::Clone::clone(&alloc),
+            out_field,
+        );
+    }
+}
+
+fn clone_fields(
+    fields: &Fields,
+    situ: &Path,
+    skip_discriminant: bool,
+) -> Option<TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let clone_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                let clone_field = clone_field(situ);
+                quote! {
+                    // SAFETY: `this_ptr` and `out_ptr` point to a value and a
+                    // slot of `Self`, so projecting them to their fields is
+                    // sound.
+                    let (this_field, out_field) = unsafe {
+                        (
+                            ::core::ptr::addr_of!((*this_ptr).#ident),
+                            ::core::ptr::addr_of_mut!((*out_ptr).#ident),
+                        )
+                    };
+                    #clone_field
+                }
+            });
+            Some(quote! {
+                #(#clone_fields)*
+            })
+        }
+        Fields::Unnamed(fields) => {
+            let clone_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    let clone_field = clone_field(situ);
+                    quote! {
+                        // SAFETY: `this_ptr` and `out_ptr` point to a value
+                        // and a slot of `Self`, so projecting them to their
+                        // fields is sound.
+                        let (this_field, out_field) = unsafe {
+                            (
+                                ::core::ptr::addr_of!((*this_ptr).#i),
+                                ::core::ptr::addr_of_mut!((*out_ptr).#i),
+                            )
+                        };
+                        #clone_field
+                    }
+                });
+            Some(quote! {
+                #(#clone_fields)*
+            })
+        }
+        Fields::Unit => None,
+    }
+}
@@ -0,0 +1,307 @@
+use ::macroix::visit_fields;
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{parse_quote, Data, DeriveInput, Error, Fields, Index, Path};
+
+use crate::attr::situ_path;
+
+/// The comparison traits that can be derived.
+#[derive(Clone, Copy)]
+pub enum Comparison {
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+}
+
+impl Comparison {
+    fn trait_name(self) -> &'static str {
+        match self {
+            Self::PartialEq => "PartialEqRaw",
+            Self::Eq => "EqRaw",
+            Self::PartialOrd => "PartialOrdRaw",
+            Self::Ord => "OrdRaw",
+        }
+    }
+
+    fn trait_path(self, situ: &Path) -> TokenStream {
+        match self {
+            Self::PartialEq => quote! { #situ::cmp::PartialEqRaw },
+            Self::Eq => quote! { #situ::cmp::EqRaw },
+            Self::PartialOrd => quote! { #situ::cmp::PartialOrdRaw },
+            Self::Ord => quote! { #situ::cmp::OrdRaw },
+        }
+    }
+
+    /// Returns a statement which compares two fields and returns early if they
+    /// are not equal.
+    fn compare_field(self, situ: &Path) -> TokenStream {
+        match self {
+            Self::PartialEq => quote! {
+                if !#situ::cmp::PartialEqRaw::eq_raw(this_field, other_field) {
+                    return false;
+                }
+            },
+            Self::PartialOrd => quote! {
+                match #situ::cmp::PartialOrdRaw::partial_cmp_raw(
+                    this_field,
+                    other_field,
+                ) {
+                    ::core::option::Option::Some(
+                        ::core::cmp::Ordering::Equal,
+                    ) => (),
+                    cmp => return cmp,
+                }
+            },
+            Self::Ord => quote! {
+                match #situ::cmp::OrdRaw::cmp_raw(this_field, other_field) {
+                    ::core::cmp::Ordering::Equal => (),
+                    cmp => return cmp,
+                }
+            },
+            Self::Eq => unreachable!(),
+        }
+    }
+
+    /// Returns an expression for the result of comparing two equal values.
+    fn equal(self) -> TokenStream {
+        match self {
+            Self::PartialEq => quote! { true },
+            Self::PartialOrd => quote! {
+                ::core::option::Option::Some(::core::cmp::Ordering::Equal)
+            },
+            Self::Ord => quote! { ::core::cmp::Ordering::Equal },
+            Self::Eq => unreachable!(),
+        }
+    }
+
+    /// Returns an expression comparing the discriminants `this_discriminant`
+    /// and `other_discriminant` of two enums with different variants.
+    fn compare_discriminants(self) -> TokenStream {
+        match self {
+            Self::PartialEq => quote! { false },
+            Self::PartialOrd => quote! {
+                ::core::cmp::PartialOrd::partial_cmp(
+                    &this_discriminant,
+                    &other_discriminant,
+                )
+            },
+            Self::Ord => quote! {
+                ::core::cmp::Ord::cmp(&this_discriminant, &other_discriminant)
+            },
+            Self::Eq => unreachable!(),
+        }
+    }
+
+    /// Returns the signature of the comparison method.
+    fn signature(self, situ: &Path) -> TokenStream {
+        let (name, output) = match self {
+            Self::PartialEq => (quote! { eq_raw }, quote! { bool }),
+            Self::PartialOrd => (
+                quote! { partial_cmp_raw },
+                quote! {
+                    ::core::option::Option<::core::cmp::Ordering>
+                },
+            ),
+            Self::Ord => (quote! { cmp_raw }, quote! { ::core::cmp::Ordering }),
+            Self::Eq => unreachable!(),
+        };
+        quote! {
+            fn #name(
+                this: #situ::Ref<'_, Self>,
+                other: #situ::Ref<'_, Self>,
+            ) -> #output
+        }
+    }
+}
+
+pub fn derive(
+    mut input: DeriveInput,
+    comparison: Comparison,
+) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+    let trait_path = comparison.trait_path(&situ);
+
+    let name = &input.ident;
+
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #trait_path });
+    });
+
+    if let Data::Union(data_union) = &input.data {
+        return Err(Error::new_spanned(
+            data_union.union_token,
+            format!(
+                "`{}` cannot be derived for unions",
+                comparison.trait_name(),
+            ),
+        ));
+    }
+
+    // `EqRaw` is a marker trait, so it doesn't have any methods to generate.
+    if let Comparison::Eq = comparison {
+        let (impl_generics, ty_generics, where_clause) =
+            input.generics.split_for_impl();
+
+        return Ok(quote! {
+            impl #impl_generics #trait_path for #name #ty_generics
+            #where_clause
+            {}
+        });
+    }
+
+    let (compare, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_discriminant_fn = &raw_enum.idents.discriminant_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let compare_variant =
+                    compare_fields(&v.fields, &situ, comparison, true);
+                quote! {
+                    (
+                        #raw_variants::#ident(this_ptr),
+                        #raw_variants::#ident(other_ptr),
+                    ) => {
+                        #compare_variant
+                    }
+                }
+            });
+            let compare_discriminants = comparison.compare_discriminants();
+            let equal = comparison.equal();
+
+            (
+                quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    let other_raw = #raw_enum_fn(other_ptr);
+                    // SAFETY: The discriminants of `this` and `other` are
+                    // always initialized and valid for reads.
+                    let (this_discriminant, other_discriminant) = unsafe {
+                        (
+                            *#raw_discriminant_fn(this_raw),
+                            *#raw_discriminant_fn(other_raw),
+                        )
+                    };
+                    if this_discriminant != other_discriminant {
+                        return #compare_discriminants;
+                    }
+
+                    match (
+                        #raw_variant_fn(this_raw),
+                        #raw_variant_fn(other_raw),
+                    ) {
+                        #(#match_arms)*
+                        // SAFETY: `this` and `other` must be the same variant
+                        // because they have the same discriminant.
+                        _ => unsafe {
+                            ::core::hint::unreachable_unchecked();
+                        },
+                    }
+
+                    #equal
+                },
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => {
+            let compare =
+                compare_fields(&data_struct.fields, &situ, comparison, false);
+            let equal = comparison.equal();
+            (
+                quote! {
+                    #compare
+                    #equal
+                },
+                None,
+            )
+        }
+        Data::Union(_) => unreachable!(),
+    };
+
+    let signature = comparison.signature(&situ);
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            impl #impl_generics #trait_path for #name #ty_generics
+            #where_clause
+            {
+                #[allow(unused_variables)]
+                #signature {
+                    let this_ptr = #situ::Ref::as_ptr(this);
+                    let other_ptr = #situ::Ref::as_ptr(other);
+                    #compare
+                }
+            }
+        };
+    })
+}
+
+fn compare_field(
+    situ: &Path,
+    comparison: Comparison,
+    member: TokenStream,
+) -> TokenStream {
+    let compare_field = comparison.compare_field(situ);
+    quote! {
+        // SAFETY: `this_ptr` and `other_ptr` point to valid values of `Self`,
+        // so their fields are also valid for reads.
+        let (this_field, other_field) = unsafe {
+            (
+                #situ::Ref::new_unchecked(
+                    ::core::ptr::addr_of!((*this_ptr).#member),
+                ),
+                #situ::Ref::new_unchecked(
+                    ::core::ptr::addr_of!((*other_ptr).#member),
+                ),
+            )
+        };
+        #compare_field
+    }
+}
+
+fn compare_fields(
+    fields: &Fields,
+    situ: &Path,
+    comparison: Comparison,
+    skip_discriminant: bool,
+) -> Option<TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let compare_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                compare_field(situ, comparison, quote! { #ident })
+            });
+            Some(quote! {
+                #(#compare_fields)*
+            })
+        }
+        Fields::Unnamed(fields) => {
+            let compare_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    compare_field(situ, comparison, quote! { #i })
+                });
+            Some(quote! {
+                #(#compare_fields)*
+            })
+        }
+        Fields::Unit => None,
+    }
+}
@@ -0,0 +1,160 @@
+use ::macroix::visit_fields;
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{
+    parse_quote,
+    Data,
+    DeriveInput,
+    Error,
+    Fields,
+    Ident,
+    Index,
+    Path,
+};
+
+use crate::attr::situ_path;
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let name = &input.ident;
+
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #situ::fmt::DebugRaw });
+    });
+
+    let (fmt_raw, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let fmt_raw_variant =
+                    fmt_raw_fields(ident, &v.fields, &situ, true);
+                quote! {
+                    #raw_variants::#ident(this_ptr) => {
+                        #fmt_raw_variant
+                    }
+                }
+            });
+
+            (
+                quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    match #raw_variant_fn(this_raw) {
+                        #(#match_arms)*
+                    }
+                },
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => (
+            fmt_raw_fields(name, &data_struct.fields, &situ, false),
+            None,
+        ),
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`DebugRaw` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            impl #impl_generics #situ::fmt::DebugRaw for #name #ty_generics
+            #where_clause
+            {
+                fn fmt_raw(
+                    this: #situ::Ref<'_, Self>,
+                    f: &mut ::core::fmt::Formatter<'_>,
+                ) -> ::core::result::Result<(), ::core::fmt::Error> {
+                    let this_ptr = #situ::Ref::as_ptr(this);
+                    #fmt_raw
+                }
+            }
+        };
+    })
+}
+
+fn fmt_raw_fields(
+    name: &Ident,
+    fields: &Fields,
+    situ: &Path,
+    skip_discriminant: bool,
+) -> TokenStream {
+    let name_str = name.to_string();
+    match fields {
+        Fields::Named(fields) => {
+            let debug_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                let ident_str = ident.as_ref().unwrap().to_string();
+                quote! {
+                    .field(
+                        #ident_str,
+                        // SAFETY: `this_ptr` points to a valid value, so its
+                        // fields are also non-null, properly aligned, valid for
+                        // reads, and initialized. They may only be aliased by
+                        // shared references because `this` is a `Ref`.
+                        &unsafe {
+                            #situ::Ref::new_unchecked(
+                                ::core::ptr::addr_of!((*this_ptr).#ident)
+                            )
+                        },
+                    )
+                }
+            });
+            quote! {
+                f.debug_struct(#name_str)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let debug_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    quote! {
+                        .field(
+                            // SAFETY: `this_ptr` points to a valid value, so
+                            // its fields are also non-null, properly aligned,
+                            // valid for reads, and initialized. They may only
+                            // be aliased by shared references because `this`
+                            // is a `Ref`.
+                            &unsafe {
+                                #situ::Ref::new_unchecked(
+                                    ::core::ptr::addr_of!((*this_ptr).#i)
+                                )
+                            },
+                        )
+                    }
+                });
+            quote! {
+                f.debug_tuple(#name_str)
+                    #(#debug_fields)*
+                    .finish()
+            }
+        }
+        Fields::Unit => quote! {
+            let _ = this_ptr;
+            f.write_str(#name_str)
+        },
+    }
+}
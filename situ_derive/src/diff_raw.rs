@@ -0,0 +1,202 @@
+use ::macroix::visit_fields;
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{parse_quote, Data, DeriveInput, Error, Fields, Index, Path};
+
+use crate::attr::situ_path;
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let name = &input.ident;
+
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #situ::diff::DiffRaw });
+    });
+
+    let (diff_raw, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_discriminant_fn = &raw_enum.idents.discriminant_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let variant = ident.to_string();
+                let diff_variant = diff_fields(
+                    &v.fields,
+                    &situ,
+                    true,
+                    quote! {
+                        path.push_segment(
+                            #situ::diff::PathSegment::Variant(#variant),
+                        );
+                    },
+                );
+                quote! {
+                    (
+                        #raw_variants::#ident(this_ptr),
+                        #raw_variants::#ident(other_ptr),
+                    ) => {
+                        #diff_variant
+                    }
+                }
+            });
+
+            (
+                quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    let other_raw = #raw_enum_fn(other_ptr);
+                    // SAFETY: The discriminants of `this` and `other` are
+                    // always initialized and valid for reads.
+                    let (this_discriminant, other_discriminant) = unsafe {
+                        (
+                            *#raw_discriminant_fn(this_raw),
+                            *#raw_discriminant_fn(other_raw),
+                        )
+                    };
+                    if this_discriminant != other_discriminant {
+                        return true;
+                    }
+
+                    match (
+                        #raw_variant_fn(this_raw),
+                        #raw_variant_fn(other_raw),
+                    ) {
+                        #(#match_arms)*
+                        // SAFETY: `this` and `other` must be the same variant
+                        // because they have the same discriminant.
+                        _ => unsafe {
+                            ::core::hint::unreachable_unchecked();
+                        },
+                    }
+
+                    false
+                },
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => {
+            let diff = diff_fields(&data_struct.fields, &situ, false, quote!());
+            (
+                quote! {
+                    #diff
+                    false
+                },
+                None,
+            )
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`DiffRaw` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            impl #impl_generics #situ::diff::DiffRaw for #name #ty_generics
+            #where_clause
+            {
+                #[allow(unused_variables)]
+                fn diff_raw<__P: #situ::diff::DiffPath + ?Sized>(
+                    this: #situ::Ref<'_, Self>,
+                    other: #situ::Ref<'_, Self>,
+                    path: &mut __P,
+                ) -> bool {
+                    let this_ptr = #situ::Ref::as_ptr(this);
+                    let other_ptr = #situ::Ref::as_ptr(other);
+                    #diff_raw
+                }
+            }
+        };
+    })
+}
+
+/// Returns a statement which diffs two fields and returns early if they differ.
+///
+/// `push_outer` is run after the field's segment is pushed, so that enums can
+/// add their variant to the path.
+fn diff_field(
+    situ: &Path,
+    member: TokenStream,
+    field_name: String,
+    push_outer: &TokenStream,
+) -> TokenStream {
+    quote! {
+        // SAFETY: `this_ptr` and `other_ptr` point to valid values of `Self`,
+        // so their fields are also valid for reads.
+        let (this_field, other_field) = unsafe {
+            (
+                #situ::Ref::new_unchecked(
+                    ::core::ptr::addr_of!((*this_ptr).#member),
+                ),
+                #situ::Ref::new_unchecked(
+                    ::core::ptr::addr_of!((*other_ptr).#member),
+                ),
+            )
+        };
+        if #situ::diff::DiffRaw::diff_raw(this_field, other_field, path) {
+            path.push_segment(#situ::diff::PathSegment::Field(#field_name));
+            #push_outer
+            return true;
+        }
+    }
+}
+
+fn diff_fields(
+    fields: &Fields,
+    situ: &Path,
+    skip_discriminant: bool,
+    push_outer: TokenStream,
+) -> Option<TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let diff_fields = fields.named.iter().map(|f| {
+                let ident = f.ident.as_ref().unwrap();
+                diff_field(
+                    situ,
+                    quote! { #ident },
+                    ident.to_string(),
+                    &push_outer,
+                )
+            });
+            Some(quote! {
+                #(#diff_fields)*
+            })
+        }
+        Fields::Unnamed(fields) => {
+            let diff_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let member = Index::from(i + offset);
+                    diff_field(
+                        situ,
+                        quote! { #member },
+                        i.to_string(),
+                        &push_outer,
+                    )
+                });
+            Some(quote! {
+                #(#diff_fields)*
+            })
+        }
+        Fields::Unit => None,
+    }
+}
@@ -0,0 +1,78 @@
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::syn::{parse_quote, Data, DeriveInput, Error, Fields, Index, Member};
+
+use crate::attr::situ_path;
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let name = &input.ident;
+
+    // `DisplayRaw` has no canonical structure to derive, so only newtypes are
+    // supported. They forward to the `DisplayRaw` impl of their single field.
+    let field = match &input.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) if fields.named.len() == 1 => {
+                fields.named.first().unwrap()
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                fields.unnamed.first().unwrap()
+            }
+            _ => return Err(Error::new_spanned(
+                &data_struct.fields,
+                "`DisplayRaw` can only be derived for structs with exactly \
+                     one field",
+            )),
+        },
+        Data::Enum(data_enum) => {
+            return Err(Error::new_spanned(
+                data_enum.enum_token,
+                "`DisplayRaw` cannot be derived for enums",
+            ))
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`DisplayRaw` cannot be derived for unions",
+            ))
+        }
+    };
+    let ty = field.ty.clone();
+    let member = field
+        .ident
+        .clone()
+        .map_or_else(|| Member::Unnamed(Index::from(0)), Member::Named);
+
+    input
+        .generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #ty: #situ::fmt::DisplayRaw });
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #situ::fmt::DisplayRaw for #name #ty_generics
+        #where_clause
+        {
+            fn fmt_raw(
+                this: #situ::Ref<'_, Self>,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::result::Result<(), ::core::fmt::Error> {
+                let this_ptr = #situ::Ref::as_ptr(this);
+                // SAFETY: `this_ptr` points to a valid value of `Self`, so its
+                // field is also non-null, properly aligned, valid for reads,
+                // and initialized. It may only be aliased by shared references
+                // because `this` is a `Ref`.
+                let field = unsafe {
+                    #situ::Ref::new_unchecked(
+                        ::core::ptr::addr_of!((*this_ptr).#member)
+                    )
+                };
+                <#ty as #situ::fmt::DisplayRaw>::fmt_raw(field, f)
+            }
+        }
+    })
+}
@@ -8,31 +8,80 @@ use ::syn::{
     Data,
     DeriveInput,
     Error,
+    Field,
     Fields,
     Index,
+    Lit,
+    Meta,
+    NestedMeta,
     Path,
 };
 
 pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
     let mut repr = None;
     let mut situ = None;
+    let mut finalize = None;
     for attr in input.attrs.iter() {
         if attr.path.is_ident("repr") {
             Repr::merge_attr(&mut repr, attr.tokens.clone())?;
         } else if attr.path.is_ident("situ") {
-            situ = Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::NameValue(meta))
+                            if meta.path.is_ident("finalize") =>
+                        {
+                            if let Lit::Str(lit) = &meta.lit {
+                                finalize = Some(lit.parse::<Path>()?);
+                            } else {
+                                return Err(Error::new_spanned(
+                                    &meta.lit,
+                                    "expected a string literal path",
+                                ));
+                            }
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                nested,
+                                "unrecognized `situ` argument",
+                            ))
+                        }
+                    }
+                }
+            } else {
+                situ =
+                    Some(parse2::<AttrValue<Path>>(attr.tokens.clone())?.value);
+            }
         }
     }
     let situ = situ.unwrap_or_else(|| parse_quote! { ::situ });
 
     let name = &input.ident;
 
+    let mut error = None;
     let where_clause = input.generics.make_where_clause();
-    visit_fields(&input.data, |f| {
-        let ty = &f.ty;
-        where_clause
-            .predicates
-            .push(parse_quote! { #ty: #situ::DropRaw });
+    visit_fields(&input.data, |f| match skip_drop(f) {
+        Ok(true) => (),
+        Ok(false) => {
+            let ty = &f.ty;
+            where_clause
+                .predicates
+                .push(parse_quote! { #ty: #situ::DropRaw });
+        }
+        Err(e) => {
+            error.get_or_insert(e);
+        }
+    });
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    // The finalizer runs before any of the fields are dropped, so it may still
+    // access all of them.
+    let finalize = finalize.map(|finalize| {
+        quote! {
+            #finalize(#situ::Mut::as_mut(&mut this));
+        }
     });
 
     let (drop_raw, util) = match &input.data {
@@ -85,6 +134,7 @@ pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
             #where_clause
             {
                 unsafe fn drop_raw(mut this: #situ::Mut<'_, Self>) {
+                    #finalize
                     let this_ptr = #situ::Mut::as_ptr(&this);
                     #drop_raw
                 }
@@ -100,24 +150,29 @@ fn drop_raw_fields(
 ) -> Option<TokenStream> {
     match fields {
         Fields::Named(fields) => {
-            let move_fields = fields.named.iter().map(|f| {
-                let ty = &f.ty;
-                let ident = &f.ident;
-                quote! {
-                    <#ty as #situ::DropRaw>::drop_raw(
-                        #situ::Mut::new_unchecked(
-                            ::core::ptr::addr_of_mut!((*this_ptr).#ident)
-                        )
-                    );
-                }
-            });
+            let move_fields =
+                fields.named.iter().filter(|f| !is_skipped(f)).map(|f| {
+                    let ty = &f.ty;
+                    let ident = &f.ident;
+                    quote! {
+                        <#ty as #situ::DropRaw>::drop_raw(
+                            #situ::Mut::new_unchecked(
+                                ::core::ptr::addr_of_mut!((*this_ptr).#ident)
+                            )
+                        );
+                    }
+                });
             Some(quote! {
                 #(#move_fields)*
             })
         }
         Fields::Unnamed(fields) => {
-            let move_fields =
-                fields.unnamed.iter().enumerate().map(|(i, _)| {
+            let move_fields = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !is_skipped(f))
+                .map(|(i, _)| {
                     // In enum tuple structs, the tag is the first element so we
                     // have to skip over it.
                     let offset = if skip_discriminant { 1 } else { 0 };
@@ -137,3 +192,41 @@ fn drop_raw_fields(
         Fields::Unit => None,
     }
 }
+
+/// Returns whether the field is annotated with `#[situ(skip_drop)]`.
+fn skip_drop(field: &Field) -> Result<bool, Error> {
+    let mut result = false;
+    for attr in field.attrs.iter() {
+        if attr.path.is_ident("situ") {
+            if let Meta::List(list) = attr.parse_meta()? {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path))
+                            if path.is_ident("skip_drop") =>
+                        {
+                            result = true;
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                nested,
+                                "unrecognized `situ` argument",
+                            ))
+                        }
+                    }
+                }
+            } else {
+                return Err(Error::new_spanned(
+                    attr,
+                    "expected `#[situ(skip_drop)]`",
+                ));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Like `skip_drop`, but for use after the field attributes have already been
+/// validated.
+fn is_skipped(field: &Field) -> bool {
+    skip_drop(field).unwrap_or(false)
+}
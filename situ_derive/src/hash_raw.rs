@@ -0,0 +1,134 @@
+use ::macroix::visit_fields;
+use ::proc_macro2::TokenStream;
+use ::quote::quote;
+use ::raw_enum::RawEnum;
+use ::syn::{parse_quote, Data, DeriveInput, Error, Fields, Index, Path};
+
+use crate::attr::situ_path;
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let name = &input.ident;
+
+    let where_clause = input.generics.make_where_clause();
+    visit_fields(&input.data, |f| {
+        let ty = &f.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #situ::hash::HashRaw });
+    });
+
+    let (hash_raw, util) = match &input.data {
+        Data::Enum(data_enum) => {
+            let raw_enum = RawEnum::for_derive(&input)?;
+
+            let raw_variants = &raw_enum.idents.variants;
+            let raw_enum_fn = &raw_enum.idents.raw_enum_fn;
+            let raw_discriminant_fn = &raw_enum.idents.discriminant_fn;
+            let raw_variant_fn = &raw_enum.idents.variant_fn;
+
+            let match_arms = data_enum.variants.iter().map(|v| {
+                let ident = &v.ident;
+                let hash_raw_variant = hash_raw_fields(&v.fields, &situ, true);
+                quote! {
+                    #raw_variants::#ident(this_ptr) => {
+                        #hash_raw_variant
+                    }
+                }
+            });
+
+            (
+                Some(quote! {
+                    let this_raw = #raw_enum_fn(this_ptr);
+                    // SAFETY: The discriminant of `this` is always initialized
+                    // and valid for reads.
+                    let discriminant =
+                        unsafe { *#raw_discriminant_fn(this_raw) };
+                    ::core::hash::Hash::hash(&discriminant, state);
+
+                    match #raw_variant_fn(this_raw) {
+                        #(#match_arms)*
+                    }
+                }),
+                Some(raw_enum.tokens),
+            )
+        }
+        Data::Struct(data_struct) => {
+            (hash_raw_fields(&data_struct.fields, &situ, false), None)
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`HashRaw` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            #util
+
+            impl #impl_generics #situ::hash::HashRaw for #name #ty_generics
+            #where_clause
+            {
+                #[allow(unused_variables)]
+                fn hash_raw<__H: ::core::hash::Hasher>(
+                    this: #situ::Ref<'_, Self>,
+                    state: &mut __H,
+                ) {
+                    let this_ptr = #situ::Ref::as_ptr(this);
+                    #hash_raw
+                }
+            }
+        };
+    })
+}
+
+fn hash_raw_field(situ: &Path, member: TokenStream) -> TokenStream {
+    quote! {
+        // SAFETY: `this_ptr` points to a valid value of `Self`, so its fields
+        // are also valid for reads.
+        let this_field = unsafe {
+            #situ::Ref::new_unchecked(
+                ::core::ptr::addr_of!((*this_ptr).#member),
+            )
+        };
+        #situ::hash::HashRaw::hash_raw(this_field, state);
+    }
+}
+
+fn hash_raw_fields(
+    fields: &Fields,
+    situ: &Path,
+    skip_discriminant: bool,
+) -> Option<TokenStream> {
+    match fields {
+        Fields::Named(fields) => {
+            let hash_fields = fields.named.iter().map(|f| {
+                let ident = &f.ident;
+                hash_raw_field(situ, quote! { #ident })
+            });
+            Some(quote! {
+                #(#hash_fields)*
+            })
+        }
+        Fields::Unnamed(fields) => {
+            let hash_fields =
+                fields.unnamed.iter().enumerate().map(|(i, _)| {
+                    // In enum tuple structs, the tag is the first element so we
+                    // have to skip over it.
+                    let offset = if skip_discriminant { 1 } else { 0 };
+                    let i = Index::from(i + offset);
+                    hash_raw_field(situ, quote! { #i })
+                });
+            Some(quote! {
+                #(#hash_fields)*
+            })
+        }
+        Fields::Unit => None,
+    }
+}
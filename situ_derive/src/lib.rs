@@ -10,12 +10,38 @@
     rustdoc::missing_crate_level_docs
 )]
 
+mod attr;
+mod clone_raw;
+mod cmp;
+mod debug_raw;
+mod diff_raw;
+mod display_raw;
 mod drop_raw;
+mod hash_raw;
+mod pinned;
 
 use ::proc_macro::TokenStream;
 use ::syn::{parse_macro_input, DeriveInput};
 
+use self::cmp::Comparison;
+
+/// Derives `CloneRaw` on the annotated type.
+///
+/// The generated implementation clones each field with the same allocator.
+#[proc_macro_derive(CloneRaw, attributes(situ))]
+pub fn derive_clone_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    clone_raw::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 /// Derives `DropRaw` on the annotated type.
+///
+/// Fields annotated with `#[situ(skip_drop)]` are not dropped and do not need
+/// to implement `DropRaw`. A finalizer may be run before any fields are dropped
+/// with `#[situ(finalize = "path::to::finalize")]`, where `finalize` is an
+/// `unsafe fn(Mut<'_, Self>)`.
 #[proc_macro_derive(DropRaw, attributes(situ))]
 pub fn derive_drop_raw(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -23,3 +49,110 @@ pub fn derive_drop_raw(input: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+/// Derives `DebugRaw` on the annotated type.
+///
+/// The generated implementation formats structs and enum variants like the
+/// standard `Debug` derive, using the `DebugRaw` implementation of each field.
+#[proc_macro_derive(DebugRaw, attributes(situ))]
+pub fn derive_debug_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    debug_raw::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `DiffRaw` on the annotated type.
+///
+/// The generated implementation diffs each field in declaration order and
+/// reports the first one which differs. Enums differ entirely if they are
+/// different variants, and otherwise report the variant along with the field.
+#[proc_macro_derive(DiffRaw, attributes(situ))]
+pub fn derive_diff_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    diff_raw::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `DisplayRaw` on the annotated type.
+///
+/// This can only be derived for structs with exactly one field, and forwards
+/// to the `DisplayRaw` implementation of that field.
+#[proc_macro_derive(DisplayRaw, attributes(situ))]
+pub fn derive_display_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    display_raw::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `HashRaw` on the annotated type.
+///
+/// The generated implementation hashes each field in declaration order. Enums
+/// hash their discriminant before their fields.
+#[proc_macro_derive(HashRaw, attributes(situ))]
+pub fn derive_hash_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    hash_raw::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `Pinned` on the annotated struct.
+///
+/// The struct is pinned to a region whenever all of its fields annotated with
+/// `#[pinned]` are. If no fields are annotated, then all of its fields must be
+/// pinned to the region instead.
+#[proc_macro_derive(Pinned, attributes(situ, pinned))]
+pub fn derive_pinned(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    pinned::derive(derive_input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `PartialEqRaw` on the annotated type.
+///
+/// Structs are equal if all of their fields are equal. Enums are equal if they
+/// are the same variant and all of their fields are equal.
+#[proc_macro_derive(PartialEqRaw, attributes(situ))]
+pub fn derive_partial_eq_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    cmp::derive(derive_input, Comparison::PartialEq)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `EqRaw` on the annotated type.
+#[proc_macro_derive(EqRaw, attributes(situ))]
+pub fn derive_eq_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    cmp::derive(derive_input, Comparison::Eq)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `PartialOrdRaw` on the annotated type.
+///
+/// Structs are ordered lexicographically by their fields in declaration order.
+/// Enums are ordered by their discriminants, and then by their fields.
+#[proc_macro_derive(PartialOrdRaw, attributes(situ))]
+pub fn derive_partial_ord_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    cmp::derive(derive_input, Comparison::PartialOrd)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `OrdRaw` on the annotated type.
+///
+/// Structs are ordered lexicographically by their fields in declaration order.
+/// Enums are ordered by their discriminants, and then by their fields.
+#[proc_macro_derive(OrdRaw, attributes(situ))]
+pub fn derive_ord_raw(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+    cmp::derive(derive_input, Comparison::Ord)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
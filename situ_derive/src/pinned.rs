@@ -0,0 +1,65 @@
+use ::proc_macro2::{Span, TokenStream};
+use ::quote::quote;
+use ::syn::{parse_quote, Data, DeriveInput, Error};
+
+use crate::attr::situ_path;
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+    let situ = situ_path(&input.attrs)?;
+
+    let fields = match &input.data {
+        Data::Struct(data_struct) => &data_struct.fields,
+        Data::Enum(data_enum) => {
+            return Err(Error::new_spanned(
+                data_enum.enum_token,
+                "`Pinned` cannot be derived for enums",
+            ))
+        }
+        Data::Union(data_union) => {
+            return Err(Error::new_spanned(
+                data_union.union_token,
+                "`Pinned` cannot be derived for unions",
+            ))
+        }
+    };
+
+    // A value is located in a region whenever any of its fields is. If no
+    // fields are annotated with `#[pinned]`, we require all of them to be
+    // pinned instead.
+    let mut pinned = fields
+        .iter()
+        .filter(|f| f.attrs.iter().any(|a| a.path.is_ident("pinned")))
+        .collect::<Vec<_>>();
+    if pinned.is_empty() {
+        pinned = fields.iter().collect();
+    }
+    if pinned.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "`Pinned` can only be derived for structs with at least one field",
+        ));
+    }
+
+    let mut pinned_generics = input.generics.clone();
+    pinned_generics.params.push(parse_quote! {
+        __R: #situ::export::mischief::Region
+    });
+    let where_clause = pinned_generics.make_where_clause();
+    for field in pinned {
+        let ty = &field.ty;
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: #situ::Pinned<__R> });
+    }
+
+    let (impl_generics, _, where_clause) = pinned_generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let ty_name = &input.ident;
+    Ok(quote! {
+        // SAFETY: Values of each pinned field type are always located in
+        // `__R`. Every value of `Self` contains a value of those types, so
+        // values of `Self` must also always be located in `__R`.
+        unsafe impl #impl_generics #situ::Pinned<__R>
+            for #ty_name #ty_generics #where_clause {}
+    })
+}